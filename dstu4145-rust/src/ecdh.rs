@@ -0,0 +1,180 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use num_bigint::BigUint;
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
+use signature::rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+use poly_algebra::gf::GFArithmetic;
+use rust_ec::affine_point::AffinePoint;
+use rust_ec::binary_ec::BinaryEC;
+use rust_ec::countermeasures::Countermeasures;
+use crate::error::Dstu4145Error;
+use crate::helpers::{check_public_key_correctness, generate_key_pair};
+use crate::scalar::EcScalar;
+use crate::sign::{SigningKey, VerifyingKey};
+
+/// Struct saves result of the key agreement, i.e. $x$ coordinate of the point $h \cdot d \cdot Q$,
+/// where $Q$ is public key of the peer, as big-endian bytes of fixed length $\lceil m / 8 \rceil$.
+/// _Secret isn't suitable as a key by itself, it has to be passed through KDF._
+/// Comparison is performed in constant time, bytes are wiped on drop.
+#[derive(Clone)]
+pub struct SharedSecret
+{
+  x : Vec<u8>,
+}
+
+/// Struct that characterize one-time __Private key__ for the ephemeral-static key agreement.
+/// It's consumed on the agreement, so it can't be reused with another peer.
+/// Multiplications on $d$ are performed with [Countermeasures::DEFAULT] unless it's changed
+/// with [EphemeralSecret::with_countermeasures].
+pub struct EphemeralSecret<T>
+{
+  ec : Arc<BinaryEC<T>>,
+  d : EcScalar<T>,
+  q : AffinePoint<T>,
+  countermeasures : Countermeasures,
+}
+
+impl SharedSecret
+{
+  pub fn as_bytes(&self) -> &[u8] { &self.x }
+
+  pub fn to_bytes(&self) -> Vec<u8> { self.x.clone() }
+}
+
+impl ConstantTimeEq for SharedSecret
+{
+  fn ct_eq(&self, other : &Self) -> Choice { self.x.ct_eq(&other.x) }
+}
+
+impl PartialEq for SharedSecret
+{
+  fn eq(&self, other : &Self) -> bool { self.ct_eq(other).into() }
+}
+
+impl Eq for SharedSecret {}
+
+impl fmt::Debug for SharedSecret
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { write!(f, "SharedSecret {{ .. }}") }
+}
+
+impl Zeroize for SharedSecret
+{
+  fn zeroize(&mut self) { self.x.zeroize() }
+}
+
+impl Drop for SharedSecret
+{
+  fn drop(&mut self) { self.zeroize() }
+}
+
+impl ZeroizeOnDrop for SharedSecret {}
+
+impl<'a, T : GFArithmetic<'a>> EphemeralSecret<T>
+{
+  /// Function generates ephemeral key with $L(n) - 1$ bit len, the same way as [SigningKey::generate].
+  pub fn generate(rng : &mut impl CryptoRngCore, ec : impl Into<Arc<BinaryEC<T>>>) -> Self
+  {
+    let ec = ec.into();
+    let (d, q) = generate_key_pair(rng, &ec, EcScalar::random_nonzero);
+    let q = q.negative();
+    EphemeralSecret {
+      ec,
+      d,
+      q,
+      countermeasures : Countermeasures::DEFAULT,
+    }
+  }
+
+  /// Function returns as output - packed ephemeral public key into bytes, the same as [VerifyingKey::pack].
+  pub fn pack(&self) -> Vec<u8> { self.q.pack().get_value().to_bytes_be() }
+
+  pub fn get_pub_key(&self) -> AffinePoint<T> { self.q.clone() }
+
+  /// Function sets countermeasures, that are used in the key agreement.
+  pub fn with_countermeasures(self, countermeasures : Countermeasures) -> Self
+  {
+    EphemeralSecret {
+      countermeasures,
+      ..self
+    }
+  }
+
+  /// Function performs key agreement with the packed public key of the peer.
+  pub fn diffie_hellman(self, peer_public_key : &[u8]) -> crate::error::Result<SharedSecret>
+  {
+    let q = unpack_public_key(&self.ec, peer_public_key)?;
+    diffie_hellman(&self.ec, &self.d, &q, self.countermeasures)
+  }
+
+  /// Function performs key agreement with the static public key of the peer,
+  /// that has to be defined over the same EC.
+  pub fn diffie_hellman_with_verifying_key(self, verifying_key : &VerifyingKey<T>) -> crate::error::Result<SharedSecret>
+  {
+    let ec = verifying_key.get_ref_ec();
+    if !Arc::ptr_eq(&self.ec, ec) && self.ec != *ec
+    {
+      return Err(Dstu4145Error::InvalidParams(
+        "Elliptic curves of the ephemeral key and verifying key don't match".to_string(),
+      ));
+    }
+    diffie_hellman(&self.ec, &self.d, &verifying_key.get_pub_key(), self.countermeasures)
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> SigningKey<T>
+{
+  /// Function performs key agreement with the packed public key of the peer, which is either static key
+  /// from [VerifyingKey::pack] or ephemeral key from [EphemeralSecret::pack].
+  pub fn diffie_hellman(&self, peer_public_key : &[u8]) -> crate::error::Result<SharedSecret>
+  {
+    let q = unpack_public_key(self.get_ref_ec(), peer_public_key)?;
+    diffie_hellman(self.get_ref_ec(), &self.get_private_key(), &q, self.get_countermeasures())
+  }
+}
+
+/// Function restores public key of the peer from bytes and checks its correctness according to the algorithm `10.1`.
+/// Related to function [check_public_key_correctness].
+pub fn unpack_public_key<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, bytes : &[u8]) -> crate::error::Result<AffinePoint<T>>
+{
+  let packed = BigUint::from_bytes_be(bytes);
+  if packed.bits() > T::get_m() as u64
+  {
+    return Err(Dstu4145Error::InvalidParamLength(
+      T::get_m() as u64,
+      packed.bits(),
+      "packed public key".to_string(),
+    ));
+  }
+  check_public_key_correctness(ec, &T::from(packed))
+}
+
+/// Function calculates cofactor Diffie–Hellman shared secret $x(h \cdot d \cdot Q)$.
+fn diffie_hellman<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  d : &EcScalar<T>,
+  q : &AffinePoint<T>,
+  countermeasures : Countermeasures,
+) -> crate::error::Result<SharedSecret>
+{
+  let mut rng = ChaCha20Rng::from_entropy();
+  // Cofactor is cleared on the public point, so secret isn't copied into the product $h \cdot d$
+  let q = ec.mul(q, ec.get_cofactor());
+  match ec.mul_secret(&q, d, countermeasures, &mut rng)
+  {
+    AffinePoint::Point { x, .. } =>
+    {
+      let len = T::get_m().div_ceil(8) as usize;
+      let mut bytes = x.get_value().to_bytes_be();
+      let mut x = vec![0; len - bytes.len()];
+      x.extend(&bytes);
+      bytes.zeroize();
+      Ok(SharedSecret { x })
+    }
+    AffinePoint::Infinity => Err(Dstu4145Error::SharedSecretIsInfinity),
+  }
+}
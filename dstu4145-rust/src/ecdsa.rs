@@ -0,0 +1,521 @@
+use std::sync::Arc;
+use num_bigint::BigUint;
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
+use signature::{DigestSigner, DigestVerifier, Error, RandomizedSigner, Signer, Verifier};
+use signature::digest::Digest;
+use signature::rand_core::CryptoRngCore;
+use poly_algebra::gf::GFArithmetic;
+use rust_ec::affine_point::AffinePoint;
+use rust_ec::base_point_table::{BasePointTable, BasePointTableCache};
+use rust_ec::binary_ec::BinaryEC;
+use rust_ec::countermeasures::Countermeasures;
+use crate::error::Dstu4145Error;
+use crate::helpers::{calculate_presign, calculate_public_point, check_sec1_public_key_correctness, generate_key_pair};
+use crate::scalar::EcScalar;
+
+/// Tag of the DER `SEQUENCE`.
+const DER_SEQUENCE_TAG : u8 = 0x30;
+/// Tag of the DER `INTEGER`.
+const DER_INTEGER_TAG : u8 = 0x02;
+/// Prefix of the DER long form length that is encoded in one byte.
+const DER_LONG_LEN_PREFIX : u8 = 0x81;
+
+/// Struct saves ECDSA signature $(r, s)$ as big-endian bytes, it's encoded as DER `SEQUENCE { r INTEGER, s INTEGER }`
+/// in the same way as `ecdsa-with-SHA*` signatures in X.509 and CMS.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Signature
+{
+  r : Vec<u8>,
+  s : Vec<u8>,
+}
+
+/// Struct that characterize ECDSA __Public key__ $Q = dP$ for checking digital signature.
+/// EC is shared between keys with help of [Arc], so keys are cheap to clone.
+/// Multiples of the base point are precomputed on the first verification and shared between clones of the key.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct VerifyingKey<T>
+{
+  ec : Arc<BinaryEC<T>>,
+  q : AffinePoint<T>,
+  base_point_table : BasePointTableCache<T>,
+}
+
+/// Struct that characterize ECDSA __Private key__ $d$ for making digital signature.
+/// Keys are generated in the same way as [crate::sign::SigningKey], but $Q = dP$ isn't negated.
+/// By default, messages are hashed with SHA-256, any other digest can be used with [DigestSigner].
+/// Multiplications on secret scalars are performed with [Countermeasures::DEFAULT] unless it's changed
+/// with [SigningKey::with_countermeasures].
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct SigningKey<T>
+{
+  ec : Arc<BinaryEC<T>>,
+  d : EcScalar<T>,
+  countermeasures : Countermeasures,
+}
+
+impl<'a, T : GFArithmetic<'a>> Verifier<Signature> for VerifyingKey<T>
+{
+  fn verify(&self, msg : &[u8], signature : &Signature) -> Result<(), Error>
+  {
+    let mut digest = sha2::Sha256::new();
+    digest.update(msg);
+    self.verify_digest(digest, signature)
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>, D : Digest> DigestVerifier<D, Signature> for VerifyingKey<T>
+{
+  fn verify_digest(&self, digest : D, signature : &Signature) -> Result<(), Error>
+  {
+    let base_point_table = self.base_point_table.get_or_init(&self.ec);
+    verify(&self.ec, base_point_table, digest, &self.q, &signature.r, &signature.s).map_err(|e| {
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(e);
+      Error::from(e)
+    })
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> Signer<Signature> for SigningKey<T>
+{
+  fn try_sign(&self, msg : &[u8]) -> Result<Signature, Error>
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    self.try_sign_with_rng(&mut rng, msg)
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> RandomizedSigner<Signature> for SigningKey<T>
+{
+  fn try_sign_with_rng(&self, rng : &mut impl CryptoRngCore, msg : &[u8]) -> Result<Signature, Error>
+  {
+    let mut digest = sha2::Sha256::new();
+    digest.update(msg);
+    Ok(sign(rng, &self.ec, digest, &self.d, self.countermeasures))
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>, D : Digest> DigestSigner<D, Signature> for SigningKey<T>
+{
+  fn try_sign_digest(&self, digest : D) -> Result<Signature, Error>
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    Ok(sign(&mut rng, &self.ec, digest, &self.d, self.countermeasures))
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> SigningKey<T>
+{
+  /// Function gets a copy of private key. Be careful in using of this command!
+  pub fn get_private_key(&self) -> EcScalar<T> { self.d.clone() }
+
+  /// Function gets reference to the shared EC.
+  pub fn get_ref_ec(&self) -> &Arc<BinaryEC<T>> { &self.ec }
+
+  /// Function gets countermeasures, that are used for multiplication on secret scalars.
+  pub fn get_countermeasures(&self) -> Countermeasures { self.countermeasures }
+
+  /// Function sets countermeasures, that are used for multiplication on secret nonces $k$.
+  pub fn with_countermeasures(self, countermeasures : Countermeasures) -> Self { SigningKey { countermeasures, ..self } }
+
+  /// Function generates __Private key__ from PRNG.
+  /// EC can be passed either by value or as [Arc] that is already shared with other keys.
+  pub fn generate(rng : &mut impl CryptoRngCore, ec : impl Into<Arc<BinaryEC<T>>>) -> (Self, VerifyingKey<T>)
+  {
+    let ec = ec.into();
+    let (d, q) = generate_key_pair(rng, &ec, EcScalar::random_uniform_nonzero);
+    (
+      Self {
+        ec : Arc::clone(&ec),
+        d,
+        countermeasures : Countermeasures::DEFAULT,
+      },
+      VerifyingKey::new(ec, q),
+    )
+  }
+
+  /// Function creates __Private key__ from big-endian bytes of $d \in [1, n - 1]$, e.g. from the existing key.
+  pub fn from_secret<B : AsRef<[u8]>>(ec : impl Into<Arc<BinaryEC<T>>>, d : B) -> crate::error::Result<(Self, VerifyingKey<T>)>
+  {
+    let ec = ec.into();
+    let d = EcScalar::from_bytes(&ec, d.as_ref()).filter(|d| !d.is_zero()).ok_or_else(|| {
+      Dstu4145Error::InvalidParams(format!(
+        "Invalid d parameter, has to be in range [1, n - 1], n: {}",
+        ec.get_ord().to_str_radix(16)
+      ))
+    })?;
+    let q = calculate_public_point(&ec, &d);
+    Ok((
+      Self {
+        ec : Arc::clone(&ec),
+        d,
+        countermeasures : Countermeasures::DEFAULT,
+      },
+      VerifyingKey::new(ec, q),
+    ))
+  }
+
+  /// Function calculates __Public key__ $Q = dP$ that corresponds to the private key.
+  pub fn verifying_key(&self) -> VerifyingKey<T>
+  {
+    VerifyingKey::new(Arc::clone(&self.ec), calculate_public_point(&self.ec, &self.d))
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> VerifyingKey<T>
+{
+  fn new(ec : Arc<BinaryEC<T>>, q : AffinePoint<T>) -> Self
+  {
+    VerifyingKey {
+      ec,
+      q,
+      base_point_table : BasePointTableCache::new(),
+    }
+  }
+
+  /// Function restores __Public key__ from SEC1 encoding, point has to belong to the subgroup of order $n$.
+  pub fn from_sec1(ec : impl Into<Arc<BinaryEC<T>>>, bytes : &[u8]) -> crate::error::Result<Self>
+  {
+    let ec = ec.into();
+    let q = check_sec1_public_key_correctness(&ec, bytes)?;
+    Ok(VerifyingKey::new(ec, q))
+  }
+
+  /// Function encodes public key $Q$ according to the SEC1.
+  pub fn to_sec1(&self, compressed : bool) -> Vec<u8> { self.q.to_sec1(compressed) }
+
+  pub fn get_pub_key(&self) -> AffinePoint<T> { self.q.clone() }
+
+  /// Function gets reference to the shared EC.
+  pub fn get_ref_ec(&self) -> &Arc<BinaryEC<T>> { &self.ec }
+}
+
+impl Signature
+{
+  /// Function encodes signature as DER `SEQUENCE` of two `INTEGER`'s.
+  pub fn to_der(&self) -> Vec<u8>
+  {
+    let mut content = Vec::new();
+    push_der_integer(&mut content, &self.r);
+    push_der_integer(&mut content, &self.s);
+    let mut buf = Vec::with_capacity(content.len() + 3);
+    buf.push(DER_SEQUENCE_TAG);
+    push_der_len(&mut buf, content.len());
+    buf.extend(content);
+    buf
+  }
+
+  pub fn get_r(&self) -> Vec<u8> { self.r.clone() }
+
+  pub fn get_ref_r(&self) -> &[u8] { &self.r }
+
+  pub fn get_s(&self) -> Vec<u8> { self.s.clone() }
+
+  pub fn get_ref_s(&self) -> &[u8] { &self.s }
+}
+
+/// This conversion decodes signature from DER, only the minimal encoding of nonnegative integers is accepted.
+impl TryFrom<&[u8]> for Signature
+{
+  type Error = Dstu4145Error;
+
+  fn try_from(value : &[u8]) -> Result<Self, Self::Error>
+  {
+    let (content, rest) = read_der_element(value, DER_SEQUENCE_TAG)?;
+    if !rest.is_empty()
+    {
+      return Err(Dstu4145Error::InvalidSignatureEncoding(format!(
+        "{} trailing bytes after SEQUENCE",
+        rest.len()
+      )));
+    }
+    let (r, content) = read_der_integer(content)?;
+    let (s, content) = read_der_integer(content)?;
+    if !content.is_empty()
+    {
+      return Err(Dstu4145Error::InvalidSignatureEncoding(
+        "SEQUENCE has to contain only two INTEGER's".to_string(),
+      ));
+    }
+    Ok(Signature { r, s })
+  }
+}
+
+/// Function encodes length of the DER element, signatures over the biggest fields fit into the one-byte long form.
+fn push_der_len(buf : &mut Vec<u8>, len : usize)
+{
+  assert!(len <= u8::MAX as usize, "DER length has to fit into one byte");
+  if len >= 0x80
+  {
+    buf.push(DER_LONG_LEN_PREFIX);
+  }
+  buf.push(len as u8);
+}
+
+/// Function encodes big-endian number as DER `INTEGER`, leading zero is added if the highest bit is set.
+fn push_der_integer(buf : &mut Vec<u8>, num : &[u8])
+{
+  let num = BigUint::from_bytes_be(num).to_bytes_be();
+  let padding = num[0] & 0x80 != 0;
+  buf.push(DER_INTEGER_TAG);
+  push_der_len(buf, num.len() + padding as usize);
+  if padding
+  {
+    buf.push(0);
+  }
+  buf.extend(num);
+}
+
+/// Function reads DER element with the given tag and returns its content with the rest of bytes.
+fn read_der_element(bytes : &[u8], tag : u8) -> crate::error::Result<(&[u8], &[u8])>
+{
+  let invalid = |msg : &str| Dstu4145Error::InvalidSignatureEncoding(format!("{msg}, tag: {tag:#04x}"));
+  let (actual_tag, bytes) = bytes.split_first().ok_or_else(|| invalid("unexpected end of data"))?;
+  if *actual_tag != tag
+  {
+    return Err(invalid(&format!("unexpected tag {actual_tag:#04x}")));
+  }
+  let (len, bytes) = bytes.split_first().ok_or_else(|| invalid("missing length"))?;
+  let (len, bytes) = match *len
+  {
+    len if len < 0x80 => (len as usize, bytes),
+    DER_LONG_LEN_PREFIX => match bytes.split_first()
+    {
+      Some((len, bytes)) if *len >= 0x80 => (*len as usize, bytes),
+      _ => return Err(invalid("non-minimal length")),
+    },
+    _ => return Err(invalid("unsupported length")),
+  };
+  if bytes.len() < len
+  {
+    return Err(invalid("length exceeds data"));
+  }
+  Ok(bytes.split_at(len))
+}
+
+/// Function reads nonnegative DER `INTEGER` and returns its big-endian bytes without leading zeros.
+fn read_der_integer(bytes : &[u8]) -> crate::error::Result<(Vec<u8>, &[u8])>
+{
+  let (content, rest) = read_der_element(bytes, DER_INTEGER_TAG)?;
+  match content
+  {
+    [] => Err(Dstu4145Error::InvalidSignatureEncoding("empty INTEGER".to_string())),
+    [first, ..] if first & 0x80 != 0 => Err(Dstu4145Error::InvalidSignatureEncoding(
+      "negative INTEGER".to_string(),
+    )),
+    [0, second, ..] if second & 0x80 == 0 => Err(Dstu4145Error::InvalidSignatureEncoding(
+      "non-minimal INTEGER".to_string(),
+    )),
+    _ => Ok((BigUint::from_bytes_be(content).to_bytes_be(), rest)),
+  }
+}
+
+/// Function converts hash into the number $e$ from the leftmost $\min(L(n), L(H))$ bits of the hash,
+/// according to the SEC1 (section `4.1.3`).
+fn hash_to_scalar<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, hash : &[u8]) -> EcScalar<T>
+{
+  let hash_bits = 8 * hash.len() as u64;
+  let n_bits = ec.get_ref_ord().bits();
+  let e = BigUint::from_bytes_be(hash);
+  if hash_bits > n_bits
+  {
+    EcScalar::reduce(ec, &(e >> (hash_bits - n_bits)))
+  }
+  else
+  {
+    EcScalar::reduce(ec, &e)
+  }
+}
+
+/// Function performs creating of the signature to the specific message.
+fn sign<'a, T : GFArithmetic<'a>, D : Digest>(
+  rng : &mut impl CryptoRngCore,
+  ec : &BinaryEC<T>,
+  digest : D,
+  d : &EcScalar<T>,
+  countermeasures : Countermeasures,
+) -> Signature
+{
+  let e = hash_to_scalar(ec, &digest.finalize());
+  loop
+  {
+    let (k, x_k) = calculate_presign(rng, ec, EcScalar::random_uniform_nonzero, countermeasures);
+    if let Some(signature) = sign_inner(ec, &k, x_k, &e, d)
+    {
+      return signature;
+    }
+  }
+}
+
+/// Function performs last step of signing $r = x_{kP} \bmod n$, $s = k^{-1}(e + dr) \bmod n$,
+/// returns `None` if either $r$ or $s$ is zero and another nonce has to be chosen.
+fn sign_inner<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  k : &EcScalar<T>,
+  x_k : T,
+  e : &EcScalar<T>,
+  d : &EcScalar<T>,
+) -> Option<Signature>
+{
+  let r = EcScalar::reduce(ec, &x_k.get_value());
+  let k_inv = match k.invert()
+  {
+    Some(k_inv) if !r.is_zero() => k_inv,
+    _ => return None,
+  };
+  let s = k_inv.mul(&e.add(&d.mul(&r)));
+  (!s.is_zero()).then(|| Signature {
+    r : r.to_bytes_be(),
+    s : s.to_bytes_be(),
+  })
+}
+
+/// Function verifies whether the given signature is correct.
+fn verify<'a, T : GFArithmetic<'a>, D : Digest>(
+  ec : &BinaryEC<T>,
+  base_point_table : &BasePointTable<T>,
+  digest : D,
+  q : &AffinePoint<T>,
+  r : &[u8],
+  s : &[u8],
+) -> crate::error::Result<()>
+{
+  let e = hash_to_scalar(ec, &digest.finalize());
+  // Check `r`, `s` validity
+  let r = EcScalar::from_bytes(ec, r).filter(|r| !r.is_zero()).ok_or_else(|| {
+    Dstu4145Error::InvalidParams(format!(
+      "Invalid r parameter, got: {}, has to be in range [1, n - 1], n: {}",
+      BigUint::from_bytes_be(r).to_str_radix(16),
+      ec.get_ord().to_str_radix(16)
+    ))
+  })?;
+  let s = EcScalar::from_bytes(ec, s).filter(|s| !s.is_zero()).ok_or_else(|| {
+    Dstu4145Error::InvalidParams(format!(
+      "Invalid s parameter, got: {}, has to be in range [1, n - 1], n: {}",
+      BigUint::from_bytes_be(s).to_str_radix(16),
+      ec.get_ord().to_str_radix(16)
+    ))
+  })?;
+  verify_inner(ec, base_point_table, q, &e, &r, &s)
+}
+
+/// Function performs last step of verifying signature: $R = (e s^{-1})P + (r s^{-1})Q$, $x_R \bmod n = r$.
+fn verify_inner<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  base_point_table : &BasePointTable<T>,
+  q : &AffinePoint<T>,
+  e : &EcScalar<T>,
+  r : &EcScalar<T>,
+  s : &EcScalar<T>,
+) -> crate::error::Result<()>
+{
+  let w = s.invert().ok_or(Dstu4145Error::IncorrectSignature)?;
+  let point = {
+    let u1_p = base_point_table.mul(ec, e.mul(&w));
+    let u2_q = ec.mul(q, r.mul(&w));
+    ec.add(&u1_p, &u2_q)
+  };
+  match point
+  {
+    AffinePoint::Point { x: x_r, .. } =>
+    {
+      if *r == EcScalar::reduce(ec, &x_r.get_value())
+      {
+        Ok(())
+      }
+      else
+      {
+        Err(Dstu4145Error::IncorrectSignature)
+      }
+    }
+    AffinePoint::Infinity => Err(Dstu4145Error::GotPointInInfinity),
+  }
+}
+
+#[cfg(test)]
+mod tests_from_openssl
+{
+  use signature::digest::Digest;
+  use poly_algebra::gf::{GFArithmetic, GF163, GF233Nist, GF283, GF409, GF571};
+  use rust_ec::affine_point::AffinePoint;
+  use rust_ec::binary_ec::BinaryEC;
+  use crate::ecdsa::{hash_to_scalar, sign_inner};
+  use crate::scalar::EcScalar;
+
+  /// Function signs message `sample` with the nonce $k$, that is restored from the deterministic signature
+  /// of OpenSSL as $k = s^{-1}(e + dr) \bmod n$, and compares result with that signature.
+  fn check_sign_inner<'a, T : GFArithmetic<'a>, D : Digest>(ec : BinaryEC<T>, d : &[u8], k : &[u8], der : &[u8])
+  {
+    let d = EcScalar::from_bytes(&ec, d).unwrap();
+    let k = EcScalar::from_bytes(&ec, k).unwrap();
+    let e = hash_to_scalar(&ec, &D::new_with_prefix(b"sample").finalize());
+    let AffinePoint::Point { x: x_k, .. } = ec.mul(ec.get_ref_bp(), &k)
+    else
+    {
+      panic!("kP can't be in infinity");
+    };
+    assert_eq!(sign_inner(&ec, &k, x_k, &e, &d).unwrap().to_der(), der);
+  }
+
+  #[test]
+  fn test_from_openssl()
+  {
+    check_sign_inner::<_, sha2::Sha256>(
+      BinaryEC::<GF163>::generate_k163_curve(),
+      &hex_literal::hex!("781F91EC00908E30D6155874BA84471E03FE835F"),
+      &hex_literal::hex!("03BD80355F7AA2A5DFF480B33E3E0EA11E83A6DA2A"),
+      &hex_literal::hex!("302D021500DA83F00E6BC61DFA28B242F4F15DE102B9231E2B02143A8FA8EE253AC82B04DDA4849CD5124F93DFC505"),
+    );
+    check_sign_inner::<_, sha2::Sha224>(
+      BinaryEC::<GF233Nist>::generate_b233_curve(),
+      &hex_literal::hex!("5EA672438368F75B79BC81A4D937B00E1B9309BFB15971782BA80C953D"),
+      &hex_literal::hex!("10693FAC7F444E0C310D097A871651A395923DD238086C4CD4204AF552"),
+      &hex_literal::hex!(
+        "3040021E00E14CB708E1B68825C0BC89453D0A6F872C667137B15915AB021C957DDD021E0085503E664CDBB051E269C3"
+        "8905F4D8A99C8CC561E3BE383D06C6F4F05C"
+      ),
+    );
+    check_sign_inner::<_, sha2::Sha256>(
+      BinaryEC::<GF283>::generate_k283_curve(),
+      &hex_literal::hex!("012ECE43FD648C587AA9988C521234CEABA1FC2ED8CE25BF9282353A423083026D28A414"),
+      &hex_literal::hex!("016D2BCAE5B870932C0D7DC6AB979A35C01738ED40E819EDE48BFFF4BE347AED9FBAB05B"),
+      &hex_literal::hex!(
+        "304C022400C61E768EC374D24602E3A401420621572F162152B0E19AFB2547025B84725F10C437DA022400CE62B7F29B"
+        "85E399D07701724351B90DDEA3F32997B823CC25631F1B64D0B3CBE51CC8"
+      ),
+    );
+    check_sign_inner::<_, sha2::Sha384>(
+      BinaryEC::<GF409>::generate_b409_curve(),
+      &hex_literal::hex!(
+        "FAF8C6A3691CA7998DF09D889489A7DD544A607350F8C71F86C0640F576636E4144E1B88D0A28F1C800AA8322A03B4B6"
+        "F8C20C"
+      ),
+      &hex_literal::hex!(
+        "28F36405FB546A77A5D48D77DDE890EEFD4FA44E4645B30FDDA407C5574BF1DCD82C56A16D07E907ACF495820533CABA"
+        "D48285"
+      ),
+      &hex_literal::hex!(
+        "306B023400C3DBBF0ED16A844F15F0FE1951B4C123C43AA1822984B9E445D96179D344B1BC6C72AA6F87CAF0B6515493"
+        "B6FF826C22F09B8E023304F8F21A18E31F3E9687142F2D731CE37B4BF15105819BC7115A44DB7115E5D8B967EE677268"
+        "38DE89AF3B73AF4946B63C3DA4"
+      ),
+    );
+    check_sign_inner::<_, sha2::Sha512>(
+      BinaryEC::<GF571>::generate_k571_curve(),
+      &hex_literal::hex!(
+        "01FFD3BA3131F521C5275FBAA4F63FEDA5DCD5D0970B7F31507D330523D5CBCB707B37AFFBF104A06B0639E3C8B7512D"
+        "601857C5C0AFC65DE09F0889020D641E4FF4CFC3E40463A1"
+      ),
+      &hex_literal::hex!(
+        "0ED9D3055536F6AD5E4B623724C65BDF451278EB02D23C4DEF57E96E05CF053F51E92579BA273E36CAA1A54123265341"
+        "09CBE5D18408A61BCA1298E60E374585FB8B15270046DE"
+      ),
+      &hex_literal::hex!(
+        "3081930248012C9D01D60E1D485C278C00C06F6BAB8699BD10FD2092469D0AA6CBA236046068250DEBCA0E89A4736971"
+        "6E365E3A5D3446C38A8C1EA2CC23CC822A8E13222152FFFB74D926CAB4024730DE31B4FC9089FF4C3579FCF098069EF2"
+        "51E4D48648CAF94AB484976E64762BBF2D39327709586D0CA8E377F05E09ECD5CF7B2DF6F9AEC4B35FB99E4652E0D10A"
+        "B7F213080D07"
+      ),
+    );
+  }
+}
@@ -0,0 +1,140 @@
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use signature::rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+use poly_algebra::gf::GFArithmetic;
+use rust_ec::binary_ec::BinaryEC;
+use rust_ec::registry::DstuPresetField;
+use crate::ecdh::{EphemeralSecret, SharedSecret};
+use crate::error::Dstu4145Error;
+use crate::sign::{SigningKey, VerifyingKey};
+
+/// Version of the ciphertext format.
+pub const ECIES_VERSION : u8 = 1;
+/// Identifier of the suite: HKDF-SHA256 as KDF and ChaCha20-Poly1305 as AEAD.
+pub const ECIES_SUITE_HKDF_SHA256_CHACHA20_POLY1305 : u8 = 1;
+/// Length of the header: version, suite, index of the EC preset.
+pub const ECIES_HEADER_LEN : usize = 3;
+/// Length of the AEAD authentication tag.
+pub const ECIES_TAG_LEN : usize = 16;
+/// Label that is used as prefix of the `info` in the KDF.
+const ECIES_KDF_LABEL : &[u8] = b"DSTU4145-ECIES";
+const KEY_LEN : usize = 32;
+const NONCE_LEN : usize = 12;
+
+/// Function encrypts message to the holder of the key pair, ciphertext has next format:
+/// `version (1 byte) || suite (1 byte) || curve (1 byte) || packed ephemeral point (⌈m/8⌉ bytes) || AEAD ciphertext || tag (16 bytes)`,
+/// where curve is [index](rust_ec::registry::DstuCurve::index) of the preset. Only EC presets from the [registry](rust_ec::registry) are supported.
+/// Ephemeral key is generated for every message, shared secret is calculated with help of [EphemeralSecret::diffie_hellman_with_verifying_key],
+/// key and nonce for AEAD are derived with HKDF from the shared secret, OID of the EC, header and ephemeral point.
+/// `associated_data` is authenticated, but isn't included into the ciphertext.
+pub fn encrypt<'a, T : GFArithmetic<'a> + DstuPresetField>(
+  rng : &mut impl CryptoRngCore,
+  verifying_key : &VerifyingKey<T>,
+  msg : &[u8],
+  associated_data : &[u8],
+) -> crate::error::Result<Vec<u8>>
+{
+  check_preset(verifying_key.get_ref_ec())?;
+  let ephemeral = EphemeralSecret::generate(rng, verifying_key.get_ref_ec().clone());
+  let mut ciphertext = header::<T>().to_vec();
+  ciphertext.extend(pack_fixed::<T>(&ephemeral.pack()));
+  let shared_secret = ephemeral.diffie_hellman_with_verifying_key(verifying_key)?;
+  let (cipher, nonce) = derive_cipher::<T>(&shared_secret, &ciphertext);
+  let encrypted = cipher
+    .encrypt(&nonce, Payload {
+      msg,
+      aad : associated_data,
+    })
+    .map_err(|_| Dstu4145Error::InvalidParams("Failed to encrypt message".to_string()))?;
+  ciphertext.extend(encrypted);
+  Ok(ciphertext)
+}
+
+/// Function decrypts ciphertext produced by [encrypt]. Header has to correspond to the EC preset of the key,
+/// ephemeral point is checked according to the algorithm `10.1`, so points of small order and points
+/// that don't belong to the EC are rejected before the key agreement.
+pub fn decrypt<'a, T : GFArithmetic<'a> + DstuPresetField>(
+  signing_key : &SigningKey<T>,
+  ciphertext : &[u8],
+  associated_data : &[u8],
+) -> crate::error::Result<Vec<u8>>
+{
+  check_preset(signing_key.get_ref_ec())?;
+  let point_len = T::get_m().div_ceil(8) as usize;
+  if ciphertext.len() < ECIES_HEADER_LEN + point_len + ECIES_TAG_LEN
+  {
+    return Err(Dstu4145Error::InvalidCiphertext(format!(
+      "too short, got {} bytes, has to be at least {}",
+      ciphertext.len(),
+      ECIES_HEADER_LEN + point_len + ECIES_TAG_LEN
+    )));
+  }
+  let (header, rest) = ciphertext.split_at(ECIES_HEADER_LEN);
+  if header[0] != ECIES_VERSION
+  {
+    return Err(Dstu4145Error::InvalidCiphertext(format!("unsupported version {}", header[0])));
+  }
+  if header[1] != ECIES_SUITE_HKDF_SHA256_CHACHA20_POLY1305
+  {
+    return Err(Dstu4145Error::InvalidCiphertext(format!("unsupported suite {}", header[1])));
+  }
+  if header[2] != T::CURVE.index()
+  {
+    return Err(Dstu4145Error::InvalidCiphertext(format!(
+      "ciphertext is made for EC preset {}, but key is for {}",
+      header[2],
+      T::CURVE.index()
+    )));
+  }
+  let (ephemeral_pub_key, encrypted) = rest.split_at(point_len);
+  let shared_secret = signing_key.diffie_hellman(ephemeral_pub_key)?;
+  let (cipher, nonce) = derive_cipher::<T>(&shared_secret, &ciphertext[.. ECIES_HEADER_LEN + point_len]);
+  cipher
+    .decrypt(&nonce, Payload {
+      msg : encrypted,
+      aad : associated_data,
+    })
+    .map_err(|_| Dstu4145Error::DecryptionFailed)
+}
+
+/// Function checks that EC of the key is the preset defined over the field.
+fn check_preset<'a, T : GFArithmetic<'a> + DstuPresetField>(ec : &BinaryEC<T>) -> crate::error::Result<()>
+{
+  match ec.oid()
+  {
+    Some(_) => Ok(()),
+    None => Err(Dstu4145Error::InvalidParams("ECIES is supported only for the EC presets from the registry".to_string())),
+  }
+}
+
+/// Function forms header of the ciphertext for the EC preset.
+fn header<T : DstuPresetField>() -> [u8; ECIES_HEADER_LEN]
+{
+  [ECIES_VERSION, ECIES_SUITE_HKDF_SHA256_CHACHA20_POLY1305, T::CURVE.index()]
+}
+
+/// Function pads packed point with leading zeros to the length $\lceil m/8 \rceil$.
+fn pack_fixed<'a, T : GFArithmetic<'a>>(packed : &[u8]) -> Vec<u8>
+{
+  let mut buf = vec![0; T::get_m().div_ceil(8) as usize - packed.len()];
+  buf.extend(packed);
+  buf
+}
+
+/// Function derives AEAD key and nonce from the shared secret, where OID of the EC, header and ephemeral point are used as `info`,
+/// so every ciphertext is bound to its EC and its own ephemeral key. Output of the KDF is wiped after the cipher is initialized.
+fn derive_cipher<T : DstuPresetField>(shared_secret : &SharedSecret, context : &[u8]) -> (ChaCha20Poly1305, Nonce)
+{
+  let hkdf = Hkdf::<Sha256>::new(None, shared_secret.as_bytes());
+  let mut okm = [0; KEY_LEN + NONCE_LEN];
+  hkdf
+    .expand_multi_info(&[ECIES_KDF_LABEL, T::CURVE.oid().as_bytes(), context], &mut okm)
+    .expect("output length is less than 255 hash lengths");
+  let cipher = ChaCha20Poly1305::new(Key::from_slice(&okm[.. KEY_LEN]));
+  let nonce = *Nonce::from_slice(&okm[KEY_LEN ..]);
+  okm.zeroize();
+  (cipher, nonce)
+}
@@ -1,5 +1,6 @@
 use signature::Error;
 use thiserror::Error;
+use rust_ec::error::EcError;
 
 pub type Result<T> = core::result::Result<T, Dstu4145Error>;
 
@@ -20,8 +21,20 @@ pub enum Dstu4145Error
   IncorrectSignature,
   #[error("Failed to parse hex string, check validity of it.")]
   ParseBigIntError(#[from] num_bigint::ParseBigIntError),
+  #[error("Got point in infinity as shared secret, key agreement failed.")]
+  SharedSecretIsInfinity,
+  #[error("Invalid ciphertext, error: '{0}'.")]
+  InvalidCiphertext(String),
+  #[error("Failed to decrypt ciphertext, authentication tag doesn't match.")]
+  DecryptionFailed,
+  #[error("Invalid signature encoding, error: '{0}'.")]
+  InvalidSignatureEncoding(String),
   #[error("Failed to check correctness of public key, error: '{0}'")]
   FailedPublicKeyCheck(String),
+  #[error("Scalars are reduced by different orders: {0} and {1}.")]
+  ScalarOrderMismatch(String, String),
+  #[error("Got EC error: {0}")]
+  Ec(#[from] EcError),
 }
 
 impl From<Dstu4145Error> for signature::Error
@@ -1,20 +1,50 @@
 use num_bigint::BigUint;
 use num_traits::One;
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
 use signature::rand_core::CryptoRngCore;
 use poly_algebra::gf::GFArithmetic;
-use poly_algebra::helpers::generate_num;
 use rust_ec::affine_point::AffinePoint;
 use rust_ec::binary_ec::BinaryEC;
-use rust_ec::helpers::unpack_affine_point;
+use rust_ec::countermeasures::Countermeasures;
+use rust_ec::error::EcError;
+use rust_ec::helpers::{point_from_sec1, try_unpack_affine_point};
 use crate::error::Dstu4145Error;
+use crate::scalar::EcScalar;
 
-/// Function calculates presign and returns tuple `(e, F_e)`
-pub fn calculate_presign<'a, T : GFArithmetic<'a>>(rng : &mut impl CryptoRngCore, ec : &BinaryEC<T>) -> (BigUint, T)
+/// Function generates secret $d$ with `random_scalar` (e.g. [EcScalar::random_nonzero] for DSTU 4145-2002)
+/// and calculates point $dP$ with [Countermeasures::DEFAULT].
+pub fn generate_key_pair<'a, T : GFArithmetic<'a>, R : CryptoRngCore>(
+  rng : &mut R,
+  ec : &BinaryEC<T>,
+  random_scalar : fn(&mut R, &BinaryEC<T>) -> EcScalar<T>,
+) -> (EcScalar<T>, AffinePoint<T>)
+{
+  let d = random_scalar(rng, ec);
+  let q = ec.mul_secret(ec.get_ref_bp(), &d, Countermeasures::DEFAULT, rng);
+  (d, q)
+}
+
+/// Function calculates point $dP$ for the pregenerated secret $d$ with [Countermeasures::DEFAULT].
+pub fn calculate_public_point<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, d : &EcScalar<T>) -> AffinePoint<T>
+{
+  let mut rng = ChaCha20Rng::from_entropy();
+  ec.mul_secret(ec.get_ref_bp(), d, Countermeasures::DEFAULT, &mut rng)
+}
+
+/// Function calculates presign and returns tuple `(e, F_e)`, secret $e$ is generated with `random_scalar`
+/// and multiplied with the given countermeasures.
+pub fn calculate_presign<'a, T : GFArithmetic<'a>, R : CryptoRngCore>(
+  rng : &mut R,
+  ec : &BinaryEC<T>,
+  random_scalar : fn(&mut R, &BinaryEC<T>) -> EcScalar<T>,
+  countermeasures : Countermeasures,
+) -> (EcScalar<T>, T)
 {
   loop
   {
-    let e = generate_num(rng, ec.get_ref_ord().bits() - 1);
-    let r = ec.get_ref_bp().mul(ec, e.clone());
+    let e = random_scalar(rng, ec);
+    let r = ec.mul_secret(ec.get_ref_bp(), &e, countermeasures, rng);
     if let AffinePoint::Point { x: x_p, .. } = r
     {
       if !x_p.is_zero()
@@ -33,30 +63,32 @@ pub fn transform_field_poly_into_number<T1 : Into<BigUint>, T2 : Into<BigUint>>(
 }
 
 /// Function checks correctness of the public key according to the algorithm `10.1`.
+/// Related to function [try_unpack_affine_point].
 pub fn check_public_key_correctness<'a, T : GFArithmetic<'a>>(
   ec : &BinaryEC<T>,
   compressed_p : &T,
 ) -> crate::error::Result<AffinePoint<T>>
 {
-  let decompressed_point = unpack_affine_point(compressed_p, ec);
-  if decompressed_point == AffinePoint::Infinity
-  {
-    return Err(Dstu4145Error::FailedPublicKeyCheck(
-      "public key can't be a point at infinity".to_string(),
-    ));
-  }
-  if !ec.check_affine_point(&decompressed_point)
+  try_unpack_affine_point(compressed_p, ec).map_err(|err| Dstu4145Error::FailedPublicKeyCheck(err.to_string()))
+}
+
+/// Function decodes public key from SEC1 encoding and checks its correctness the same way as
+/// [check_public_key_correctness], i.e. point isn't $O$ and belongs to the subgroup of order $n$.
+pub fn check_sec1_public_key_correctness<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  bytes : &[u8],
+) -> crate::error::Result<AffinePoint<T>>
+{
+  let point = point_from_sec1(bytes, ec).map_err(|err| Dstu4145Error::FailedPublicKeyCheck(err.to_string()))?;
+  if point.is_inf()
   {
-    return Err(Dstu4145Error::FailedPublicKeyCheck(format!(
-      "{decompressed_point:X} doesn't belong to the curve"
-    )));
+    return Err(Dstu4145Error::FailedPublicKeyCheck(EcError::PointIsInfinity.to_string()));
   }
-  if ec.mul(&decompressed_point, ec.get_ord()) != AffinePoint::Infinity
+  if !ec.is_in_prime_subgroup(&point)
   {
-    return Err(Dstu4145Error::FailedPublicKeyCheck(format!(
-      "incorrect order, order of Q:'{decompressed_point:X}' != {:X}",
-      ec.get_ord()
-    )));
+    return Err(Dstu4145Error::FailedPublicKeyCheck(
+      EcError::WrongPointOrder(ec.get_ref_ord().to_str_radix(16)).to_string(),
+    ));
   }
-  Ok(decompressed_point)
+  Ok(point)
 }
@@ -1,4 +1,8 @@
 #![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
+pub mod ecdh;
+pub mod ecdsa;
+pub mod ecies;
 pub mod error;
 mod helpers;
+pub mod scalar;
 pub mod sign;
@@ -0,0 +1,333 @@
+//! Scalars modulo the order $n$ of the base point, that are used for secret keys, nonces and signature values.
+//!
+//! Value of the scalar is always in range $[0, n)$, it's kept in the little-endian 64-bit limbs of the fixed length
+//! $\lceil L(n) / 64 \rceil$, so comparison doesn't depend on the value and limbs are wiped on drop.
+//! Arithmetic is performed with help of [BigUint], _temporary numbers of it aren't wiped_.
+//!
+//! [Scalar] is tied to the EC preset by the field `T` ([DstuPresetField]), so scalars of different presets can't be mixed
+//! and arithmetic on them doesn't fail. ECs over the same field (e.g. M163 and K-163 over `GF163`) have the same type,
+//! so keys over arbitrary EC keep their scalars as [EcScalar], which is tied to its EC by the order $n$ it keeps.
+//! [EcScalar] of the preset is converted into [Scalar] with [TryFrom], scalars of other EC's are rejected with
+//! [Dstu4145Error::ScalarOrderMismatch]. With feature `group` [Scalar] is converted from and into [PresetScalar].
+use std::fmt;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use signature::rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+use poly_algebra::gf::GFArithmetic;
+use poly_algebra::helpers::generate_num;
+use rust_ec::binary_ec::BinaryEC;
+#[cfg(feature = "group")]
+use rust_ec::preset_group::{PresetScalar, PresetScalarParams};
+use rust_ec::registry::DstuPresetField;
+use crate::error::{Dstu4145Error, Result};
+
+/// Element of $\mathbb{Z}_n$, where $n$ is the order of the base point of the EC over the field `T`,
+/// which is chosen at runtime. It's used by the keys over arbitrary EC, arithmetic on it is performed inside the crate.
+#[derive(Clone)]
+pub struct EcScalar<T>
+{
+  limbs : Vec<u64>,
+  n : BigUint,
+  _field : PhantomData<fn() -> T>,
+}
+
+/// Element of $\mathbb{Z}_n$, where $n$ is the order of the base point of the EC preset over the field `T`.
+/// Scalars of different presets have different types, so they can't be mixed.
+///```compile_fail
+/// # use poly_algebra::gf::{GF163, GF167};
+/// # use dstu4145_rust::scalar::Scalar;
+/// let x = &Scalar::<GF163>::one() + &Scalar::<GF167>::one();
+/// ```
+#[derive(Clone)]
+pub struct Scalar<T>(EcScalar<T>);
+
+impl<'a, T : GFArithmetic<'a>> EcScalar<T>
+{
+  pub fn zero(ec : &BinaryEC<T>) -> Self { EcScalar::from_reduced(BigUint::zero(), ec.get_ord()) }
+
+  /// Function reduces number modulo $n$.
+  pub fn reduce(ec : &BinaryEC<T>, value : &BigUint) -> Self { EcScalar::from_reduced(value % ec.get_ref_ord(), ec.get_ord()) }
+
+  /// Function reduces big-endian bytes modulo $n$.
+  pub fn from_bytes_reduced(ec : &BinaryEC<T>, bytes : &[u8]) -> Self { EcScalar::reduce(ec, &BigUint::from_bytes_be(bytes)) }
+
+  /// Function restores scalar from big-endian bytes, returns `None` if number isn't less than $n$.
+  pub fn from_bytes(ec : &BinaryEC<T>, bytes : &[u8]) -> Option<Self>
+  {
+    let value = BigUint::from_bytes_be(bytes);
+    (value < *ec.get_ref_ord()).then(|| EcScalar::from_reduced(value, ec.get_ord()))
+  }
+
+  /// Function generates non-zero scalar with $L(n) - 1$ bit len, which is used for DSTU 4145-2002 secret keys and nonces.
+  pub fn random_nonzero(rng : &mut impl CryptoRngCore, ec : &BinaryEC<T>) -> Self
+  {
+    loop
+    {
+      let value = generate_num(rng, ec.get_ref_ord().bits() - 1);
+      if !value.is_zero()
+      {
+        return EcScalar::from_reduced(value, ec.get_ord());
+      }
+    }
+  }
+
+  /// Function generates scalar uniformly in $[1, n - 1]$ with rejection sampling of $L(n)$-bit numbers
+  /// (FIPS 186-5, A.2.2), which is used for ECDSA secret keys and nonces.
+  pub fn random_uniform_nonzero(rng : &mut impl CryptoRngCore, ec : &BinaryEC<T>) -> Self
+  {
+    loop
+    {
+      let value = generate_num(rng, ec.get_ref_ord().bits());
+      if !value.is_zero() && value < *ec.get_ref_ord()
+      {
+        return EcScalar::from_reduced(value, ec.get_ord());
+      }
+    }
+  }
+
+  fn from_reduced(value : BigUint, n : BigUint) -> Self
+  {
+    let mut limbs = vec![0; n.bits().div_ceil(64) as usize];
+    for (limb, digit) in limbs.iter_mut().zip(value.iter_u64_digits())
+    {
+      *limb = digit;
+    }
+    EcScalar {
+      limbs,
+      n,
+      _field : PhantomData,
+    }
+  }
+
+  /// Function gets the order $n$, which scalar is reduced by.
+  pub fn get_ref_modulus(&self) -> &BigUint { &self.n }
+
+  pub fn is_zero(&self) -> bool { self.limbs.iter().all(|limb| *limb == 0) }
+
+  /// Function calculates $a^{-1} = a^{n - 2} \bmod n$ by Fermat's little theorem, returns `None` for zero.
+  /// _Exponentiation is performed with [BigUint::modpow], which isn't constant time._
+  pub fn invert(&self) -> Option<Self>
+  {
+    let inverse = EcScalar::from_reduced(self.to_biguint().modpow(&(&self.n - 2_u8), &self.n), self.n.clone());
+    (!inverse.is_zero()).then_some(inverse)
+  }
+
+  pub fn to_biguint(&self) -> BigUint
+  {
+    BigUint::new(self.limbs.iter().flat_map(|limb| [*limb as u32, (limb >> 32) as u32]).collect())
+  }
+
+  /// Function converts scalar into big-endian bytes without leading zeros, the same as [BigUint::to_bytes_be].
+  pub fn to_bytes_be(&self) -> Vec<u8> { self.to_biguint().to_bytes_be() }
+
+  /// Function calculates $a + b \bmod n$, scalars have to be reduced by the same $n$.
+  pub(crate) fn add(&self, rhs : &Self) -> Self { self.apply(rhs, |a, b| a + b) }
+
+  /// Function calculates $a - b \bmod n$, scalars have to be reduced by the same $n$.
+  pub(crate) fn sub(&self, rhs : &Self) -> Self { self.apply(rhs, |a, b| a + &self.n - b) }
+
+  /// Function calculates $a \cdot b \bmod n$, scalars have to be reduced by the same $n$.
+  pub(crate) fn mul(&self, rhs : &Self) -> Self { self.apply(rhs, |a, b| a * b) }
+
+  /// Function calculates $-a \bmod n$.
+  pub(crate) fn neg(&self) -> Self { EcScalar::from_reduced((&self.n - self.to_biguint()) % &self.n, self.n.clone()) }
+
+  fn apply(&self, rhs : &Self, f : impl FnOnce(BigUint, BigUint) -> BigUint) -> Self
+  {
+    debug_assert_eq!(self.n, rhs.n, "scalars have to be reduced by the same order");
+    EcScalar::from_reduced(f(self.to_biguint(), rhs.to_biguint()) % &self.n, self.n.clone())
+  }
+}
+
+impl<'a, T : GFArithmetic<'a> + DstuPresetField> Scalar<T>
+{
+  pub fn zero() -> Self { Scalar(EcScalar::zero(T::preset())) }
+
+  pub fn one() -> Self { Scalar::reduce(&BigUint::from(1_u8)) }
+
+  /// Function reduces number modulo $n$.
+  pub fn reduce(value : &BigUint) -> Self { Scalar(EcScalar::reduce(T::preset(), value)) }
+
+  /// Function reduces big-endian bytes modulo $n$.
+  pub fn from_bytes_reduced(bytes : &[u8]) -> Self { Scalar(EcScalar::from_bytes_reduced(T::preset(), bytes)) }
+
+  /// Function restores scalar from big-endian bytes, returns `None` if number isn't less than $n$.
+  pub fn from_bytes(bytes : &[u8]) -> Option<Self> { EcScalar::from_bytes(T::preset(), bytes).map(Scalar) }
+
+  /// Function generates non-zero scalar with $L(n) - 1$ bit len, the same as [EcScalar::random_nonzero].
+  pub fn random_nonzero(rng : &mut impl CryptoRngCore) -> Self { Scalar(EcScalar::random_nonzero(rng, T::preset())) }
+
+  /// Function generates scalar uniformly in $[1, n - 1]$, the same as [EcScalar::random_uniform_nonzero].
+  pub fn random_uniform_nonzero(rng : &mut impl CryptoRngCore) -> Self
+  {
+    Scalar(EcScalar::random_uniform_nonzero(rng, T::preset()))
+  }
+
+  /// Function gets the order $n$ of the preset.
+  pub fn get_ref_modulus(&self) -> &BigUint { self.0.get_ref_modulus() }
+
+  pub fn is_zero(&self) -> bool { self.0.is_zero() }
+
+  /// Function calculates $a^{-1} \bmod n$, the same as [EcScalar::invert].
+  pub fn invert(&self) -> Option<Self> { self.0.invert().map(Scalar) }
+
+  pub fn to_biguint(&self) -> BigUint { self.0.to_biguint() }
+
+  /// Function converts scalar into big-endian bytes without leading zeros, the same as [BigUint::to_bytes_be].
+  pub fn to_bytes_be(&self) -> Vec<u8> { self.0.to_bytes_be() }
+}
+
+impl<'a, T : GFArithmetic<'a>> From<&EcScalar<T>> for BigUint
+{
+  fn from(value : &EcScalar<T>) -> Self { value.to_biguint() }
+}
+
+impl<'a, T : GFArithmetic<'a>> From<EcScalar<T>> for BigUint
+{
+  fn from(value : EcScalar<T>) -> Self { value.to_biguint() }
+}
+
+impl<'a, T : GFArithmetic<'a>> From<&Scalar<T>> for BigUint
+{
+  fn from(value : &Scalar<T>) -> Self { value.0.to_biguint() }
+}
+
+impl<'a, T : GFArithmetic<'a>> From<Scalar<T>> for BigUint
+{
+  fn from(value : Scalar<T>) -> Self { value.0.to_biguint() }
+}
+
+impl<T> From<Scalar<T>> for EcScalar<T>
+{
+  fn from(value : Scalar<T>) -> Self { value.0 }
+}
+
+impl<'a, T : GFArithmetic<'a> + DstuPresetField> TryFrom<&EcScalar<T>> for Scalar<T>
+{
+  type Error = Dstu4145Error;
+
+  /// Function converts scalar of the preset over the field `T`, scalars of other EC's are rejected.
+  fn try_from(value : &EcScalar<T>) -> Result<Self>
+  {
+    let n = T::preset().get_ref_ord();
+    if value.n != *n
+    {
+      return Err(Dstu4145Error::ScalarOrderMismatch(value.n.to_str_radix(16), n.to_str_radix(16)));
+    }
+    Ok(Scalar(value.clone()))
+  }
+}
+
+impl<'a, T : GFArithmetic<'a> + DstuPresetField> Add for &Scalar<T>
+{
+  type Output = Scalar<T>;
+
+  fn add(self, rhs : Self) -> Scalar<T> { Scalar(self.0.add(&rhs.0)) }
+}
+
+impl<'a, T : GFArithmetic<'a> + DstuPresetField> Sub for &Scalar<T>
+{
+  type Output = Scalar<T>;
+
+  fn sub(self, rhs : Self) -> Scalar<T> { Scalar(self.0.sub(&rhs.0)) }
+}
+
+impl<'a, T : GFArithmetic<'a> + DstuPresetField> Mul for &Scalar<T>
+{
+  type Output = Scalar<T>;
+
+  fn mul(self, rhs : Self) -> Scalar<T> { Scalar(self.0.mul(&rhs.0)) }
+}
+
+impl<'a, T : GFArithmetic<'a> + DstuPresetField> Neg for &Scalar<T>
+{
+  type Output = Scalar<T>;
+
+  fn neg(self) -> Scalar<T> { Scalar(self.0.neg()) }
+}
+
+#[cfg(feature = "group")]
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> From<PresetScalar<T>> for Scalar<T>
+{
+  fn from(value : PresetScalar<T>) -> Self { Scalar::reduce(&value.to_biguint()) }
+}
+
+#[cfg(feature = "group")]
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> From<&Scalar<T>> for PresetScalar<T>
+{
+  fn from(value : &Scalar<T>) -> Self { PresetScalar::from_biguint(&value.to_biguint()) }
+}
+
+impl<T> ConstantTimeEq for EcScalar<T>
+{
+  /// Function compares limbs without dependency on the value, scalars of different length are unequal.
+  fn ct_eq(&self, other : &Self) -> Choice { self.limbs.ct_eq(&other.limbs) & Choice::from((self.n == other.n) as u8) }
+}
+
+impl<T> ConstantTimeEq for Scalar<T>
+{
+  fn ct_eq(&self, other : &Self) -> Choice { self.0.ct_eq(&other.0) }
+}
+
+impl<T> PartialEq for EcScalar<T>
+{
+  fn eq(&self, other : &Self) -> bool { self.ct_eq(other).into() }
+}
+
+impl<T> Eq for EcScalar<T> {}
+
+impl<T> PartialEq for Scalar<T>
+{
+  fn eq(&self, other : &Self) -> bool { self.ct_eq(other).into() }
+}
+
+impl<T> Eq for Scalar<T> {}
+
+impl<T> Hash for EcScalar<T>
+{
+  fn hash<H : Hasher>(&self, state : &mut H)
+  {
+    self.limbs.hash(state);
+    self.n.hash(state);
+  }
+}
+
+impl<T> Hash for Scalar<T>
+{
+  fn hash<H : Hasher>(&self, state : &mut H) { self.0.hash(state) }
+}
+
+impl<T> fmt::Debug for EcScalar<T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { write!(f, "EcScalar {{ .. }}") }
+}
+
+impl<T> fmt::Debug for Scalar<T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { write!(f, "Scalar {{ .. }}") }
+}
+
+impl<T> Zeroize for EcScalar<T>
+{
+  fn zeroize(&mut self) { self.limbs.zeroize() }
+}
+
+impl<T> Zeroize for Scalar<T>
+{
+  fn zeroize(&mut self) { self.0.zeroize() }
+}
+
+impl<T> Drop for EcScalar<T>
+{
+  fn drop(&mut self) { self.zeroize() }
+}
+
+impl<T> ZeroizeOnDrop for EcScalar<T> {}
+
+impl<T> ZeroizeOnDrop for Scalar<T> {}
@@ -1,5 +1,6 @@
+use std::sync::Arc;
 use num_bigint::BigUint;
-use num_traits::{ToBytes, Zero};
+use num_traits::Zero;
 use rand_chacha::{ChaCha20Rng};
 use rand_chacha::rand_core::SeedableRng;
 use signature::{DigestSigner, DigestVerifier, Error, RandomizedSigner, Signer, Verifier};
@@ -7,11 +8,16 @@ use signature::digest::Digest;
 use signature::rand_core::CryptoRngCore;
 use bytes::{BytesMut};
 use poly_algebra::gf::GFArithmetic;
-use poly_algebra::helpers::{create_field_el_from_hash, generate_num};
+use poly_algebra::helpers::create_field_el_from_hash;
 use rust_ec::affine_point::AffinePoint;
+use rust_ec::base_point_table::{BasePointTable, BasePointTableCache};
 use rust_ec::binary_ec::BinaryEC;
+use rust_ec::countermeasures::Countermeasures;
 use crate::error::Dstu4145Error;
-use crate::helpers::{calculate_presign, check_public_key_correctness, transform_field_poly_into_number};
+use crate::helpers::{
+  calculate_presign, calculate_public_point, check_public_key_correctness, generate_key_pair, transform_field_poly_into_number,
+};
+use crate::scalar::EcScalar;
 
 /// Struct saves signature info as output structure.
 /// It contains in itself $(r, s)$ values with $L_d$ parameter.
@@ -25,32 +31,40 @@ pub struct Signature
 
 /// Struct that characterize __Private key__ for making digital signature.
 /// It consists of saved EC, affine point $Q$ on the EC, $L_d$ parameter.
+/// EC is shared between keys with help of [Arc], so keys are cheap to clone.
+/// Multiples of the base point are precomputed on the first verification and shared between clones of the key.
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct VerifyingKey<T>
 {
-  ec : BinaryEC<T>,
+  ec : Arc<BinaryEC<T>>,
   q : AffinePoint<T>,
   l_d : u64,
+  base_point_table : BasePointTableCache<T>,
 }
 
 /// Struct designed for restoring Verifying key from packed EC point
 /// It consists from packed value of affine point in the GF element, $L_d$ parameter and EC.
+/// _EC is passed as [Arc] since keys share it, previously it was passed by value as [BinaryEC]._
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct VerifyingKeyConstructor<T>
 {
-  pub ec : BinaryEC<T>,
+  pub ec : Arc<BinaryEC<T>>,
   pub q : T,
   pub l_d : u64,
 }
 
 /// Struct that characterize __Public key__ for checking digital signature.
-/// It consists from secred $d$ value with $L(n) - 1$ bit len, $L_d$ parameter and EC.
+/// It consists from secred $d$ value with $L(n) - 1$ bit len as [EcScalar], $L_d$ parameter and EC.
+/// EC is shared between keys with help of [Arc], so keys are cheap to clone.
+/// Multiplications on secret scalars are performed with [Countermeasures::DEFAULT] unless it's changed
+/// with [SigningKey::with_countermeasures].
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct SigningKey<T>
 {
-  ec : BinaryEC<T>,
-  d : BigUint,
+  ec : Arc<BinaryEC<T>>,
+  d : EcScalar<T>,
   l_d : u64,
+  countermeasures : Countermeasures,
 }
 impl<'a, T : GFArithmetic<'a>> Verifier<Signature> for VerifyingKey<T>
 {
@@ -60,14 +74,15 @@ impl<'a, T : GFArithmetic<'a>> Verifier<Signature> for VerifyingKey<T>
     digest.update(msg);
     if signature.l_d != self.l_d
     {
-      let e : Box<(dyn std::error::Error + Send + Sync + 'static)> = Box::new(Dstu4145Error::InvalidParams(format!(
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(Dstu4145Error::InvalidParams(format!(
         "Unequal L_D param in both structs, VerifyingKey.l_d: {}, Signature.l_d: {}",
         self.l_d, signature.l_d
       )));
       return Err(Error::from(e));
     }
-    verify(&self.ec, digest, &self.q, &signature.r, &signature.s, signature.l_d).map_err(|e| {
-      let e : Box<(dyn std::error::Error + Send + Sync + 'static)> = Box::new(e);
+    let base_point_table = self.base_point_table.get_or_init(&self.ec);
+    verify(&self.ec, base_point_table, digest, &self.q, &signature.r, &signature.s, signature.l_d).map_err(|e| {
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(e);
       Error::from(e)
     })
   }
@@ -79,14 +94,15 @@ impl<'a, T : GFArithmetic<'a>, D : Digest> DigestVerifier<D, Signature> for Veri
   {
     if signature.l_d != self.l_d
     {
-      let e : Box<(dyn std::error::Error + Send + Sync + 'static)> = Box::new(Dstu4145Error::InvalidParams(format!(
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(Dstu4145Error::InvalidParams(format!(
         "Unequal L_D param in both structs, VerifyingKey.l_d: {}, Signature.l_d: {}",
         self.l_d, signature.l_d
       )));
       return Err(Error::from(e));
     }
-    verify(&self.ec, digest, &self.q, &signature.r, &signature.s, signature.l_d).map_err(|e| {
-      let e : Box<(dyn std::error::Error + Send + Sync + 'static)> = Box::new(e);
+    let base_point_table = self.base_point_table.get_or_init(&self.ec);
+    verify(&self.ec, base_point_table, digest, &self.q, &signature.r, &signature.s, signature.l_d).map_err(|e| {
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(e);
       Error::from(e)
     })
   }
@@ -98,8 +114,8 @@ impl<'a, T : GFArithmetic<'a>> Signer<Signature> for SigningKey<T>
     let mut rng = ChaCha20Rng::from_entropy();
     let mut digest = sha3::Sha3_512::new();
     digest.update(msg);
-    sign(&mut rng, &self.ec, digest, &self.d, self.l_d).map_err(|e| {
-      let e : Box<(dyn std::error::Error + Send + Sync + 'static)> = Box::new(e);
+    sign(&mut rng, &self.ec, digest, &self.d, self.l_d, self.countermeasures).map_err(|e| {
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(e);
       Error::from(e)
     })
   }
@@ -110,8 +126,8 @@ impl<'a, T : GFArithmetic<'a>> RandomizedSigner<Signature> for SigningKey<T>
   {
     let mut digest = sha3::Sha3_512::new();
     digest.update(msg);
-    sign(rng, &self.ec, digest, &self.d, self.l_d).map_err(|e| {
-      let e : Box<(dyn std::error::Error + Send + Sync + 'static)> = Box::new(e);
+    sign(rng, &self.ec, digest, &self.d, self.l_d, self.countermeasures).map_err(|e| {
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(e);
       Error::from(e)
     })
   }
@@ -122,8 +138,8 @@ impl<'a, T : GFArithmetic<'a>, D : Digest> DigestSigner<D, Signature> for Signin
   fn try_sign_digest(&self, digest : D) -> Result<Signature, Error>
   {
     let mut rng = ChaCha20Rng::from_entropy();
-    sign(&mut rng, &self.ec, digest, &self.d, self.l_d).map_err(|e| {
-      let e : Box<(dyn std::error::Error + Send + Sync + 'static)> = Box::new(e);
+    sign(&mut rng, &self.ec, digest, &self.d, self.l_d, self.countermeasures).map_err(|e| {
+      let e : Box<dyn std::error::Error + Send + Sync + 'static> = Box::new(e);
       Error::from(e)
     })
   }
@@ -132,41 +148,84 @@ impl<'a, T : GFArithmetic<'a>, D : Digest> DigestSigner<D, Signature> for Signin
 impl<'a, T : GFArithmetic<'a>> SigningKey<T>
 {
   /// Function gets a copy of private key. Be careful in using of this command!
-  pub fn get_private_key(&self) -> BigUint { self.d.clone() }
+  pub fn get_private_key(&self) -> EcScalar<T> { self.d.clone() }
+
+  /// Function gets reference to the shared EC.
+  pub fn get_ref_ec(&self) -> &Arc<BinaryEC<T>> { &self.ec }
+
+  /// Function gets countermeasures, that are used for multiplication on secret scalars.
+  pub fn get_countermeasures(&self) -> Countermeasures { self.countermeasures }
+
+  /// Function sets countermeasures, that are used for multiplication on secret scalars $d$ and $e$.
+  pub fn with_countermeasures(self, countermeasures : Countermeasures) -> Self { SigningKey { countermeasures, ..self } }
 
   /// Function generates __Private key__ from PRNG.
-  pub fn generate(rng : &mut impl CryptoRngCore, ec : BinaryEC<T>, l_d : u64) -> crate::error::Result<(Self, VerifyingKey<T>)>
+  /// EC can be passed either by value or as [Arc] that is already shared with other keys.
+  pub fn generate(
+    rng : &mut impl CryptoRngCore,
+    ec : impl Into<Arc<BinaryEC<T>>>,
+    l_d : u64,
+  ) -> crate::error::Result<(Self, VerifyingKey<T>)>
   {
+    let ec = ec.into();
     check_l_d_value(l_d, &ec)?;
-    loop
-    {
-      let d = generate_num(rng, ec.get_ref_ord().bits() - 1);
-      if !d.is_zero()
-      {
-        let q = ec.get_ref_bp().mul(&ec, d.clone()).negative();
-        return Ok((Self { ec : ec.clone(), d, l_d }, VerifyingKey { ec, q, l_d }));
-      }
-    }
+    let (d, q) = generate_key_pair(rng, &ec, EcScalar::random_nonzero);
+    let q = q.negative();
+    Ok((
+      Self {
+        ec : Arc::clone(&ec),
+        d,
+        l_d,
+        countermeasures : Countermeasures::DEFAULT,
+      },
+      VerifyingKey {
+        ec,
+        q,
+        l_d,
+        base_point_table : BasePointTableCache::new(),
+      },
+    ))
   }
 
   /// Function creates __Private key__ from given values that may be pregenerated.
-  pub fn from_secret<B : AsRef<[u8]>>(ec : BinaryEC<T>, d : B, l_d : u64) -> crate::error::Result<(Self, VerifyingKey<T>)>
+  pub fn from_secret<B : AsRef<[u8]>>(
+    ec : impl Into<Arc<BinaryEC<T>>>,
+    d : B,
+    l_d : u64,
+  ) -> crate::error::Result<(Self, VerifyingKey<T>)>
   {
+    let ec = ec.into();
     check_l_d_value(l_d, &ec)?;
     let desired_length = ec.get_ref_ord().bits() - 1;
-    let d = BigUint::from_bytes_be(d.as_ref());
-    if d.bits() > desired_length
+    let d_bits = BigUint::from_bytes_be(d.as_ref()).bits();
+    if d_bits > desired_length
     {
-      return Err(Dstu4145Error::InvalidParamLength(desired_length, d.bits(), "d".to_string()));
+      return Err(Dstu4145Error::InvalidParamLength(desired_length, d_bits, "d".to_string()));
     }
-    let q = ec.get_ref_bp().mul(&ec, d.clone()).negative();
-    Ok((Self { ec : ec.clone(), d, l_d }, VerifyingKey { ec, q, l_d }))
+    // $L(d) < L(n)$, so $d$ is already reduced
+    let d = EcScalar::from_bytes_reduced(&ec, d.as_ref());
+    let q = calculate_public_point(&ec, &d).negative();
+    Ok((
+      Self {
+        ec : Arc::clone(&ec),
+        d,
+        l_d,
+        countermeasures : Countermeasures::DEFAULT,
+      },
+      VerifyingKey {
+        ec,
+        q,
+        l_d,
+        base_point_table : BasePointTableCache::new(),
+      },
+    ))
   }
 
   /// Function checks on correctness __Public key__ on correctness. To reduce amount of incorrect signature.
+  /// Keys that share the same EC are compared by pointer, full comparison of EC's is performed otherwise.
   pub fn verify_verifying_key(&self, verifying_key : &VerifyingKey<T>) -> crate::error::Result<()>
   {
-    if self.ec != verifying_key.ec
+    if !Arc::ptr_eq(&self.ec, &verifying_key.ec) && self.ec != verifying_key.ec
     {
       return Err(Dstu4145Error::InvalidParams(format!(
         "Elliptic curves don't match, got EC with 'A in hex: {}, B in hex: {},  n in hex: {}, m: {}', \
@@ -188,7 +247,8 @@ impl<'a, T : GFArithmetic<'a>> SigningKey<T>
         verifying_key.l_d, self.l_d,
       )));
     }
-    let q_from_signing_key = self.ec.get_ref_bp().mul(&self.ec, self.d.clone()).negative();
+    let mut rng = ChaCha20Rng::from_entropy();
+    let q_from_signing_key = self.ec.mul_secret(self.ec.get_ref_bp(), &self.d, self.countermeasures, &mut rng).negative();
     if q_from_signing_key != verifying_key.q
     {
       Err(Dstu4145Error::InvalidParams(
@@ -282,6 +342,9 @@ impl<'a, T : GFArithmetic<'a>> VerifyingKey<T>
   pub fn pack(&self) -> Vec<u8> { self.q.pack().get_value().to_bytes_be() }
 
   pub fn get_pub_key(&self) -> AffinePoint<T> { self.q.clone() }
+
+  /// Function gets reference to the shared EC.
+  pub fn get_ref_ec(&self) -> &Arc<BinaryEC<T>> { &self.ec }
 }
 
 impl<'a, T : GFArithmetic<'a>> TryFrom<VerifyingKeyConstructor<T>> for VerifyingKey<T>
@@ -296,6 +359,7 @@ impl<'a, T : GFArithmetic<'a>> TryFrom<VerifyingKeyConstructor<T>> for Verifying
       ec : value.ec,
       q,
       l_d : value.l_d,
+      base_point_table : BasePointTableCache::new(),
     })
   }
 }
@@ -305,38 +369,38 @@ fn sign<'a, T : GFArithmetic<'a>, D : Digest>(
   rng : &mut impl CryptoRngCore,
   ec : &BinaryEC<T>,
   digest : D,
-  d : &BigUint,
+  d : &EcScalar<T>,
   l_d : u64,
+  countermeasures : Countermeasures,
 ) -> crate::error::Result<Signature>
 {
   let hash = digest.finalize().to_vec();
   let h = create_field_el_from_hash::<T, _>(hash);
   let (r, e) = loop
   {
-    let (e, f_e) = calculate_presign(rng, ec);
+    let (e, f_e) = calculate_presign(rng, ec, EcScalar::random_nonzero, countermeasures);
     let y = h.clone() * f_e;
     let r = y.get_value();
     if !r.is_zero()
     {
-      break (transform_field_poly_into_number(r, ec.get_ord()), e);
+      break (EcScalar::reduce(ec, &transform_field_poly_into_number(r, ec.get_ord())), e);
     }
   };
-  sign_inner(ec, &r, &e, d, l_d)
+  sign_inner(&r, &e, d, l_d)
 }
 
-/// Function performs last step of signing.
+/// Function performs last step of signing $s = (e + dr) \bmod n$.
 fn sign_inner<'a, T : GFArithmetic<'a>>(
-  ec : &BinaryEC<T>,
-  r : &BigUint,
-  e : &BigUint,
-  d : &BigUint,
+  r : &EcScalar<T>,
+  e : &EcScalar<T>,
+  d : &EcScalar<T>,
   l_d : u64,
 ) -> crate::error::Result<Signature>
 {
-  let s = (e.clone() + d * r) % ec.get_ord();
+  let s = e.add(&d.mul(r));
   Ok(Signature {
-    r : r.to_be_bytes().to_vec(),
-    s : s.to_be_bytes().to_vec(),
+    r : r.to_bytes_be(),
+    s : s.to_bytes_be(),
     l_d,
   })
 }
@@ -344,6 +408,7 @@ fn sign_inner<'a, T : GFArithmetic<'a>>(
 /// Function verifies whether the given signature is correct.
 fn verify<'a, T : GFArithmetic<'a>, D : Digest>(
   ec : &BinaryEC<T>,
+  base_point_table : &BasePointTable<T>,
   digest : D,
   q : &AffinePoint<T>,
   r : &[u8],
@@ -355,39 +420,36 @@ fn verify<'a, T : GFArithmetic<'a>, D : Digest>(
   let hash = digest.finalize().to_vec();
   let h = create_field_el_from_hash::<T, _>(hash);
   // Check `r`, `s` validity
-  let r_original = BigUint::from_bytes_be(r);
-  if r_original > *ec.get_ref_ord()
-  {
-    return Err(Dstu4145Error::InvalidParams(format!(
+  let r_original = EcScalar::from_bytes(ec, r).ok_or_else(|| {
+    Dstu4145Error::InvalidParams(format!(
       "Invalid r parameter, got: {}, has to be less than n: {}",
-      r_original.to_str_radix(16),
+      BigUint::from_bytes_be(r).to_str_radix(16),
       ec.get_ord().to_str_radix(16)
-    )));
-  }
-  let s_original = BigUint::from_bytes_be(s);
-  if s_original > *ec.get_ref_ord()
-  {
-    return Err(Dstu4145Error::InvalidParams(format!(
+    ))
+  })?;
+  let s_original = EcScalar::from_bytes(ec, s).ok_or_else(|| {
+    Dstu4145Error::InvalidParams(format!(
       "Invalid s parameter, got: {}, has to be less than n: {}",
-      s_original.to_str_radix(16),
+      BigUint::from_bytes_be(s).to_str_radix(16),
       ec.get_ord().to_str_radix(16)
-    )));
-  }
-  verify_inner(ec, q, &h, &r_original, &s_original)
+    ))
+  })?;
+  verify_inner(ec, base_point_table, q, &h, &r_original, &s_original)
 }
 
 /// Function performs last step of verifying signature.
 fn verify_inner<'a, T : GFArithmetic<'a>>(
   ec : &BinaryEC<T>,
+  base_point_table : &BasePointTable<T>,
   q : &AffinePoint<T>,
   h : &T,
-  r_original : &BigUint,
-  s_original : &BigUint,
+  r_original : &EcScalar<T>,
+  s_original : &EcScalar<T>,
 ) -> crate::error::Result<()>
 {
   let r = {
-    let s_p = ec.mul(ec.get_ref_bp(), s_original.clone());
-    let r_q = ec.mul(q, r_original.clone());
+    let s_p = base_point_table.mul(ec, s_original);
+    let r_q = ec.mul(q, r_original);
     ec.add(&s_p, &r_q)
   };
   match r
@@ -395,7 +457,7 @@ fn verify_inner<'a, T : GFArithmetic<'a>>(
     AffinePoint::Point { x: x_r, .. } =>
     {
       let y = h.clone() * x_r;
-      let r_dash = transform_field_poly_into_number(y, ec.get_ord());
+      let r_dash = EcScalar::reduce(ec, &transform_field_poly_into_number(y, ec.get_ord()));
       if *r_original == r_dash
       {
         Ok(())
@@ -429,20 +491,19 @@ mod tests_from_docs
 {
   use num_bigint::BigUint;
   use num_traits::Num;
-  use signature::{Signer, Verifier};
   use poly_algebra::gf::{GFArithmetic, GF163};
-  use poly_algebra::helpers::{create_field_el_from_hash, get_string_array_plain};
+  use poly_algebra::helpers::create_field_el_from_hash;
   use rust_ec::affine_point::AffinePoint;
   use rust_ec::binary_ec::BinaryEC;
   use crate::error::Dstu4145Error;
-  use crate::helpers::{calculate_presign, transform_field_poly_into_number};
-  use crate::sign::{sign_inner, verify_inner, SigningKey};
+  use crate::helpers::transform_field_poly_into_number;
+  use crate::scalar::EcScalar;
+  use crate::sign::{sign_inner, SigningKey};
 
   #[test]
   fn test_from_doc1() -> crate::error::Result<()>
   {
-    let mut ec = BinaryEC::generate_m163_pb_curve_from_examples();
-    let msg = "hello";
+    let ec = BinaryEC::generate_m163_pb_curve_from_examples();
 
     // Signing
     let (private_key, pub_key) = SigningKey::from_secret(
@@ -467,8 +528,8 @@ mod tests_from_docs
     let hash = hex_literal::hex!("003A2EB95B7180166DDF73532EEB76EDAEF52247FF").to_vec();
     let h = create_field_el_from_hash::<GF163, _>(hash);
     assert_eq!(h, GF163::from_hex_be("03A2EB95B7180166DDF73532EEB76EDAEF52247FF")?);
-    let e = BigUint::from_bytes_be(&hex_literal::hex!("01025E40BD97DB012B7A1D79DE8E12932D247F61C6"));
-    let ep = ec.mul(ec.get_ref_bp(), e.clone());
+    let e = EcScalar::from_bytes(&ec, &hex_literal::hex!("01025E40BD97DB012B7A1D79DE8E12932D247F61C6")).unwrap();
+    let ep = ec.mul(ec.get_ref_bp(), &e);
     assert_eq!(
       ep,
       AffinePoint::Point {
@@ -480,8 +541,8 @@ mod tests_from_docs
     {
       let y = h.clone() * x_p;
       assert_eq!(y, GF163::from_hex_be("0274EA2C0CAA014A0D80A424F59ADE7A93068D08A7")?);
-      let r = transform_field_poly_into_number(y, ec.get_ord());
-      let sign = sign_inner(&ec, &r, &e, &private_key.d, private_key.l_d)?;
+      let r = EcScalar::reduce(&ec, &transform_field_poly_into_number(y, ec.get_ord()));
+      let sign = sign_inner(&r, &e, &private_key.d, private_key.l_d)?;
       assert_eq!(
         sign.r,
         hex_literal::hex!("0274EA2C0CAA014A0D80A424F59ADE7A93068D08A7").to_vec()
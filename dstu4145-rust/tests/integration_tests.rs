@@ -1,23 +1,28 @@
-use num_bigint::BigUint;
-use proptest::arbitrary::any;
-use proptest::collection::vec;
-use proptest::prelude::Strategy;
-
 #[cfg(test)]
 mod tests
 {
-  use proptest::arbitrary::any;
-  use proptest::collection::vec;
-  use proptest::prelude::Strategy;
+  use std::sync::Arc;
+  use num_bigint::BigUint;
+  use num_traits::{One, Zero};
   use rand_chacha::ChaCha20Rng;
   use rand_chacha::rand_core::{RngCore, SeedableRng};
   use sha3::Digest;
   use signature::{DigestSigner, DigestVerifier, RandomizedSigner, Signer, Verifier};
-  use dstu4145_rust::sign::SigningKey;
+  use hex_literal::hex;
+  use subtle::ConstantTimeEq;
+  use zeroize::Zeroize;
+  use dstu4145_rust::ecdh::EphemeralSecret;
+  use dstu4145_rust::ecdsa;
+  use dstu4145_rust::ecies::{decrypt, encrypt, ECIES_HEADER_LEN, ECIES_TAG_LEN};
+  use dstu4145_rust::error::Dstu4145Error;
+  use dstu4145_rust::scalar::{EcScalar, Scalar};
+  use dstu4145_rust::sign::{Signature, SigningKey, VerifyingKey, VerifyingKeyConstructor};
+  use poly_algebra::gf::{GFArithmetic, GFGetters, GF163, GF173, GF233Nist, GF283, GF409, GF571};
+  use rust_ec::affine_point::AffinePoint;
   use rust_ec::binary_ec::BinaryEC;
-
-  const PROP_TEST_BIGUINT_BYTE_LEN : usize = 128;
-  fn arb_byte_vec() -> impl Strategy<Value = Vec<u8>> { vec(any::<u8>(), 0 .. PROP_TEST_BIGUINT_BYTE_LEN) }
+  use rust_ec::countermeasures::Countermeasures;
+  use rust_ec::error::EcError;
+  use rust_ec::preset_group::PresetScalar;
 
   #[test]
   fn test1()
@@ -406,4 +411,563 @@ mod tests
     digest.update(&pt);
     assert!(pub_key.verify_digest(digest, &signature).is_ok());
   }
+
+  // Public key check
+  #[test]
+  fn small_subgroup_public_key_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let ec = Arc::new(BinaryEC::generate_m173_pb_curve());
+    let (_, pub_key) = SigningKey::generate(&mut rng, Arc::clone(&ec), 512).unwrap();
+    let constructor = VerifyingKeyConstructor {
+      ec : Arc::clone(&ec),
+      q : GF173::from(BigUint::from_bytes_be(&pub_key.pack())),
+      l_d : 512,
+    };
+    assert!(VerifyingKey::try_from(constructor).unwrap() == pub_key);
+
+    // $Q + T$, where $T = (0, \sqrt{B})$ has order 2, so $Q + T$ has order $2n$
+    let t = AffinePoint::Point {
+      x : GF173::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    let q = ec.add(&ec.get_bp(), &t);
+    assert_eq!(ec.unpack_affine_point(&q.pack()), q);
+    let constructor = VerifyingKeyConstructor {
+      ec : Arc::clone(&ec),
+      q : q.pack(),
+      l_d : 512,
+    };
+    assert!(matches!(
+      VerifyingKey::try_from(constructor),
+      Err(Dstu4145Error::FailedPublicKeyCheck(err))
+        if err == EcError::WrongPointOrder(ec.get_ref_ord().to_str_radix(16)).to_string()
+    ));
+  }
+
+  #[test]
+  fn shared_ec_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let ec = Arc::new(BinaryEC::generate_m257_pb_curve());
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, Arc::clone(&ec), 512).unwrap();
+    assert!(Arc::ptr_eq(private_key.get_ref_ec(), &ec));
+    assert!(Arc::ptr_eq(pub_key.get_ref_ec(), &ec));
+    let cloned_key = private_key.clone();
+    assert!(Arc::ptr_eq(cloned_key.get_ref_ec(), &ec));
+    assert!(private_key.verify_verifying_key(&pub_key).is_ok());
+
+    // Equal EC's, which aren't shared, are compared by value
+    let (private_key, pub_key) = SigningKey::from_secret(
+      BinaryEC::generate_m257_pb_curve(),
+      private_key.get_private_key().to_bytes_be(),
+      512,
+    )
+    .unwrap();
+    assert!(!Arc::ptr_eq(private_key.get_ref_ec(), &ec));
+    assert!(Arc::ptr_eq(private_key.get_ref_ec(), pub_key.get_ref_ec()));
+    assert!(cloned_key.verify_verifying_key(&pub_key).is_ok());
+
+    let (_, another_pub_key) = SigningKey::generate(&mut rng, BinaryEC::generate_m163_pb_curve_from_examples(), 512).unwrap();
+    let (another_private_key, _) = SigningKey::generate(&mut rng, BinaryEC::generate_m163_pb_curve(), 512).unwrap();
+    assert!(matches!(
+      another_private_key.verify_verifying_key(&another_pub_key),
+      Err(Dstu4145Error::InvalidParams(_))
+    ));
+  }
+
+  // Key agreement
+  #[test]
+  fn ecdh_test_vectors()
+  {
+    let (private_key_a, pub_key_a) = SigningKey::from_secret(
+      BinaryEC::generate_m163_pb_curve(),
+      hex!("1f2e3d4c5b6a79880716253443526170f1e2d3c4"),
+      512,
+    )
+    .unwrap();
+    let (private_key_b, pub_key_b) = SigningKey::from_secret(
+      BinaryEC::generate_m163_pb_curve(),
+      hex!("0c0ffee0123456789abcdef0fedcba9876543210"),
+      512,
+    )
+    .unwrap();
+    assert_eq!(pub_key_a.pack(), hex!("0616f027af291204b7dfc7a1b91e5fe3aeb55212f9"));
+    assert_eq!(pub_key_b.pack(), hex!("02729245c3bd4d69da1e9f192716805c0c106a8d85"));
+    let secret = private_key_a.diffie_hellman(&pub_key_b.pack()).unwrap();
+    assert_eq!(secret.as_bytes(), hex!("02262193eff45f5bc823a9538cbe1d99d779cb29bc"));
+    assert_eq!(private_key_b.diffie_hellman(&pub_key_a.pack()).unwrap(), secret);
+
+    let (private_key_a, pub_key_a) = SigningKey::from_secret(
+      BinaryEC::generate_m257_pb_curve(),
+      hex!("3a5c7e9f0b1d2f4061728394a5b6c7d8e9fa0b1c2d3e4f5061728394a5b6c7"),
+      512,
+    )
+    .unwrap();
+    let (private_key_b, pub_key_b) = SigningKey::from_secret(
+      BinaryEC::generate_m257_pb_curve(),
+      hex!("0123456789abcdeffedcba98765432100123456789abcdeffedcba987654"),
+      512,
+    )
+    .unwrap();
+    assert_eq!(
+      pub_key_a.pack(),
+      hex!("e06c1bdf9da7899e4caf6445fa3ede748efbc06a1ae2948f820917443e08cb3d")
+    );
+    assert_eq!(
+      pub_key_b.pack(),
+      hex!("b341502adaf4a878c757ab78478a86d84b993c8081b40ca3317496dfde235315")
+    );
+    // Shared secret has fixed length, so leading zero byte is kept
+    let secret = private_key_a.diffie_hellman(&pub_key_b.pack()).unwrap();
+    assert_eq!(
+      secret.as_bytes(),
+      hex!("008180bc85d865d26c2abd43bd447b2d0651ed6679b713ed98dc0a975a9b15666c")
+    );
+    assert_eq!(private_key_b.diffie_hellman(&pub_key_a.pack()).unwrap(), secret);
+
+    // Independent vector on sect163k1 ($h = 2$), keys and secret are produced by OpenSSL 3.5.6:
+    // `openssl ecparam -name sect163k1 -genkey` and `openssl pkeyutl -derive -pkeyopt ecdh_cofactor_mode:1`.
+    // DSTU public key is $Q = -dP$, which has the same x-coordinate as $dP$ of OpenSSL, so secrets are equal.
+    let ec = Arc::new(BinaryEC::<GF163>::generate_k163_curve());
+    let (private_key_a, pub_key_a) =
+      SigningKey::from_secret(ec.clone(), hex!("03e40e4a8e9392a32ccde570d0bd75420fdb197063"), 512).unwrap();
+    let (private_key_b, pub_key_b) =
+      SigningKey::from_secret(ec.clone(), hex!("0398c8122ae52150e10891d1645971026143b09999"), 512).unwrap();
+    let openssl_pub_key_a = hex!(
+      "0406ee19fa167998d355ada925f741062f8fce5ff964044533832d34b9b06c1966e8b4dfdee6da05c6d57f"
+    );
+    let openssl_pub_key_b = hex!(
+      "0407a7f4afb4d6539b264f20a503c59e974e500c9d7804d8e1792394d12f69d5f506faa3e9a6409901e5c5"
+    );
+    assert_eq!(pub_key_a.get_pub_key().negative().to_sec1(false), openssl_pub_key_a);
+    assert_eq!(pub_key_b.get_pub_key().negative().to_sec1(false), openssl_pub_key_b);
+    let secret = private_key_a.diffie_hellman(&pub_key_b.pack()).unwrap();
+    assert_eq!(secret.as_bytes(), hex!("041b8e7bed1c24c406d749334700cabfa759079725"));
+    assert_eq!(private_key_b.diffie_hellman(&pub_key_a.pack()).unwrap(), secret);
+  }
+
+  #[test]
+  fn ecdh_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let ec = Arc::new(BinaryEC::generate_m173_pb_curve());
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, Arc::clone(&ec), 512).unwrap();
+    let (peer_private_key, peer_pub_key) = SigningKey::generate(&mut rng, Arc::clone(&ec), 512).unwrap();
+    let secret = private_key.diffie_hellman(&peer_pub_key.pack()).unwrap();
+    assert_eq!(peer_private_key.diffie_hellman(&pub_key.pack()).unwrap(), secret);
+    assert_eq!(secret.as_bytes().len(), 22);
+    assert!(bool::from(secret.ct_eq(&secret.clone())));
+    let mut wiped = secret.clone();
+    wiped.zeroize();
+    assert_ne!(wiped, secret);
+    assert!(wiped.as_bytes().is_empty());
+    // $x(-d_A \cdot Q_B) = x(h \cdot d_A \cdot d_B \cdot P)$
+    let expected = ec.mul(
+      ec.get_ref_bp(),
+      ec.get_cofactor() * private_key.get_private_key().to_biguint() * peer_private_key.get_private_key().to_biguint(),
+    );
+    match expected
+    {
+      AffinePoint::Point { x, .. } => assert_eq!(BigUint::from_bytes_be(secret.as_bytes()), x.get_value()),
+      AffinePoint::Infinity => panic!("shared point can't be infinity"),
+    }
+
+    // Ephemeral-static mode
+    let ephemeral = EphemeralSecret::generate(&mut rng, Arc::clone(&ec));
+    let ephemeral_pub_key = ephemeral.pack();
+    assert_eq!(ephemeral.get_pub_key(), ec.unpack_affine_point(&GF173::from(BigUint::from_bytes_be(&ephemeral_pub_key))));
+    let secret = ephemeral.diffie_hellman(&pub_key.pack()).unwrap();
+    assert_eq!(private_key.diffie_hellman(&ephemeral_pub_key).unwrap(), secret);
+    let ephemeral = EphemeralSecret::generate(&mut rng, BinaryEC::generate_m173_pb_curve());
+    let ephemeral_pub_key = ephemeral.pack();
+    let secret = ephemeral.diffie_hellman_with_verifying_key(&pub_key).unwrap();
+    assert_eq!(private_key.diffie_hellman(&ephemeral_pub_key).unwrap(), secret);
+    let ephemeral = EphemeralSecret::generate(&mut rng, BinaryEC::generate_m163_pb_curve());
+    let (_, another_pub_key) = SigningKey::generate(&mut rng, BinaryEC::generate_m163_pb_curve_from_examples(), 512).unwrap();
+    assert!(matches!(
+      ephemeral.diffie_hellman_with_verifying_key(&another_pub_key),
+      Err(Dstu4145Error::InvalidParams(_))
+    ));
+
+    // Invalid peer keys
+    assert!(matches!(
+      private_key.diffie_hellman(&[0]),
+      Err(Dstu4145Error::FailedPublicKeyCheck(err)) if err == EcError::PointIsInfinity.to_string()
+    ));
+    assert!(matches!(
+      private_key.diffie_hellman(&[0xff; 23]),
+      Err(Dstu4145Error::InvalidParamLength(173, _, _))
+    ));
+    let t = AffinePoint::Point {
+      x : GF173::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    let q = ec.add(&peer_pub_key.get_pub_key(), &t);
+    assert!(matches!(
+      private_key.diffie_hellman(&q.pack().get_value().to_bytes_be()),
+      Err(Dstu4145Error::FailedPublicKeyCheck(err))
+        if err == EcError::WrongPointOrder(ec.get_ref_ord().to_str_radix(16)).to_string()
+    ));
+    let ec = BinaryEC::<GF163>::generate_m163_pb_curve();
+    let (private_key, _) = SigningKey::generate(&mut rng, ec, 512).unwrap();
+    assert!(private_key.diffie_hellman(&peer_pub_key.pack()).is_err());
+  }
+
+  // Side-channel countermeasures don't change results of the operations with secret scalars
+  #[test]
+  fn countermeasures_test()
+  {
+    let mut pt = vec![0; 16];
+    let mut rng = ChaCha20Rng::from_entropy();
+    rng.fill_bytes(&mut pt);
+    let ec = Arc::new(BinaryEC::generate_m163_pb_curve());
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, Arc::clone(&ec), 512).unwrap();
+    assert_eq!(private_key.get_countermeasures(), Countermeasures::DEFAULT);
+    assert_eq!(
+      pub_key.get_pub_key(),
+      ec.mul(ec.get_ref_bp(), private_key.get_private_key()).negative()
+    );
+    let (peer_private_key, peer_pub_key) = SigningKey::generate(&mut rng, Arc::clone(&ec), 512).unwrap();
+    let secret = private_key.diffie_hellman(&peer_pub_key.pack()).unwrap();
+    assert_eq!(peer_private_key.diffie_hellman(&pub_key.pack()).unwrap(), secret);
+    for countermeasures in [Countermeasures::NONE, Countermeasures::ALL]
+    {
+      let private_key = private_key.clone().with_countermeasures(countermeasures);
+      assert_eq!(private_key.get_countermeasures(), countermeasures);
+      assert!(private_key.verify_verifying_key(&pub_key).is_ok());
+      let signature = private_key.sign_with_rng(&mut rng, &pt);
+      assert!(pub_key.verify(&pt, &signature).is_ok());
+      assert_eq!(private_key.diffie_hellman(&peer_pub_key.pack()).unwrap(), secret);
+      let ephemeral = EphemeralSecret::generate(&mut rng, Arc::clone(&ec)).with_countermeasures(countermeasures);
+      let ephemeral_pub_key = ephemeral.pack();
+      assert_eq!(ephemeral.diffie_hellman(&pub_key.pack()).unwrap(), private_key.diffie_hellman(&ephemeral_pub_key).unwrap());
+    }
+  }
+
+  // Hybrid encryption
+  #[test]
+  fn ecies_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let mut pt = vec![0; 32];
+    rng.fill_bytes(&mut pt);
+    let aad = b"session";
+
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, BinaryEC::generate_m163_pb_curve(), 512).unwrap();
+    let ct = encrypt(&mut rng, &pub_key, &pt, aad).unwrap();
+    assert_eq!(ct.len(), ECIES_HEADER_LEN + 21 + pt.len() + ECIES_TAG_LEN);
+    assert_eq!(ct[.. ECIES_HEADER_LEN], [1, 1, 0]);
+    assert_eq!(decrypt(&private_key, &ct, aad).unwrap(), pt);
+    // Every message has its own ephemeral key
+    assert_ne!(encrypt(&mut rng, &pub_key, &pt, aad).unwrap(), ct);
+    let empty = encrypt(&mut rng, &pub_key, &[], &[]).unwrap();
+    assert!(decrypt(&private_key, &empty, &[]).unwrap().is_empty());
+
+    assert!(matches!(decrypt(&private_key, &ct, b"another"), Err(Dstu4145Error::DecryptionFailed)));
+    let mut tampered = ct.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    assert!(matches!(decrypt(&private_key, &tampered, aad), Err(Dstu4145Error::DecryptionFailed)));
+    let mut tampered = ct.clone();
+    tampered[ECIES_HEADER_LEN + 21] ^= 1;
+    assert!(matches!(decrypt(&private_key, &tampered, aad), Err(Dstu4145Error::DecryptionFailed)));
+    let (another_private_key, _) = SigningKey::generate(&mut rng, BinaryEC::generate_m163_pb_curve(), 512).unwrap();
+    assert!(matches!(decrypt(&another_private_key, &ct, aad), Err(Dstu4145Error::DecryptionFailed)));
+
+    // Header validation
+    for (i, value) in [(0, 2), (1, 2), (2, 2)]
+    {
+      let mut tampered = ct.clone();
+      tampered[i] = value;
+      assert!(matches!(decrypt(&private_key, &tampered, aad), Err(Dstu4145Error::InvalidCiphertext(_))));
+    }
+    assert!(matches!(
+      decrypt(&private_key, &ct[.. ECIES_HEADER_LEN + 21 + ECIES_TAG_LEN - 1], aad),
+      Err(Dstu4145Error::InvalidCiphertext(_))
+    ));
+
+    // Ephemeral point validation
+    let ec = BinaryEC::generate_m173_pb_curve();
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, ec.clone(), 512).unwrap();
+    let ct = encrypt(&mut rng, &pub_key, &pt, aad).unwrap();
+    assert_eq!(decrypt(&private_key, &ct, aad).unwrap(), pt);
+    let mut tampered = ct.clone();
+    tampered[ECIES_HEADER_LEN .. ECIES_HEADER_LEN + 22].fill(0);
+    assert!(matches!(
+      decrypt(&private_key, &tampered, aad),
+      Err(Dstu4145Error::FailedPublicKeyCheck(err)) if err == EcError::PointIsInfinity.to_string()
+    ));
+    let t = AffinePoint::Point {
+      x : GF173::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    let ephemeral = ec.unpack_affine_point(&GF173::from(BigUint::from_bytes_be(&ct[ECIES_HEADER_LEN .. ECIES_HEADER_LEN + 22])));
+    let packed = ec.add(&ephemeral, &t).pack().get_value().to_bytes_be();
+    let mut tampered = ct.clone();
+    tampered[ECIES_HEADER_LEN .. ECIES_HEADER_LEN + 22].fill(0);
+    tampered[ECIES_HEADER_LEN + 22 - packed.len() .. ECIES_HEADER_LEN + 22].copy_from_slice(&packed);
+    assert!(matches!(
+      decrypt(&private_key, &tampered, aad),
+      Err(Dstu4145Error::FailedPublicKeyCheck(err))
+        if err == EcError::WrongPointOrder(ec.get_ref_ord().to_str_radix(16)).to_string()
+    ));
+
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, BinaryEC::generate_m257_pb_curve(), 512).unwrap();
+    let ct = encrypt(&mut rng, &pub_key, &pt, &[]).unwrap();
+    assert_eq!(ct[.. ECIES_HEADER_LEN], [1, 1, 6]);
+    assert_eq!(decrypt(&private_key, &ct, &[]).unwrap(), pt);
+
+    // Only presets are supported, other EC's over the same field can't be distinguished by the header
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, BinaryEC::generate_k163_curve(), 512).unwrap();
+    assert!(matches!(encrypt(&mut rng, &pub_key, &pt, aad), Err(Dstu4145Error::InvalidParams(_))));
+    assert!(matches!(decrypt(&private_key, &ct, aad), Err(Dstu4145Error::InvalidParams(_))));
+  }
+
+  #[test]
+  fn scalar_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let ec = BinaryEC::generate_m163_pb_curve();
+    let n = ec.get_ord();
+    let zero = Scalar::<GF163>::zero();
+    assert!(zero.is_zero());
+    assert!(zero.invert().is_none());
+    assert_eq!(zero.get_ref_modulus(), &n);
+    assert_eq!(Scalar::<GF163>::from_bytes(&n.to_bytes_be()), None);
+    assert_eq!(
+      Scalar::<GF163>::from_bytes_reduced(&(&n + BigUint::from(5_u8)).to_bytes_be()),
+      Scalar::reduce(&BigUint::from(5_u8))
+    );
+    assert_eq!(Scalar::<GF163>::from_bytes(&(&n - BigUint::one()).to_bytes_be()), Some(-&Scalar::one()));
+    for _ in 0 .. 16
+    {
+      let (a, b) = (Scalar::<GF163>::random_nonzero(&mut rng), Scalar::random_uniform_nonzero(&mut rng));
+      assert!(a.to_biguint().bits() < n.bits());
+      assert_eq!(Scalar::from_bytes(&a.to_bytes_be()), Some(a.clone()));
+      assert_eq!((&a + &b).to_biguint(), (a.to_biguint() + b.to_biguint()) % &n);
+      assert_eq!((&a * &b).to_biguint(), (a.to_biguint() * b.to_biguint()) % &n);
+      assert_eq!(&(&a - &b) + &b, a);
+      assert!((&a + &-&a).is_zero());
+      assert_eq!(&a * &a.invert().unwrap(), Scalar::one());
+      assert_eq!(a.invert().unwrap().to_biguint(), a.to_biguint().modinv(&n).unwrap());
+    }
+
+    // Scalar of the key is reduced by the order of its EC and converted into scalar of the preset
+    let (private_key, pub_key) = SigningKey::generate(&mut rng, ec.clone(), 512).unwrap();
+    let d = private_key.get_private_key();
+    assert_eq!(d.get_ref_modulus(), &n);
+    assert_eq!(format!("{:?}", d), "EcScalar { .. }");
+    assert_eq!(EcScalar::reduce(&ec, &BigUint::one()), EcScalar::from(Scalar::<GF163>::one()));
+    let preset_d = Scalar::<GF163>::try_from(&d).unwrap();
+    assert_eq!(format!("{:?}", preset_d), "Scalar { .. }");
+    assert_eq!(preset_d.to_biguint(), d.to_biguint());
+    assert_eq!(EcScalar::from(preset_d.clone()), d);
+    let another_ec = BinaryEC::generate_k163_curve();
+    let another_one = EcScalar::reduce(&another_ec, &BigUint::one());
+    assert_ne!(another_one, EcScalar::reduce(&ec, &BigUint::one()));
+    assert!(matches!(Scalar::<GF163>::try_from(&another_one), Err(Dstu4145Error::ScalarOrderMismatch(..))));
+
+    // Conversions between scalar of the preset and [PresetScalar]
+    let group_d = PresetScalar::<GF163>::from(&preset_d);
+    assert_eq!(group_d.to_biguint(), d.to_biguint());
+    assert_eq!(Scalar::from(group_d), preset_d);
+
+    // Signature values, that aren't reduced, are rejected
+    let msg = b"scalar";
+    let signature = private_key.sign(msg);
+    assert!(pub_key.verify(msg, &signature).is_ok());
+    let element_size = (signature.get_l_d() / 16) as usize;
+    let n_bytes = n.to_bytes_be();
+    let mut packed = vec![0; element_size - n_bytes.len()];
+    packed.extend(&n_bytes);
+    packed.extend(vec![0; element_size - signature.get_ref_r().len()]);
+    packed.extend(signature.get_ref_r());
+    let unreduced_signature = Signature::try_from(packed.as_slice()).unwrap();
+    assert!(pub_key.verify(msg, &unreduced_signature).is_err());
+  }
+
+  /// Function checks ECDSA key, that is restored from $d$, against public key $Q$ and DER signature
+  /// of the message `sample`, that are generated by OpenSSL.
+  fn check_ecdsa_test_vector<'a, T : GFArithmetic<'a>, D : Digest>(ec : BinaryEC<T>, d : &[u8], q : &[u8], der : &[u8])
+  {
+    let ec = Arc::new(ec);
+    let (private_key, pub_key) = ecdsa::SigningKey::from_secret(Arc::clone(&ec), d).unwrap();
+    assert_eq!(pub_key.to_sec1(false), q);
+    assert_eq!(private_key.verifying_key().get_pub_key(), pub_key.get_pub_key());
+    assert_eq!(ecdsa::VerifyingKey::from_sec1(Arc::clone(&ec), q).unwrap().get_pub_key(), pub_key.get_pub_key());
+    assert_eq!(
+      ecdsa::VerifyingKey::from_sec1(ec, &pub_key.to_sec1(true)).unwrap().get_pub_key(),
+      pub_key.get_pub_key()
+    );
+
+    let signature = ecdsa::Signature::try_from(der).unwrap();
+    assert_eq!(signature.to_der(), der);
+    assert!(pub_key.verify_digest(D::new_with_prefix(b"sample"), &signature).is_ok());
+    assert!(pub_key.verify_digest(D::new_with_prefix(b"samplf"), &signature).is_err());
+    let signature = private_key.sign_digest(D::new_with_prefix(b"sample"));
+    assert!(pub_key.verify_digest(D::new_with_prefix(b"sample"), &signature).is_ok());
+  }
+
+  /// Function encodes $(r, s)$ as DER, both numbers have to be encoded in less than 127 bytes.
+  fn ecdsa_der(r : &[u8], s : &[u8]) -> Vec<u8>
+  {
+    let mut content = Vec::new();
+    for num in [r, s]
+    {
+      let padding = num[0] & 0x80 != 0;
+      content.extend([0x02, (num.len() + padding as usize) as u8]);
+      if padding
+      {
+        content.push(0);
+      }
+      content.extend(num);
+    }
+    let mut der = vec![0x30, content.len() as u8];
+    der.extend(content);
+    der
+  }
+
+  #[test]
+  fn ecdsa_test_vectors()
+  {
+    check_ecdsa_test_vector::<_, sha2::Sha256>(
+      BinaryEC::<GF163>::generate_k163_curve(),
+      &hex!("781F91EC00908E30D6155874BA84471E03FE835F"),
+      &hex!("0405455B00374A6CBF7AC37D0E69B5C0DCA4A53D2EE9068296C69BB3AC28D3B563BFAA1583A9882FFA2CB3"),
+      &hex!("302D021500DA83F00E6BC61DFA28B242F4F15DE102B9231E2B02143A8FA8EE253AC82B04DDA4849CD5124F93DFC505"),
+    );
+    check_ecdsa_test_vector::<_, sha2::Sha224>(
+      BinaryEC::<GF233Nist>::generate_b233_curve(),
+      &hex!("5EA672438368F75B79BC81A4D937B00E1B9309BFB15971782BA80C953D"),
+      &hex!(
+        "04004D4BD41C44C77DB2450A33CC2CAFFCCEBB719EDFCB5488E8805C570D850070FB7B107F4909C5C534BC098A565743"
+        "F50F77885AD3ED3BC944B63BE1"
+      ),
+      &hex!(
+        "3040021E00E14CB708E1B68825C0BC89453D0A6F872C667137B15915AB021C957DDD021E0085503E664CDBB051E269C3"
+        "8905F4D8A99C8CC561E3BE383D06C6F4F05C"
+      ),
+    );
+    check_ecdsa_test_vector::<_, sha2::Sha256>(
+      BinaryEC::<GF283>::generate_k283_curve(),
+      &hex!("012ECE43FD648C587AA9988C521234CEABA1FC2ED8CE25BF9282353A423083026D28A414"),
+      &hex!(
+        "0403DA5B6845D58FFA2C3274ABBF221C1B429FE1EF09491127ED9E864901AF2D772C9FDF3806008A8E86A969647BD4BF"
+        "C5194892A21C54A9D26A835F16785076FAB84D7593559718F2"
+      ),
+      &hex!(
+        "304C022400C61E768EC374D24602E3A401420621572F162152B0E19AFB2547025B84725F10C437DA022400CE62B7F29B"
+        "85E399D07701724351B90DDEA3F32997B823CC25631F1B64D0B3CBE51CC8"
+      ),
+    );
+    check_ecdsa_test_vector::<_, sha2::Sha384>(
+      BinaryEC::<GF409>::generate_b409_curve(),
+      &hex!(
+        "FAF8C6A3691CA7998DF09D889489A7DD544A607350F8C71F86C0640F576636E4144E1B88D0A28F1C800AA8322A03B4B6"
+        "F8C20C"
+      ),
+      &hex!(
+        "0401A2A031F98602EAEC3AA7D05FCCB6FE4ABDDAE92A8B1323AFA318BD2BAE611D5C12740E4F728168E2355C585EA386"
+        "55ED28775901D26A50F867738F572B2C4B44B24F6EF1559DE239143CC77423990F03D0F725C69AA4CE4C23DE2AD4E394"
+        "69AE1ED3214A57FDB7"
+      ),
+      &hex!(
+        "306B023400C3DBBF0ED16A844F15F0FE1951B4C123C43AA1822984B9E445D96179D344B1BC6C72AA6F87CAF0B6515493"
+        "B6FF826C22F09B8E023304F8F21A18E31F3E9687142F2D731CE37B4BF15105819BC7115A44DB7115E5D8B967EE677268"
+        "38DE89AF3B73AF4946B63C3DA4"
+      ),
+    );
+    check_ecdsa_test_vector::<_, sha2::Sha512>(
+      BinaryEC::<GF571>::generate_k571_curve(),
+      &hex!(
+        "01FFD3BA3131F521C5275FBAA4F63FEDA5DCD5D0970B7F31507D330523D5CBCB707B37AFFBF104A06B0639E3C8B7512D"
+        "601857C5C0AFC65DE09F0889020D641E4FF4CFC3E40463A1"
+      ),
+      &hex!(
+        "0402522331F6606F9C56DC5A3B61965965B20BD6D8C567E67AD8731A80BA4E925E584965B11DF69AEF09BCB5539BE0E1"
+        "446BBE43E8D3D8D2E1886787FA0B55F7BCC301C3935B6C8BEC045FE6CCE08C2F349283DEF632928D46C25C8EDAEAC16C"
+        "E3FABDE7CDDCADF2F2C6C28AEA5A5B53B11B23428156ED0DC1567F83B0C5BF1F99431C3E26B3FF8B6E48E240115B43AC"
+        "B3"
+      ),
+      &hex!(
+        "3081930248012C9D01D60E1D485C278C00C06F6BAB8699BD10FD2092469D0AA6CBA236046068250DEBCA0E89A4736971"
+        "6E365E3A5D3446C38A8C1EA2CC23CC822A8E13222152FFFB74D926CAB4024730DE31B4FC9089FF4C3579FCF098069EF2"
+        "51E4D48648CAF94AB484976E64762BBF2D39327709586D0CA8E377F05E09ECD5CF7B2DF6F9AEC4B35FB99E4652E0D10A"
+        "B7F213080D07"
+      ),
+    );
+  }
+
+  #[test]
+  fn ecdsa_test()
+  {
+    let mut pt = vec![0; 16];
+    let mut rng = ChaCha20Rng::from_entropy();
+    rng.fill_bytes(&mut pt);
+    let ec = BinaryEC::generate_m257_pb_curve();
+    let (private_key, pub_key) = ecdsa::SigningKey::generate(&mut rng, ec.clone());
+    let signature = private_key.sign_with_rng(&mut rng, &pt);
+    assert!(pub_key.verify(&pt, &signature).is_ok());
+    assert!(pub_key.verify_digest(sha2::Sha256::new_with_prefix(&pt), &signature).is_ok());
+    assert!(pub_key.verify_digest(sha2::Sha512::new_with_prefix(&pt), &signature).is_err());
+    assert_eq!(ecdsa::Signature::try_from(signature.to_der().as_slice()).unwrap(), signature);
+    let (_, another_pub_key) = ecdsa::SigningKey::generate(&mut rng, ec.clone());
+    assert!(another_pub_key.verify(&pt, &signature).is_err());
+
+    // Key with the same secret as DSTU 4145-2002 key has $Q$, that isn't negated
+    let d = private_key.get_private_key().to_bytes_be();
+    let (dstu_private_key, dstu_pub_key) = SigningKey::from_secret(ec.clone(), d, 512).unwrap();
+    assert_eq!(dstu_private_key.get_private_key(), private_key.get_private_key());
+    assert_eq!(dstu_pub_key.get_pub_key(), pub_key.get_pub_key().negative());
+
+    // $r, s \in [1, n - 1]$
+    let n = ec.get_ord().to_bytes_be();
+    let der = |r : &[u8], s : &[u8]| ecdsa::Signature::try_from(ecdsa_der(r, s).as_slice()).unwrap();
+    assert!(pub_key.verify(&pt, &der(&[0], signature.get_ref_s())).is_err());
+    assert!(pub_key.verify(&pt, &der(signature.get_ref_r(), &n)).is_err());
+    assert!(ecdsa::SigningKey::from_secret(ec.clone(), [0]).is_err());
+    assert!(ecdsa::SigningKey::from_secret(ec.clone(), &n).is_err());
+    assert!(matches!(
+      ecdsa::VerifyingKey::from_sec1(ec.clone(), &[0x00]),
+      Err(Dstu4145Error::FailedPublicKeyCheck(err)) if err == EcError::PointIsInfinity.to_string()
+    ));
+
+    // Only DER is accepted
+    let encoded = signature.to_der();
+    let mut trailing = encoded.clone();
+    trailing.push(0);
+    assert!(ecdsa::Signature::try_from(trailing.as_slice()).is_err());
+    assert!(ecdsa::Signature::try_from(&encoded[.. encoded.len() - 1]).is_err());
+    assert!(ecdsa::Signature::try_from(hex!("3006020101020181").as_slice()).is_err());
+    assert!(ecdsa::Signature::try_from(hex!("300702020001020101").as_slice()).is_err());
+    assert!(ecdsa::Signature::try_from(hex!("308106020101020101").as_slice()).is_err());
+    assert_eq!(
+      ecdsa::Signature::try_from(hex!("30070202008002010F").as_slice()).unwrap().get_r(),
+      vec![0x80]
+    );
+
+    // Keys and nonces are uniform in $[1, n - 1]$, so unlike DSTU 4145-2002 ones they exceed $2^{L(n) - 1}$,
+    // $n$ of M167 is slightly less than $2^{166}$, so half of them are expected to do it.
+    // Nonce is restored from the signature: $k = s^{-1}(e + dr) \bmod n$.
+    let ec = BinaryEC::generate_m167_pb_curve();
+    let n = ec.get_ord();
+    let bound = BigUint::one() << (n.bits() - 1);
+    let (private_key, _) = ecdsa::SigningKey::generate(&mut rng, ec.clone());
+    let d = private_key.get_private_key().to_biguint();
+    let e = (BigUint::from_bytes_be(&sha2::Sha256::digest(&pt)) >> (256 - n.bits())) % &n;
+    let nonces = (0 .. 32)
+      .map(|_| {
+        let signature : ecdsa::Signature = private_key.sign_digest(sha2::Sha256::new_with_prefix(&pt));
+        let (r, s) = (BigUint::from_bytes_be(signature.get_ref_r()), BigUint::from_bytes_be(signature.get_ref_s()));
+        s.modinv(&n).unwrap() * (&e + &d * r) % &n
+      })
+      .collect::<Vec<_>>();
+    assert!(nonces.iter().all(|k| !k.is_zero() && *k < n));
+    assert!(nonces.iter().any(|k| *k >= bound));
+    let keys = (0 .. 32)
+      .map(|_| ecdsa::SigningKey::generate(&mut rng, ec.clone()).0.get_private_key().to_biguint())
+      .collect::<Vec<_>>();
+    assert!(keys.iter().any(|d| *d >= bound));
+    assert!((0 .. 32).all(|_| EcScalar::random_nonzero(&mut rng, &ec).to_biguint() < bound));
+  }
 }
@@ -0,0 +1,28 @@
+use ff::{Field, PrimeField};
+use group::{Curve, Group};
+use poly_algebra::gf::GF257;
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
+use rust_ec::preset_group::{PresetPoint, PresetScalar};
+
+/// Function knows nothing about binary fields, it works with every prime order group.
+fn schnorr_prove<G : Group>(secret : &G::Scalar, nonce : &G::Scalar, challenge : &G::Scalar) -> (G, G, G::Scalar)
+{
+  let public_key = G::generator() * secret;
+  let commitment = G::generator() * nonce;
+  (public_key, commitment, *nonce + *challenge * secret)
+}
+
+fn main()
+{
+  let mut rng = ChaCha20Rng::from_entropy();
+  let secret = PresetScalar::<GF257>::random(&mut rng);
+  let nonce = PresetScalar::<GF257>::random(&mut rng);
+  let challenge = PresetScalar::<GF257>::random(&mut rng);
+  let (public_key, commitment, response) = schnorr_prove::<PresetPoint<GF257>>(&secret, &nonce, &challenge);
+  // Verification $sG = R + cQ$
+  assert_eq!(PresetPoint::generator() * response, commitment + public_key * challenge);
+  println!("scalar field: {} bits, 2-adicity {}", PresetScalar::<GF257>::NUM_BITS, PresetScalar::<GF257>::S);
+  println!("public key: {:?}", public_key.to_affine());
+  println!("Schnorr identification over M-257 preset is verified with generic group code");
+}
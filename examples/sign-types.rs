@@ -62,7 +62,7 @@ fn sign_rng<'a, T : GFArithmetic<'a>>(
 {
   let (private_key, pub_key) = SigningKey::generate(rng, ec.clone(), 1024).unwrap();
   let signature = private_key.sign_with_rng(rng, msg);
-  (private_key.sign_with_rng(rng, msg), private_key, pub_key)
+  (signature, private_key, pub_key)
 }
 fn sign_digest<'a, T : GFArithmetic<'a>, D : Digest>(
   digest : D,
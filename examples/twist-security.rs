@@ -0,0 +1,36 @@
+use num_traits::One;
+use rust_ec::registry::DstuCurve;
+use rust_ec::twist::TWIST_RHO_ITERATIONS;
+
+fn main()
+{
+  // Order of the twist matters for x-only arithmetic: if twist points aren't rejected,
+  // the secret scalar leaks modulo every small factor of $\#E'$
+  for curve in DstuCurve::ALL
+  {
+    let ec = curve.generate();
+    let security = ec.twist_security();
+    let factors = security
+      .get_ref_factors()
+      .iter()
+      .map(|(r, e)| if *e == 1 { format!("{r:x}") } else { format!("{r:x}^{e}") })
+      .collect::<Vec<_>>()
+      .join(" * ");
+    let largest_factor = match security.largest_prime_factor()
+    {
+      Some(r) => format!("{} bits, security {} bits", r.bits(), r.bits() / 2),
+      None => format!(
+        "unknown, composite cofactor of {} bits isn't split within {TWIST_RHO_ITERATIONS} iterations",
+        security.get_ref_cofactor().bits()
+      ),
+    };
+    println!(
+      "m{}: #E' = {:x} = {}{}, largest prime factor: {}",
+      ec.get_m(),
+      security.get_ref_twist_order(),
+      factors,
+      if security.get_ref_cofactor().is_one() { String::new() } else { " * c".to_string() },
+      largest_factor
+    );
+  }
+}
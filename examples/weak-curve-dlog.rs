@@ -0,0 +1,75 @@
+use std::marker::PhantomData;
+use dstu4145_rust::sign::SigningKey;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use poly_algebra::gf::{GFArithmetic, GF31, GF59};
+use poly_algebra::helpers::generate_num;
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
+use rust_ec::affine_point::AffinePoint;
+use rust_ec::binary_ec::{ACoefficient, BinaryEC};
+use rust_ec::ecdlp::{factorize, pohlig_hellman, point_order};
+use rust_ec::error::EcError;
+use rust_ec::point_counting::count_points;
+
+/// Upper bound of the largest prime factor of the number of points on the weak EC.
+const SMOOTHNESS_BOUND : u64 = 1 << 24;
+
+fn main()
+{
+  let mut rng = ChaCha20Rng::from_entropy();
+
+  // EC over GF(2^31) passes validation, but the subgroup of order $n \approx 2^{29}$
+  // is small enough to solve $-Q = dP$ with Pollard's rho
+  let (ec, _) = BinaryEC::<GF31>::generate_random(&mut rng).unwrap();
+  let (private_key, pub_key) = SigningKey::generate(&mut rng, ec.clone(), 64).unwrap();
+  let d = ec.discrete_log(&ec.get_bp(), &pub_key.get_pub_key().negative(), &mut rng).unwrap();
+  assert_eq!(d, private_key.get_private_key().to_biguint());
+  println!("n: {:x}, recovered private key: {:x}", ec.get_ref_ord(), d);
+
+  // Point $(x, y)$ lies on the EC with $B = y^2 + xy + x^3$, such EC's are chosen until
+  // number of points is smooth, then Pohlig–Hellman reduces logarithm to the tiny subgroups
+  loop
+  {
+    let x = GF59::from(generate_num(&mut rng, GF59::get_m()));
+    let y = GF59::from(generate_num(&mut rng, GF59::get_m()));
+    let b = y.square() + x.clone() * y.clone() + x.clone() * x.square();
+    if b.is_zero()
+    {
+      continue;
+    }
+    let points_num = count_points(&ACoefficient::Zero(PhantomData), &b);
+    let factors = factorize(&points_num);
+    let largest_factor = factors.last().map(|(r, _)| r.clone()).unwrap_or(BigUint::one());
+    if largest_factor > BigUint::from(SMOOTHNESS_BOUND)
+    {
+      continue;
+    }
+    let bp = AffinePoint::Point { x, y };
+    let weak_ec = BinaryEC::new_unchecked(
+      ACoefficient::Zero(PhantomData),
+      b.clone(),
+      bp.clone(),
+      points_num.clone(),
+      BigUint::one(),
+    );
+    let ord = point_order(&weak_ec, &bp);
+    let d = generate_num(&mut rng, GF59::get_m()) % &ord;
+    let q = weak_ec.mul(&bp, d.clone());
+    let recovered = pohlig_hellman(&weak_ec, &bp, &q, &ord, &mut rng).unwrap();
+    assert_eq!(recovered, d);
+    println!(
+      "#E: {:x} = {}, ord(P): {:x}, recovered private key: {:x}",
+      points_num,
+      factors.iter().map(|(r, e)| format!("{r:x}^{e}")).collect::<Vec<_>>().join(" * "),
+      ord,
+      recovered
+    );
+
+    // So validation in [BinaryEC::new] has to reject composite order of the base point
+    let result = BinaryEC::new(ACoefficient::Zero(PhantomData), b, bp, points_num, BigUint::one());
+    assert!(matches!(result, Err(EcError::CompositeOrder(_))));
+    println!("EC is rejected by validation: {:?}", result.unwrap_err());
+    break;
+  }
+}
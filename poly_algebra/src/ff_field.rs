@@ -0,0 +1,259 @@
+//! Implementation of the [ff::Field] for binary fields, that is enabled with feature `ff`.
+//!
+//! Elements of the `GF` types keep their polynomials in [BigUint], so they can't be [Copy], which is required by
+//! [ff::Field]. [FieldElement] keeps polynomial in the fixed number of 64-bit limbs instead, addition is performed
+//! directly on limbs, while multiplication, squaring and inversion are delegated to the `GF` type.
+//! _Only addition, comparison and selection don't depend on the value, other operations aren't constant time._
+use std::fmt;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use ff::Field;
+use num_bigint::BigUint;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use crate::gf::GFArithmetic;
+
+/// Number of 64-bit limbs in the [FieldElement], that is enough for the biggest field `GF571`.
+pub const FIELD_ELEMENT_LIMBS : usize = 9;
+
+/// Element of the binary field `T` in the little-endian 64-bit limbs, it can be converted from and into `T`.
+pub struct FieldElement<T>
+{
+  limbs : [u64; FIELD_ELEMENT_LIMBS],
+  _field : PhantomData<fn() -> T>,
+}
+
+impl<T : for<'a> GFArithmetic<'a>> FieldElement<T>
+{
+  /// Function creates element from the little-endian limbs, polynomial has to be of degree less than $m$.
+  pub const fn from_limbs(limbs : [u64; FIELD_ELEMENT_LIMBS]) -> Self
+  {
+    FieldElement {
+      limbs,
+      _field : PhantomData,
+    }
+  }
+
+  pub fn get_limbs(&self) -> [u64; FIELD_ELEMENT_LIMBS] { self.limbs }
+
+  /// Function converts element into the `GF` type.
+  pub fn to_gf(&self) -> T { T::from_poly(limbs_to_biguint(&self.limbs)) }
+
+  /// Function adds polynomials limb by limb.
+  fn xor(mut self, rhs : &Self) -> Self
+  {
+    for (limb, rhs_limb) in self.limbs.iter_mut().zip(rhs.limbs.iter())
+    {
+      *limb ^= rhs_limb;
+    }
+    self
+  }
+}
+
+/// Function converts little-endian limbs into the number.
+pub fn limbs_to_biguint(limbs : &[u64]) -> BigUint
+{
+  BigUint::new(limbs.iter().flat_map(|limb| [*limb as u32, (limb >> 32) as u32]).collect())
+}
+
+/// Function converts number into the little-endian limbs, _number has to fit into them_.
+pub fn biguint_to_limbs(n : &BigUint) -> [u64; FIELD_ELEMENT_LIMBS]
+{
+  let mut limbs = [0; FIELD_ELEMENT_LIMBS];
+  for (limb, digit) in limbs.iter_mut().zip(n.iter_u64_digits())
+  {
+    *limb = digit;
+  }
+  limbs
+}
+
+impl<T : for<'a> GFArithmetic<'a>> From<T> for FieldElement<T>
+{
+  fn from(value : T) -> Self
+  {
+    assert!(
+      T::get_m() as usize <= 64 * FIELD_ELEMENT_LIMBS,
+      "field element has to fit into {FIELD_ELEMENT_LIMBS} limbs"
+    );
+    FieldElement::from_limbs(biguint_to_limbs(value.get_ref_value()))
+  }
+}
+
+impl<T> Clone for FieldElement<T>
+{
+  fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for FieldElement<T> {}
+
+impl<T> PartialEq for FieldElement<T>
+{
+  fn eq(&self, other : &Self) -> bool { self.limbs == other.limbs }
+}
+
+impl<T> Eq for FieldElement<T> {}
+
+impl<T> Hash for FieldElement<T>
+{
+  fn hash<H : Hasher>(&self, state : &mut H) { self.limbs.hash(state) }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> Default for FieldElement<T>
+{
+  fn default() -> Self { Self::ZERO }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> fmt::Debug for FieldElement<T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { write!(f, "FieldElement({:x})", limbs_to_biguint(&self.limbs)) }
+}
+
+impl<T> ConstantTimeEq for FieldElement<T>
+{
+  fn ct_eq(&self, other : &Self) -> Choice { self.limbs[..].ct_eq(&other.limbs[..]) }
+}
+
+impl<T> ConditionallySelectable for FieldElement<T>
+{
+  fn conditional_select(a : &Self, b : &Self, choice : Choice) -> Self
+  {
+    let mut limbs = [0; FIELD_ELEMENT_LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate()
+    {
+      *limb = u64::conditional_select(&a.limbs[i], &b.limbs[i], choice);
+    }
+    FieldElement {
+      limbs,
+      _field : PhantomData,
+    }
+  }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> Neg for FieldElement<T>
+{
+  type Output = Self;
+
+  /// Every element is negative to itself in the field of characteristic 2.
+  fn neg(self) -> Self { self }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> Add<&FieldElement<T>> for FieldElement<T>
+{
+  type Output = Self;
+
+  fn add(self, rhs : &Self) -> Self { self.xor(rhs) }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> Sub<&FieldElement<T>> for FieldElement<T>
+{
+  type Output = Self;
+
+  /// Subtraction is the same as addition in the field of characteristic 2.
+  fn sub(self, rhs : &Self) -> Self { self.xor(rhs) }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> Mul<&FieldElement<T>> for FieldElement<T>
+{
+  type Output = Self;
+
+  fn mul(self, rhs : &Self) -> Self { FieldElement::from(self.to_gf() * rhs.to_gf()) }
+}
+
+/// Macro implements owned and assigning variants of the binary operation through the one with reference.
+macro_rules! impl_field_ops {
+  ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+    impl<T : for<'a> GFArithmetic<'a>> $op<FieldElement<T>> for FieldElement<T>
+    {
+      type Output = Self;
+
+      fn $op_fn(self, rhs : Self) -> Self { self.$op_fn(&rhs) }
+    }
+
+    impl<T : for<'a> GFArithmetic<'a>> $op_assign<FieldElement<T>> for FieldElement<T>
+    {
+      fn $op_assign_fn(&mut self, rhs : Self) { *self = self.$op_fn(&rhs) }
+    }
+
+    impl<T : for<'a> GFArithmetic<'a>> $op_assign<&FieldElement<T>> for FieldElement<T>
+    {
+      fn $op_assign_fn(&mut self, rhs : &Self) { *self = self.$op_fn(rhs) }
+    }
+  };
+}
+
+impl_field_ops!(Add, add, AddAssign, add_assign);
+impl_field_ops!(Sub, sub, SubAssign, sub_assign);
+impl_field_ops!(Mul, mul, MulAssign, mul_assign);
+
+impl<T : for<'a> GFArithmetic<'a>> Sum for FieldElement<T>
+{
+  fn sum<I : Iterator<Item = Self>>(iter : I) -> Self { iter.fold(Self::ZERO, |acc, x| acc + x) }
+}
+
+impl<'b, T : for<'a> GFArithmetic<'a>> Sum<&'b FieldElement<T>> for FieldElement<T>
+{
+  fn sum<I : Iterator<Item = &'b Self>>(iter : I) -> Self { iter.fold(Self::ZERO, |acc, x| acc + x) }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> Product for FieldElement<T>
+{
+  fn product<I : Iterator<Item = Self>>(iter : I) -> Self { iter.fold(Self::ONE, |acc, x| acc * x) }
+}
+
+impl<'b, T : for<'a> GFArithmetic<'a>> Product<&'b FieldElement<T>> for FieldElement<T>
+{
+  fn product<I : Iterator<Item = &'b Self>>(iter : I) -> Self { iter.fold(Self::ONE, |acc, x| acc * x) }
+}
+
+impl<T : for<'a> GFArithmetic<'a>> Field for FieldElement<T>
+{
+  const ZERO : Self = FieldElement::from_limbs([0; FIELD_ELEMENT_LIMBS]);
+  const ONE : Self = {
+    let mut limbs = [0; FIELD_ELEMENT_LIMBS];
+    limbs[0] = 1;
+    FieldElement::from_limbs(limbs)
+  };
+
+  /// Function generates uniformly distributed element, limbs are filled with random bits and truncated to $m$ bits.
+  fn random(mut rng : impl RngCore) -> Self
+  {
+    let m = T::get_m() as usize;
+    let mut limbs = [0; FIELD_ELEMENT_LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate().take(m.div_ceil(64))
+    {
+      let bits = (m - 64 * i).min(64);
+      *limb = if bits == 64 { rng.next_u64() } else { rng.next_u64() & ((1 << bits) - 1) };
+    }
+    FieldElement::from_limbs(limbs)
+  }
+
+  fn square(&self) -> Self { FieldElement::from(self.to_gf().square()) }
+
+  /// Function doubles element, which is always zero in the field of characteristic 2.
+  fn double(&self) -> Self { Self::ZERO }
+
+  fn invert(&self) -> CtOption<Self>
+  {
+    let is_nonzero = !self.is_zero();
+    let inverse = if bool::from(is_nonzero) { FieldElement::from(self.to_gf().inverse()) } else { Self::ZERO };
+    CtOption::new(inverse, is_nonzero)
+  }
+
+  /// Function calculates $\sqrt{num / div}$, every element is a square in the binary field,
+  /// so the root doesn't exist only when $div = 0$ and $num \ne 0$.
+  fn sqrt_ratio(num : &Self, div : &Self) -> (Choice, Self)
+  {
+    if bool::from(num.is_zero())
+    {
+      return (Choice::from(1), Self::ZERO);
+    }
+    if bool::from(div.is_zero())
+    {
+      return (Choice::from(0), Self::ZERO);
+    }
+    (Choice::from(1), FieldElement::from((num.to_gf() * div.to_gf().inverse()).sqrt()))
+  }
+}
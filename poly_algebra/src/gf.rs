@@ -1,3 +1,8 @@
 mod gf_def;
 pub(crate) mod gf_expand;
-pub use gf_def::{GFArithmetic, GFGetters, GFDisplay, GF163, GF167, GF173, GF179, GF191, GF233, GF257, GF307, GF367, GF431};
+pub use gf_def::{
+  GFArithmetic, GFGetters, GFDisplay, GF163, GF167, GF173, GF179, GF191, GF233, GF233Nist, GF257, GF283, GF307, GF367, GF409,
+  GF431, GF571,
+};
+#[cfg(any(test, feature = "toy-fields"))]
+pub use gf_def::{GF5, GF7, GF11, GF13, GF17, GF23, GF29, GF31, GF37, GF41, GF47, GF53, GF59};
@@ -10,7 +10,7 @@ use crate::{
   helpers::{
     create_prime_polynomial, to_binary_be, to_lower_hex_be, to_upper_hex_be, to_binary_le, to_lower_hex_le, to_upper_hex_le,
   },
-  gf_arithmetic::{add, module_reduction, trace, inverse, mul, htrace, pow, square},
+  gf_arithmetic::{add, module_reduction, trace, inverse, mul, htrace, pow, square, sqrt},
   gf::gf_def::private::{GFFactory, SealingStruct},
   impl_gf_for_poly, impl_gf_display, impl_gf_conversions,
 };
@@ -108,6 +108,16 @@ pub trait GFArithmetic<'a>:
     pow(&mut poly, self.get_ref_prime_poly(), &power.into());
     <Self as GFFactory>::new(poly, self.get_prime_poly(), SealingStruct {})
   }
+
+  /// Function calculates square root of the element, which is always unique in binary field.
+  fn sqrt(&self) -> Self
+  {
+    <Self as GFFactory>::new(
+      sqrt(self.get_ref_value(), self.get_ref_prime_poly()),
+      self.get_prime_poly(),
+      SealingStruct {},
+    )
+  }
 }
 
 pub trait GFGetters
@@ -133,10 +143,14 @@ static GF173_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_pr
 static GF179_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF179_PRIME_POLY));
 static GF191_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF191_PRIME_POLY));
 static GF233_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF233_PRIME_POLY));
+static GF233_NIST_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF233_NIST_PRIME_POLY));
 static GF257_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF257_PRIME_POLY));
+static GF283_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF283_PRIME_POLY));
 static GF307_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF307_PRIME_POLY));
 static GF367_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF367_PRIME_POLY));
+static GF409_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF409_PRIME_POLY));
 static GF431_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF431_PRIME_POLY));
+static GF571_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF571_PRIME_POLY));
 /// GF 2^163 over prime polynomial `x^163 + x^7 + x^6 + x^3 + 1`.
 #[derive(Hash, Eq, PartialEq, Clone)]
 pub struct GF163
@@ -179,6 +193,14 @@ pub struct GF233
   pub poly : BigUint,
   prime_poly : BigUint,
 }
+/// GF 2^233 over prime polynomial `x^233 + x^74 + 1` from the FIPS 186 standard,
+/// it differs from the polynomial of [GF233] recommended for DSTU 4145-2002.
+#[derive(Hash, Eq, PartialEq, Clone)]
+pub struct GF233Nist
+{
+  pub poly : BigUint,
+  prime_poly : BigUint,
+}
 /// GF 2^257 over prime polynomial `x^257 + x^12 + 1`.
 #[derive(Hash, Eq, PartialEq, Clone)]
 pub struct GF257
@@ -186,6 +208,13 @@ pub struct GF257
   pub poly : BigUint,
   prime_poly : BigUint,
 }
+/// GF 2^283 over prime polynomial `x^283 + x^12 + x^7 + x^5 + 1` from the FIPS 186 standard.
+#[derive(Hash, Eq, PartialEq, Clone)]
+pub struct GF283
+{
+  pub poly : BigUint,
+  prime_poly : BigUint,
+}
 /// GF 2^307 over prime polynomial `x^307 + x^8 + x^4 + x^2 + 1`.
 #[derive(Hash, Eq, PartialEq, Clone)]
 pub struct GF307
@@ -200,6 +229,13 @@ pub struct GF367
   pub poly : BigUint,
   prime_poly : BigUint,
 }
+/// GF 2^409 over prime polynomial `x^409 + x^87 + 1` from the FIPS 186 standard.
+#[derive(Hash, Eq, PartialEq, Clone)]
+pub struct GF409
+{
+  pub poly : BigUint,
+  prime_poly : BigUint,
+}
 /// GF 2^431 over prime polynomial `x^431 + x^5 + x^3 + x + 1`.
 #[derive(Hash, Eq, PartialEq, Clone)]
 pub struct GF431
@@ -207,6 +243,13 @@ pub struct GF431
   pub poly : BigUint,
   prime_poly : BigUint,
 }
+/// GF 2^571 over prime polynomial `x^571 + x^10 + x^5 + x^2 + 1` from the FIPS 186 standard.
+#[derive(Hash, Eq, PartialEq, Clone)]
+pub struct GF571
+{
+  pub poly : BigUint,
+  prime_poly : BigUint,
+}
 const GF163_PRIME_POLY : [u32; 5] = [163_u32, 7, 6, 3, 0];
 const GF167_PRIME_POLY : [u32; 3] = [167_u32, 6, 0];
 const GF173_PRIME_POLY : [u32; 5] = [173_u32, 10, 2, 1, 0];
@@ -217,6 +260,10 @@ const GF257_PRIME_POLY : [u32; 3] = [257_u32, 12, 0];
 const GF307_PRIME_POLY : [u32; 5] = [307_u32, 8, 4, 2, 0];
 const GF367_PRIME_POLY : [u32; 3] = [367_u32, 21, 0];
 const GF431_PRIME_POLY : [u32; 5] = [431_u32, 5, 3, 1, 0];
+const GF233_NIST_PRIME_POLY : [u32; 3] = [233_u32, 74, 0];
+const GF283_PRIME_POLY : [u32; 5] = [283_u32, 12, 7, 5, 0];
+const GF409_PRIME_POLY : [u32; 3] = [409_u32, 87, 0];
+const GF571_PRIME_POLY : [u32; 5] = [571_u32, 10, 5, 2, 0];
 
 impl_gf_for_poly!(GF163, &GF163_PRIME_POLY, GF163_PRECALC_PRIME_POLY);
 impl_gf_display!(GF163);
@@ -257,3 +304,200 @@ impl_gf_conversions!(GF367);
 impl_gf_for_poly!(GF431, &GF431_PRIME_POLY, GF431_PRECALC_PRIME_POLY);
 impl_gf_display!(GF431);
 impl_gf_conversions!(GF431);
+
+impl_gf_for_poly!(GF233Nist, &GF233_NIST_PRIME_POLY, GF233_NIST_PRECALC_PRIME_POLY);
+impl_gf_display!(GF233Nist);
+impl_gf_conversions!(GF233Nist);
+
+impl_gf_for_poly!(GF283, &GF283_PRIME_POLY, GF283_PRECALC_PRIME_POLY);
+impl_gf_display!(GF283);
+impl_gf_conversions!(GF283);
+
+impl_gf_for_poly!(GF409, &GF409_PRIME_POLY, GF409_PRECALC_PRIME_POLY);
+impl_gf_display!(GF409);
+impl_gf_conversions!(GF409);
+
+impl_gf_for_poly!(GF571, &GF571_PRIME_POLY, GF571_PRECALC_PRIME_POLY);
+impl_gf_display!(GF571);
+impl_gf_conversions!(GF571);
+
+/// Small fields for testing & toy examples, they are available only with the `toy-fields` feature.
+/// Discrete logarithm on the EC over such field is found in seconds, so they mustn't be used
+/// for anything except tests, teaching and demonstration of attacks.
+#[cfg(any(test, feature = "toy-fields"))]
+mod toy
+{
+  use super::*;
+
+  const GF5_PRIME_POLY : [u32; 3] = [5_u32, 2, 0];
+  const GF7_PRIME_POLY : [u32; 3] = [7_u32, 1, 0];
+  const GF11_PRIME_POLY : [u32; 3] = [11_u32, 2, 0];
+  const GF13_PRIME_POLY : [u32; 5] = [13_u32, 4, 3, 1, 0];
+  const GF17_PRIME_POLY : [u32; 3] = [17_u32, 3, 0];
+  const GF23_PRIME_POLY : [u32; 3] = [23_u32, 5, 0];
+  const GF29_PRIME_POLY : [u32; 3] = [29_u32, 2, 0];
+  const GF31_PRIME_POLY : [u32; 3] = [31_u32, 3, 0];
+  const GF37_PRIME_POLY : [u32; 5] = [37_u32, 6, 4, 1, 0];
+  const GF41_PRIME_POLY : [u32; 3] = [41_u32, 3, 0];
+  const GF47_PRIME_POLY : [u32; 3] = [47_u32, 5, 0];
+  const GF53_PRIME_POLY : [u32; 5] = [53_u32, 6, 2, 1, 0];
+  const GF59_PRIME_POLY : [u32; 5] = [59_u32, 7, 4, 2, 0];
+  static GF5_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF5_PRIME_POLY));
+  static GF7_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF7_PRIME_POLY));
+  static GF11_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF11_PRIME_POLY));
+  static GF13_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF13_PRIME_POLY));
+  static GF17_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF17_PRIME_POLY));
+  static GF23_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF23_PRIME_POLY));
+  static GF29_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF29_PRIME_POLY));
+  static GF31_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF31_PRIME_POLY));
+  static GF37_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF37_PRIME_POLY));
+  static GF41_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF41_PRIME_POLY));
+  static GF47_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF47_PRIME_POLY));
+  static GF53_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF53_PRIME_POLY));
+  static GF59_PRECALC_PRIME_POLY : LazyLock<BigUint> = LazyLock::new(|| create_prime_polynomial(&GF59_PRIME_POLY));
+  /// GF 2^5 over prime polynomial `x^5 + x^2 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF5
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^7 over prime polynomial `x^7 + x + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF7
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^11 over prime polynomial `x^11 + x^2 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF11
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^13 over prime polynomial `x^13 + x^4 + x^3 + x + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF13
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^17 over prime polynomial `x^17 + x^3 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF17
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^23 over prime polynomial `x^23 + x^5 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF23
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^29 over prime polynomial `x^29 + x^2 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF29
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^31 over prime polynomial `x^31 + x^3 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF31
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^37 over prime polynomial `x^37 + x^6 + x^4 + x + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF37
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^41 over prime polynomial `x^41 + x^3 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF41
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^47 over prime polynomial `x^47 + x^5 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF47
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^53 over prime polynomial `x^53 + x^6 + x^2 + x + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF53
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+  /// GF 2^59 over prime polynomial `x^59 + x^7 + x^4 + x^2 + 1`, small field for testing & toy examples.
+  #[derive(Hash, Eq, PartialEq, Clone)]
+  pub struct GF59
+  {
+    pub poly : BigUint,
+    prime_poly : BigUint,
+  }
+
+  impl_gf_for_poly!(GF5, &GF5_PRIME_POLY, GF5_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF5);
+  impl_gf_conversions!(GF5);
+
+  impl_gf_for_poly!(GF7, &GF7_PRIME_POLY, GF7_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF7);
+  impl_gf_conversions!(GF7);
+
+  impl_gf_for_poly!(GF11, &GF11_PRIME_POLY, GF11_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF11);
+  impl_gf_conversions!(GF11);
+
+  impl_gf_for_poly!(GF13, &GF13_PRIME_POLY, GF13_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF13);
+  impl_gf_conversions!(GF13);
+
+  impl_gf_for_poly!(GF17, &GF17_PRIME_POLY, GF17_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF17);
+  impl_gf_conversions!(GF17);
+
+  impl_gf_for_poly!(GF23, &GF23_PRIME_POLY, GF23_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF23);
+  impl_gf_conversions!(GF23);
+
+  impl_gf_for_poly!(GF29, &GF29_PRIME_POLY, GF29_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF29);
+  impl_gf_conversions!(GF29);
+
+  impl_gf_for_poly!(GF31, &GF31_PRIME_POLY, GF31_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF31);
+  impl_gf_conversions!(GF31);
+
+  impl_gf_for_poly!(GF37, &GF37_PRIME_POLY, GF37_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF37);
+  impl_gf_conversions!(GF37);
+
+  impl_gf_for_poly!(GF41, &GF41_PRIME_POLY, GF41_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF41);
+  impl_gf_conversions!(GF41);
+
+  impl_gf_for_poly!(GF47, &GF47_PRIME_POLY, GF47_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF47);
+  impl_gf_conversions!(GF47);
+
+  impl_gf_for_poly!(GF53, &GF53_PRIME_POLY, GF53_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF53);
+  impl_gf_conversions!(GF53);
+
+  impl_gf_for_poly!(GF59, &GF59_PRIME_POLY, GF59_PRECALC_PRIME_POLY);
+  impl_gf_display!(GF59);
+  impl_gf_conversions!(GF59);
+}
+#[cfg(any(test, feature = "toy-fields"))]
+pub use toy::{GF5, GF7, GF11, GF13, GF17, GF23, GF29, GF31, GF37, GF41, GF47, GF53, GF59};
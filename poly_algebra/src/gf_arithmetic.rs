@@ -27,24 +27,29 @@ pub fn mul(a : &mut BigUint, b : &BigUint, prime_poly : &BigUint)
 }
 
 /// Function that calculates square of polynomial with help of thinning out with zeros.
+/// Every 16-bit chunk is spread into 32-bit one by interleaving its bits with zeros.
 pub fn square(a : &mut BigUint, prime_poly : &BigUint)
 {
-  let mut res = BigUint::zero();
-  let mut tmp = a.clone();
-  let one = BigUint::one();
-  for i in 0 .. a.bits()
+  let mut digits = Vec::with_capacity(a.iter_u32_digits().len() * 2);
+  for digit in a.iter_u32_digits()
   {
-    if &tmp & &one == one
-    {
-      // i << 1 = 2*i
-      add(&mut res, &(&one << (i << 1)))
-    }
-    tmp >>= 1;
+    digits.push(spread_u16_with_zeros(digit as u16));
+    digits.push(spread_u16_with_zeros((digit >> 16) as u16));
   }
-  let _ = mem::replace(a, res);
+  let _ = mem::replace(a, BigUint::new(digits));
   module_reduction(a, prime_poly);
 }
 
+/// Function inserts zero bit after every bit of the number: $b_{15} \dots b_1 b_0 \to 0 b_{15} \dots 0 b_1 0 b_0$.
+fn spread_u16_with_zeros(x : u16) -> u32
+{
+  let mut x = x as u32;
+  x = (x | (x << 8)) & 0x00FF_00FF;
+  x = (x | (x << 4)) & 0x0F0F_0F0F;
+  x = (x | (x << 2)) & 0x3333_3333;
+  (x | (x << 1)) & 0x5555_5555
+}
+
 /// Function that implements window method of powering numbers to some power.
 pub fn pow(a : &mut BigUint, prime_poly : &BigUint, power : &BigUint)
 {
@@ -86,30 +91,68 @@ pub fn _inverse_old(a : &BigUint, prime_poly : &BigUint) -> BigUint
   inverse
 }
 
-/// Function that implements regular polynomial reduction in binary field.
+/// Function that implements polynomial reduction in binary field.
+/// Prime polynomial is considered as $f(x) = x^m + r(x)$, so the part of the number that is higher
+/// than $x^m$ is folded back with help of $x^m \equiv r(x)$, that is fast for sparse polynomials.
 pub fn module_reduction(a : &mut BigUint, prime_poly : &BigUint)
 {
-  let mut a_bits = a.bits();
   let poly_size = prime_poly.bits();
-  while a_bits >= poly_size
+  if a.bits() < poly_size
   {
-    add(a, &(prime_poly << (a_bits - poly_size)));
-    a_bits = a.bits();
+    return;
+  }
+  let m = poly_size - 1;
+  let r_degs = (0 .. m).filter(|deg| prime_poly.bit(*deg)).collect::<Vec<u64>>();
+  let mask = (BigUint::one() << m) - BigUint::one();
+  while a.bits() > m
+  {
+    let high = &*a >> m;
+    *a &= &mask;
+    for deg in r_degs.iter()
+    {
+      add(a, &(&high << *deg));
+    }
   }
 }
 
-/// Function to calculate trace of the element in field which is implemented from `6.5` algorithm.
-pub fn trace(a : &BigUint, prime_poly : &BigUint) -> BigUint
+/// Function calculates square root of the element with usage of $m - 1$ squarings,
+/// because in binary field $\sqrt{a} = a^{2^{m - 1}}$.
+pub fn sqrt(a : &BigUint, prime_poly : &BigUint) -> BigUint
 {
   let poly_size = prime_poly.bits();
   let mut t = a.clone();
   for _ in 1 .. poly_size - 1
   {
     square(&mut t, prime_poly);
-    add(&mut t, a);
   }
   t
 }
+
+/// Function to calculate trace of the element in field.
+/// Trace is linear, so it's calculated as $Tr(a) = \sum a_i Tr(x^i)$, where $Tr(x^i)$ are power sums
+/// of the prime polynomial roots that are found with help of Newton's identities.
+pub fn trace(a : &BigUint, prime_poly : &BigUint) -> BigUint
+{
+  let m = prime_poly.bits() - 1;
+  // Degrees `j` of nonzero coefficients $c_{m - j}$ of the prime polynomial
+  let shifts = (1 .. m).filter(|j| prime_poly.bit(m - *j)).collect::<Vec<u64>>();
+  let mut power_sums = Vec::with_capacity(m as usize);
+  power_sums.push(m & 1 == 1);
+  for i in 1 .. m
+  {
+    let mut s = i & 1 == 1 && prime_poly.bit(m - i);
+    for j in shifts.iter().take_while(|j| **j < i)
+    {
+      s ^= power_sums[(i - *j) as usize];
+    }
+    power_sums.push(s);
+  }
+  let t = (0 .. a.bits().min(m))
+    .filter(|i| power_sums[*i as usize] && a.bit(*i))
+    .count();
+  BigUint::from((t & 1) as u8)
+}
+
 /// Function to calculate half trace which is implemented from algorithm `6.6`.
 pub fn htrace(a : &BigUint, prime_poly : &BigUint) -> BigUint
 {
@@ -1,4 +1,6 @@
 #![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
+#[cfg(feature = "ff")]
+pub mod ff_field;
 pub mod gf;
 pub mod gf_arithmetic;
 pub mod helpers;
@@ -1,10 +1,12 @@
 #[cfg(test)]
 mod tests
 {
-  use num_bigint::{BigUint, ParseBigIntError};
+  use num_bigint::BigUint;
   use num_traits::{Num, One, Zero};
-  use poly_algebra::gf::{GFArithmetic, GFDisplay, GFGetters, GF163, GF167, GF173, GF179, GF191, GF233, GF257, GF307, GF367, GF431};
-  use poly_algebra::gf_arithmetic::_inverse_old;
+  use poly_algebra::gf::{
+    GFArithmetic, GFGetters, GF5, GF7, GF11, GF13, GF17, GF23, GF29, GF31, GF37, GF41, GF47, GF53, GF59, GF163, GF167, GF173,
+    GF179, GF191, GF233, GF233Nist, GF257, GF283, GF307, GF367, GF409, GF431, GF571,
+  };
   use poly_algebra::helpers::generate_num;
   use proptest::arbitrary::any;
   use proptest::collection::vec;
@@ -30,16 +32,20 @@ mod tests
       .prop_map(|bytes| BigUint::from_bytes_le(&bytes))
       .prop_filter("BigUint should not be zero", |n| *n != BigUint::zero())
   }
-  fn arb_gf163() -> impl Strategy<Value = GF163> { arb_biguint().prop_map(|x| GF163::from(x)) }
-  fn arb_gf167() -> impl Strategy<Value = GF167> { arb_biguint().prop_map(|x| GF167::from(x)) }
-  fn arb_gf173() -> impl Strategy<Value = GF173> { arb_biguint().prop_map(|x| GF173::from(x)) }
-  fn arb_gf179() -> impl Strategy<Value = GF179> { arb_biguint().prop_map(|x| GF179::from(x)) }
-  fn arb_gf191() -> impl Strategy<Value = GF191> { arb_biguint().prop_map(|x| GF191::from(x)) }
-  fn arb_gf233() -> impl Strategy<Value = GF233> { arb_biguint().prop_map(|x| GF233::from(x)) }
-  fn arb_gf257() -> impl Strategy<Value = GF257> { arb_biguint().prop_map(|x| GF257::from(x)) }
-  fn arb_gf307() -> impl Strategy<Value = GF307> { arb_biguint().prop_map(|x| GF307::from(x)) }
-  fn arb_gf367() -> impl Strategy<Value = GF367> { arb_biguint().prop_map(|x| GF367::from(x)) }
-  fn arb_gf431() -> impl Strategy<Value = GF431> { arb_biguint().prop_map(|x| GF431::from(x)) }
+  fn arb_gf163() -> impl Strategy<Value = GF163> { arb_biguint().prop_map(GF163::from) }
+  fn arb_gf167() -> impl Strategy<Value = GF167> { arb_biguint().prop_map(GF167::from) }
+  fn arb_gf173() -> impl Strategy<Value = GF173> { arb_biguint().prop_map(GF173::from) }
+  fn arb_gf179() -> impl Strategy<Value = GF179> { arb_biguint().prop_map(GF179::from) }
+  fn arb_gf191() -> impl Strategy<Value = GF191> { arb_biguint().prop_map(GF191::from) }
+  fn arb_gf233() -> impl Strategy<Value = GF233> { arb_biguint().prop_map(GF233::from) }
+  fn arb_gf257() -> impl Strategy<Value = GF257> { arb_biguint().prop_map(GF257::from) }
+  fn arb_gf307() -> impl Strategy<Value = GF307> { arb_biguint().prop_map(GF307::from) }
+  fn arb_gf367() -> impl Strategy<Value = GF367> { arb_biguint().prop_map(GF367::from) }
+  fn arb_gf431() -> impl Strategy<Value = GF431> { arb_biguint().prop_map(GF431::from) }
+  fn arb_gf233_nist() -> impl Strategy<Value = GF233Nist> { arb_biguint().prop_map(GF233Nist::from) }
+  fn arb_gf283() -> impl Strategy<Value = GF283> { arb_biguint().prop_map(GF283::from) }
+  fn arb_gf409() -> impl Strategy<Value = GF409> { arb_biguint().prop_map(GF409::from) }
+  fn arb_gf571() -> impl Strategy<Value = GF571> { arb_biguint().prop_map(GF571::from) }
   fn arb_two_gf163() -> impl Strategy<Value = (GF163, GF163)> { (arb_gf163(), arb_gf163()) }
   fn arb_two_gf167() -> impl Strategy<Value = (GF167, GF167)> { (arb_gf167(), arb_gf167()) }
   fn arb_two_gf173() -> impl Strategy<Value = (GF173, GF173)> { (arb_gf173(), arb_gf173()) }
@@ -95,6 +101,19 @@ mod tests
   #[test]
   fn prime_poly_test()
   {
+    assert_eq!(GF5::zero().get_prime_poly(), BigUint::from(0b100101_u32));
+    assert_eq!(GF7::zero().get_prime_poly(), BigUint::from(0b10000011_u32));
+    assert_eq!(GF11::zero().get_prime_poly(), BigUint::from(0x805_u32));
+    assert_eq!(GF13::zero().get_prime_poly(), BigUint::from(0x201b_u32));
+    assert_eq!(GF17::zero().get_prime_poly(), BigUint::from(0x20009_u32));
+    assert_eq!(GF23::zero().get_prime_poly(), BigUint::from(0x800021_u64));
+    assert_eq!(GF29::zero().get_prime_poly(), BigUint::from(0x20000005_u64));
+    assert_eq!(GF31::zero().get_prime_poly(), BigUint::from(0x80000009_u64));
+    assert_eq!(GF37::zero().get_prime_poly(), BigUint::from(0x2000000053_u64));
+    assert_eq!(GF41::zero().get_prime_poly(), BigUint::from(0x20000000009_u64));
+    assert_eq!(GF47::zero().get_prime_poly(), BigUint::from(0x800000000021_u64));
+    assert_eq!(GF53::zero().get_prime_poly(), BigUint::from(0x20000000000047_u64));
+    assert_eq!(GF59::zero().get_prime_poly(), BigUint::from(0x800000000000095_u64));
     assert_eq!(
       GF163::zero().get_prime_poly(),
       BigUint::from_str_radix("800000000000000000000000000000000000000c9", 16).unwrap()
@@ -147,6 +166,30 @@ mod tests
       )
       .unwrap()
     );
+    assert_eq!(
+      GF233Nist::zero().get_prime_poly(),
+      BigUint::from_str_radix("20000000000000000000000000000000000000004000000000000000001", 16).unwrap()
+    );
+    assert_eq!(
+      GF283::zero().get_prime_poly(),
+      BigUint::from_str_radix("800000000000000000000000000000000000000000000000000000000000000000010a1", 16).unwrap()
+    );
+    assert_eq!(
+      GF409::zero().get_prime_poly(),
+      BigUint::from_str_radix(
+        "2000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000001",
+        16
+      )
+      .unwrap()
+    );
+    assert_eq!(
+      GF571::zero().get_prime_poly(),
+      BigUint::from_str_radix(
+        "80000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000425",
+        16
+      )
+      .unwrap()
+    );
   }
 
   // Associativity
@@ -410,6 +453,30 @@ mod tests
         assert_eq!(a.inverse() * a, GF431::one());
       }
   }
+  proptest! {
+      #[test]
+      fn inverse_test_233_nist(a in arb_gf233_nist()) {
+        assert_eq!(a.inverse() * a, GF233Nist::one());
+      }
+  }
+  proptest! {
+      #[test]
+      fn inverse_test_283(a in arb_gf283()) {
+        assert_eq!(a.inverse() * a, GF283::one());
+      }
+  }
+  proptest! {
+      #[test]
+      fn inverse_test_409(a in arb_gf409()) {
+        assert_eq!(a.inverse() * a, GF409::one());
+      }
+  }
+  proptest! {
+      #[test]
+      fn inverse_test_571(a in arb_gf571()) {
+        assert_eq!(a.inverse() * a, GF571::one());
+      }
+  }
 
   // Addition
 
@@ -637,4 +704,221 @@ mod tests
         assert_eq!((a.clone() + &b).htrace(), a.htrace() ^ b.htrace());
       }
   }
+
+  // Square root
+  proptest! {
+      #[test]
+      fn sqrt_test_163(a in arb_gf163()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_167(a in arb_gf167()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_173(a in arb_gf173()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_179(a in arb_gf179()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_191(a in arb_gf191()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_233(a in arb_gf233()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_257(a in arb_gf257()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_307(a in arb_gf307()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_367(a in arb_gf367()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+  proptest! {
+      #[test]
+      fn sqrt_test_431(a in arb_gf431()) {
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.square().sqrt(), a);
+      }
+  }
+
+  // Small fields are checked exhaustively
+  fn check_small_field<'a, T : GFArithmetic<'a>>()
+  {
+    let m = T::get_m();
+    let mut traces = 0;
+    for i in 1 .. 1_u32 << m
+    {
+      let a = T::from(BigUint::from(i));
+      assert_eq!(a.inverse() * a.clone(), T::one());
+      assert_eq!(a.sqrt().square(), a);
+      assert_eq!(a.pow(calc_pow(m)), T::one());
+      let h = T::from_poly(a.htrace());
+      if a.trace().is_zero()
+      {
+        // $H(a)^2 + H(a) = a$ when $Tr(a) = 0$
+        assert_eq!(h.square() + h, a);
+      }
+      else
+      {
+        traces += 1;
+      }
+    }
+    // Exactly half of the elements have trace 1
+    assert_eq!(traces, 1_u32 << (m - 1));
+  }
+
+  #[test]
+  fn small_fields_test()
+  {
+    check_small_field::<GF5>();
+    check_small_field::<GF7>();
+    check_small_field::<GF11>();
+    check_small_field::<GF13>();
+  }
+
+  // Toy fields are too big for exhaustive check, so random elements are sampled
+  fn check_toy_field<'a, T : GFArithmetic<'a>>(rng : &mut impl CryptoRngCore)
+  {
+    let m = T::get_m();
+    for _ in 0 .. ITERATIONS_NUM
+    {
+      let a = T::rand(rng);
+      if a.is_zero()
+      {
+        continue;
+      }
+      assert_eq!(a.inverse() * a.clone(), T::one());
+      assert_eq!(a.sqrt().square(), a);
+      assert_eq!(a.pow(calc_pow(m)), T::one());
+      if a.trace().is_zero()
+      {
+        let h = T::from_poly(a.htrace());
+        assert_eq!(h.square() + h, a);
+      }
+    }
+  }
+
+  #[test]
+  fn toy_fields_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_toy_field::<GF23>(&mut rng);
+    check_toy_field::<GF29>(&mut rng);
+    check_toy_field::<GF31>(&mut rng);
+    check_toy_field::<GF37>(&mut rng);
+    check_toy_field::<GF41>(&mut rng);
+    check_toy_field::<GF47>(&mut rng);
+    check_toy_field::<GF53>(&mut rng);
+    check_toy_field::<GF59>(&mut rng);
+  }
+
+  /// Function compares squaring with multiplication and trace with its definition $\sum_{i = 0}^{m - 1} a^{2^i}$.
+  fn check_square_and_trace<T : for<'a> GFArithmetic<'a>>(rng : &mut impl CryptoRngCore)
+  {
+    for _ in 0 .. 4
+    {
+      let a = T::rand(rng);
+      assert_eq!(a.square(), a.clone() * a.clone());
+      let mut power = a.clone();
+      let mut trace = a.clone();
+      for _ in 1 .. T::get_m()
+      {
+        power = power.square();
+        trace = trace + &power;
+      }
+      assert_eq!(trace.get_value(), a.trace());
+    }
+  }
+
+  #[test]
+  fn square_and_trace_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_square_and_trace::<GF163>(&mut rng);
+    check_square_and_trace::<GF233>(&mut rng);
+    check_square_and_trace::<GF409>(&mut rng);
+    check_square_and_trace::<GF431>(&mut rng);
+    check_square_and_trace::<GF571>(&mut rng);
+  }
+
+  #[cfg(feature = "ff")]
+  fn check_ff_field<T : for<'a> GFArithmetic<'a>>(rng : &mut impl CryptoRngCore)
+  {
+    use ff::Field;
+    use poly_algebra::ff_field::FieldElement;
+
+    assert_eq!(FieldElement::<T>::ZERO.to_gf(), T::zero());
+    assert_eq!(FieldElement::<T>::ONE.to_gf(), T::one());
+    assert!(bool::from(FieldElement::<T>::ZERO.invert().is_none()));
+    assert!(!bool::from(FieldElement::<T>::sqrt_ratio(&FieldElement::ONE, &FieldElement::ZERO).0));
+    for _ in 0 .. ITERATIONS_NUM
+    {
+      let (a, b) = (FieldElement::<T>::random(&mut *rng), FieldElement::<T>::random(&mut *rng));
+      assert_eq!(FieldElement::from(a.to_gf()), a);
+      assert_eq!((a + b).to_gf(), a.to_gf() + b.to_gf());
+      assert_eq!((a * b).to_gf(), a.to_gf() * b.to_gf());
+      assert_eq!(a - b, a + b);
+      assert_eq!(-a, a);
+      assert_eq!(a.double(), FieldElement::ZERO);
+      assert_eq!(a.square(), a * a);
+      assert_eq!([a, b].iter().sum::<FieldElement<T>>(), a + b);
+      if bool::from(b.is_zero())
+      {
+        continue;
+      }
+      assert_eq!(b * b.invert().unwrap(), FieldElement::ONE);
+      let (is_square, root) = FieldElement::sqrt_ratio(&a, &b);
+      assert!(bool::from(is_square));
+      assert_eq!(root.square() * b, a);
+      assert_eq!(a.sqrt().unwrap().square(), a);
+    }
+  }
+
+  #[cfg(feature = "ff")]
+  #[test]
+  fn ff_field_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_ff_field::<GF5>(&mut rng);
+    check_ff_field::<GF163>(&mut rng);
+    check_ff_field::<GF257>(&mut rng);
+    check_ff_field::<GF431>(&mut rng);
+    check_ff_field::<GF571>(&mut rng);
+  }
 }
@@ -1,10 +1,16 @@
 use std::fmt;
 use std::fmt::Formatter;
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{Signed, Zero};
 use rand_core::CryptoRngCore;
 use poly_algebra::gf::{GFArithmetic};
-use crate::binary_ec::BinaryEC;
-use crate::helpers::{generate_random_affine_point, pack_affine_point, unpack_affine_point};
+use crate::binary_ec::{ACoefficient, BinaryEC};
+use crate::error::Result;
+use crate::helpers::{
+  generate_random_affine_point, mul_affine_point, pack_affine_point, point_from_sec1, point_to_sec1, try_unpack_affine_point,
+  unpack_affine_point,
+};
+use crate::koblitz::{get_mu, partial_reduction, tnaf, wtnaf, wtnaf_representatives};
 
 /// Enum represents affine point in the EC, where $x,y \in GF(2^m)$.
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -92,10 +98,12 @@ impl<'a, T : GFArithmetic<'a>> AffinePoint<T>
   }
 
   /// Function performs doubling of point in affine coordinates.
+  /// Point $(0, \sqrt{B})$ has order 2, so its doubling gives $O_e$.
   pub fn double(&self, ec : &BinaryEC<T>) -> Self
   {
     match self
     {
+      AffinePoint::Point { x: x_p, .. } if x_p.is_zero() => AffinePoint::Infinity,
       AffinePoint::Point { x: x_p, y: y_p } =>
       {
         let x_p_squared = x_p.clone().square();
@@ -114,36 +122,202 @@ impl<'a, T : GFArithmetic<'a>> AffinePoint<T>
   }
 
   /// Function performs multiplication on number in affine coordinates.
+  /// Number is reduced by the order of the EC group $h \cdot n$, so multiplication is correct
+  /// for every point on the EC, not only for the points from the subgroup of big prime order.
   pub fn mul<N : Into<BigUint>>(&self, ec : &BinaryEC<T>, n : N) -> Self
   {
+    let n = n.into() % ec.get_curve_ord();
+    mul_affine_point(self, ec, &n)
+  }
+
+  /// Function performs halving of point in affine coordinates, i.e. finds such $P$ that $2P = Q$,
+  /// where $Q$ is `self`. Halving is calculated with usage of half trace, square root and one
+  /// multiplication instead of doubling (Knudsen, Schroeppel).
+  /// _Point has to be of odd order and EC has to have cofactor 2 ($A = 1$) or 4 ($A = 0$),_
+  /// _that is true for all EC's from the standard, then returned point also has odd order._
+  pub fn halve(&self, ec : &BinaryEC<T>) -> Self
+  {
+    match self
+    {
+      AffinePoint::Point { x: u, y: v } =>
+      {
+        // $\hat\lambda^2 + \hat\lambda = u + A$
+        let lambda = T::from_poly((u.clone() + ec.a.as_field_el()).htrace());
+        let t = v.clone() + u.clone() * lambda.clone();
+        let (x_p, lambda_p) = if t.trace().is_zero()
+        {
+          ((t + u.clone()).sqrt(), lambda)
+        }
+        else
+        {
+          (t.sqrt(), lambda + T::one())
+        };
+        let y_p = x_p.clone() * lambda_p + x_p.square();
+        let p = AffinePoint::Point { x : x_p, y : y_p };
+        match ec.a
+        {
+          ACoefficient::One(_) => p,
+          // With cofactor 4 both $P$ and $P + (0, \sqrt{B})$ are halves of $Q$,
+          // so we choose the one that belongs to $4E$, i.e. has odd order.
+          ACoefficient::Zero(_) =>
+          {
+            if is_quadruple(&p)
+            {
+              p
+            }
+            else
+            {
+              p.add(
+                ec,
+                &AffinePoint::Point {
+                  x : T::zero(),
+                  y : ec.b.sqrt(),
+                },
+              )
+            }
+          }
+        }
+      }
+      AffinePoint::Infinity => AffinePoint::Infinity,
+    }
+  }
+
+  /// Function performs multiplication on number with usage of _halve-and-add_ method.
+  /// Scalar is recoded as $k' = k \cdot 2^{t} \mod n$, where $t = L(n)$, so that
+  /// $k \equiv \sum_{i=0}^{t-1} k'_i \cdot (2^{-1})^{t - i} \mod n$.
+  /// _Point has to be of odd order, the same restrictions as in [AffinePoint::halve] are applied._
+  pub fn mul_halve_and_add<N : Into<BigUint>>(&self, ec : &BinaryEC<T>, n : N) -> Self
+  {
+    let ord = ec.get_ref_ord();
+    let t = ord.bits();
+    let k = ((n.into() % ord) << t) % ord;
     let mut r = AffinePoint::neutral();
-    let mut tmp = self.clone();
-    let n = n.into() % ec.get_ref_ord();
     // from LSB to MSB
-    for x in n.to_str_radix(2).into_bytes().iter().rev()
+    for i in 0 .. t
     {
-      if *x - b'0' == 1
+      if k.bit(i)
       {
-        r = r.add(ec, &tmp)
+        r = r.add(ec, self)
       }
-      tmp = tmp.double(ec)
+      r = r.halve(ec)
     }
     r
   }
 
+  /// Function applies Frobenius endomorphism to the point: $\tau(x, y) = (x^2, y^2)$.
+  pub fn frobenius(&self) -> Self
+  {
+    match self
+    {
+      AffinePoint::Point { x, y } => AffinePoint::Point {
+        x : x.square(),
+        y : y.square(),
+      },
+      AffinePoint::Infinity => AffinePoint::Infinity,
+    }
+  }
+
+  /// Function performs multiplication on number with usage of TNAF (algorithm `3.66`
+  /// from the «Guide to Elliptic Curve Cryptography»), where doublings are replaced
+  /// with Frobenius map that requires only squarings.
+  /// Number is partially reduced modulo $(\tau^m - 1)/(\tau - 1)$ beforehand.
+  /// Returns `None` when EC isn't Koblitz curve, i.e. $B \ne 1$.
+  /// _Point has to belong to the main subgroup of prime order._
+  pub fn mul_tnaf<N : Into<BigUint>>(&self, ec : &BinaryEC<T>, n : N) -> Option<Self>
+  {
+    if !ec.is_koblitz()
+    {
+      return None;
+    }
+    let mu = get_mu(&ec.a);
+    let rho = partial_reduction(&(n.into() % ec.get_ref_ord()), mu, T::get_m());
+    let negative = self.negative();
+    let mut r = AffinePoint::neutral();
+    for u in tnaf(&rho, mu).iter().rev()
+    {
+      r = r.frobenius();
+      match u
+      {
+        1 => r = r.add(ec, self),
+        -1 => r = r.add(ec, &negative),
+        _ => (),
+      }
+    }
+    Some(r)
+  }
+
+  /// Function performs multiplication on number with usage of width-w TNAF (algorithm `3.70`
+  /// from the «Guide to Elliptic Curve Cryptography»), points $\alpha_u P$ are precomputed.
+  /// Returns `None` when EC isn't Koblitz curve, i.e. $B \ne 1$, or width isn't in range $\[2, 8\]$.
+  /// _Point has to belong to the main subgroup of prime order._
+  pub fn mul_wtnaf<N : Into<BigUint>>(&self, ec : &BinaryEC<T>, n : N, w : u8) -> Option<Self>
+  {
+    if !ec.is_koblitz()
+    {
+      return None;
+    }
+    let mu = get_mu(&ec.a);
+    let rho = partial_reduction(&(n.into() % ec.get_ref_ord()), mu, T::get_m());
+    let digits = wtnaf(&rho, mu, w)?;
+    // $P_u = \alpha_u P = \beta_u P + \gamma_u \tau(P)$
+    let frobenius = self.frobenius();
+    let precomputed = wtnaf_representatives(mu, w)
+      .iter()
+      .map(|alpha| self.mul_signed(ec, &alpha.r0).add(ec, &frobenius.mul_signed(ec, &alpha.r1)))
+      .collect::<Vec<_>>();
+    let mut r = AffinePoint::neutral();
+    for u in digits.iter().rev()
+    {
+      r = r.frobenius();
+      if *u != 0
+      {
+        let p = &precomputed[(u.unsigned_abs() as usize - 1) / 2];
+        r = if *u > 0 { r.add(ec, p) } else { r.add(ec, &p.negative()) };
+      }
+    }
+    Some(r)
+  }
+
+  /// Function multiplies point on signed number.
+  fn mul_signed(&self, ec : &BinaryEC<T>, n : &BigInt) -> Self
+  {
+    let p = self.mul(ec, n.magnitude().clone());
+    if n.is_negative()
+    {
+      p.negative()
+    }
+    else
+    {
+      p
+    }
+  }
+
   /// Function performs _packing_ of point that has odd prime order in EC over GF(2^m)
   /// according to the algorithm `6.10`. So, for example, you can use this algorithm with
   /// base point or with another point that has the same order as in `EC.get_ord()`.
-  /// _By now this function don't return an error(not to conforming prime order condition)_
-  ///_but it can lead to incorrect unpacking behaviour._
+  /// _This function doesn't check the result (prime order condition, belonging to the EC)_,
+  /// _use [AffinePoint::try_unpack] for the points received from untrusted sources._
   /// __To sum up unpacking is suitable to use only for public keys.__
   pub fn unpack(num : &T, ec : &BinaryEC<T>) -> Self { unpack_affine_point(num, ec) }
 
+  /// Function performs _unpacking_ of point according to the algorithm `6.10` with validation of the result,
+  /// so it's suitable for the points received from untrusted sources.
+  /// Related to function [try_unpack_affine_point].
+  pub fn try_unpack(num : &T, ec : &BinaryEC<T>) -> Result<Self> { try_unpack_affine_point(num, ec) }
+
   /// Function performs _unpacking_ of point that has odd prime order in EC over GF(2^m)
   /// according to the algorithm `6.9`.
   /// _With usage of incorrect point in packing stage, it's impossible to restore previous point._
   /// __To sum up packing is suitable to use only for public keys.__
   pub fn pack(&self) -> T { pack_affine_point(self) }
+
+  /// Function encodes point according to the SEC1 in compressed or uncompressed form.
+  /// Related to function [point_to_sec1].
+  pub fn to_sec1(&self, compressed : bool) -> Vec<u8> { point_to_sec1(self, compressed) }
+
+  /// Function decodes point from the SEC1 encoding and checks that it belongs to the EC.
+  /// Related to function [point_from_sec1].
+  pub fn from_sec1(ec : &BinaryEC<T>, bytes : &[u8]) -> Result<Self> { point_from_sec1(bytes, ec) }
 }
 impl<'a, T : GFArithmetic<'a>> fmt::Debug for AffinePoint<T>
 {
@@ -205,6 +379,21 @@ impl<'a, T : GFArithmetic<'a>> fmt::Binary for AffinePoint<T>
   }
 }
 
+/// Function checks whether point $P = (x, y)$ from $2E$ belongs to $4E$ on the EC with $A = 0$,
+/// i.e. whether $Tr(y + x\lambda) = 0$, where $\lambda^2 + \lambda = x$.
+fn is_quadruple<'a, T : GFArithmetic<'a>>(p : &AffinePoint<T>) -> bool
+{
+  match p
+  {
+    AffinePoint::Point { x, y } =>
+    {
+      let lambda = T::from_poly(x.htrace());
+      (y.clone() + x.clone() * lambda).trace().is_zero()
+    }
+    AffinePoint::Infinity => true,
+  }
+}
+
 fn write_into_formatter(point : Option<(String, String)>, f : &mut Formatter<'_>) -> fmt::Result
 {
   match point
@@ -0,0 +1,128 @@
+//! Precomputed multiples of the base point for the fixed-base multiplication $kP$ by public numbers,
+//! e.g. $sP$ in verification of signatures, which is calculated for every signature with the same $P$.
+//!
+//! Table is built once per EC and shared between clones of the keys with help of [BasePointTableCache].
+use std::fmt;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, OnceLock};
+use num_bigint::BigUint;
+use poly_algebra::gf::GFArithmetic;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::BinaryEC;
+use crate::helpers::batch_normalize_projective_points;
+use crate::projective_point::ProjectivePoint;
+
+/// Width $w$ of the window in bits, table keeps $2^w - 1$ points per window.
+pub const BASE_POINT_TABLE_WINDOW : u32 = 4;
+
+/// Struct keeps multiples $j \cdot 2^{wi} P$ for $j \in \[1, 2^w)$ and all windows $i$ of numbers less than $n$,
+/// so $kP$ is calculated with $\lceil L(n)/w \rceil$ mixed additions and one inversion, without doublings.
+/// _Points are looked up by the digits of $k$, so table has to be used only for public numbers._
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct BasePointTable<T>
+{
+  bp : AffinePoint<T>,
+  windows : Vec<Vec<AffinePoint<T>>>,
+}
+
+impl<'a, T : GFArithmetic<'a>> BasePointTable<T>
+{
+  /// Function builds table for the base point of the EC, points are accumulated in the López–Dahab coordinates
+  /// and converted into the affine ones at once, only the first point of every window is normalized separately.
+  pub fn new(ec : &BinaryEC<T>) -> Self
+  {
+    let windows_num = ec.get_ref_ord().bits().div_ceil(BASE_POINT_TABLE_WINDOW as u64);
+    let mut windows = Vec::with_capacity(windows_num as usize);
+    let mut window_base = ec.get_bp();
+    for _ in 0 .. windows_num
+    {
+      let mut multiples = Vec::with_capacity((1 << BASE_POINT_TABLE_WINDOW) - 1);
+      let mut acc = ProjectivePoint::from(window_base.clone());
+      for _ in 1 .. 1 << BASE_POINT_TABLE_WINDOW
+      {
+        multiples.push(acc.clone());
+        acc = acc.add_affine(ec, &window_base);
+      }
+      // $2^w \cdot 2^{wi} P$ is the base of the next window
+      window_base = acc.to_affine();
+      windows.push(multiples);
+    }
+    let points = batch_normalize_projective_points(&windows.concat());
+    BasePointTable {
+      bp : ec.get_bp(),
+      windows : points.chunks((1 << BASE_POINT_TABLE_WINDOW) - 1).map(<[_]>::to_vec).collect(),
+    }
+  }
+
+  /// Function calculates $kP$ for the base point $P$ of the EC, which table is built for.
+  /// Number is reduced modulo $n$ beforehand.
+  pub fn mul<N : Into<BigUint>>(&self, ec : &BinaryEC<T>, k : N) -> AffinePoint<T>
+  {
+    debug_assert!(self.bp == *ec.get_ref_bp(), "table has to be built for the base point of the EC");
+    let k = k.into() % ec.get_ref_ord();
+    let digits = k.to_bytes_le().into_iter().flat_map(|byte| [byte & 0x0f, byte >> 4]);
+    let mut acc = ProjectivePoint::neutral();
+    for (window, digit) in self.windows.iter().zip(digits)
+    {
+      if digit != 0
+      {
+        acc = acc.add_affine(ec, &window[digit as usize - 1]);
+      }
+    }
+    acc.to_affine()
+  }
+
+  /// Function gets base point, which table is built for.
+  pub fn get_ref_bp(&self) -> &AffinePoint<T> { &self.bp }
+}
+
+/// Lazily built [BasePointTable], that is shared between clones with help of [Arc],
+/// so the table is built once on the first use and then reused by every clone of the key that owns the cache.
+/// Table is determined by the EC, so cache doesn't take part in comparison and hashing of the keys.
+pub struct BasePointTableCache<T>(Arc<OnceLock<BasePointTable<T>>>);
+
+impl<'a, T : GFArithmetic<'a>> BasePointTableCache<T>
+{
+  /// Function creates empty cache.
+  pub fn new() -> Self { BasePointTableCache(Arc::new(OnceLock::new())) }
+
+  /// Function gets table for the EC, building it on the first call.
+  /// _All calls have to pass the same EC._
+  pub fn get_or_init(&self, ec : &BinaryEC<T>) -> &BasePointTable<T> { self.0.get_or_init(|| BasePointTable::new(ec)) }
+
+  /// Function checks whether table is already built.
+  pub fn is_initialized(&self) -> bool { self.0.get().is_some() }
+}
+
+impl<'a, T : GFArithmetic<'a>> Default for BasePointTableCache<T>
+{
+  fn default() -> Self { Self::new() }
+}
+
+impl<T> Clone for BasePointTableCache<T>
+{
+  fn clone(&self) -> Self { BasePointTableCache(Arc::clone(&self.0)) }
+}
+
+impl<T> PartialEq for BasePointTableCache<T>
+{
+  fn eq(&self, _ : &Self) -> bool { true }
+}
+
+impl<T> Eq for BasePointTableCache<T> {}
+
+impl<T> Hash for BasePointTableCache<T>
+{
+  fn hash<H : Hasher>(&self, _ : &mut H) {}
+}
+
+impl<T> fmt::Debug for BasePointTableCache<T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result
+  {
+    f.debug_struct("BasePointTableCache")
+      .field("initialized", &self.0.get().is_some())
+      .finish()
+  }
+}
@@ -1,10 +1,32 @@
+use std::fmt;
+use std::fmt::Formatter;
 use std::marker::PhantomData;
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use num_traits::{Num, One, Zero};
-use rand_core::CryptoRngCore;
-use poly_algebra::gf::{GFArithmetic, GF163, GF167, GF173, GF179, GF191, GF233, GF257, GF307, GF367, GF431};
+use digest::core_api::BlockSizeUser;
+use digest::Digest;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{CryptoRngCore, RngCore, SeedableRng};
+use poly_algebra::helpers::generate_num;
+use poly_algebra::gf::{
+  GFArithmetic, GF163, GF167, GF173, GF179, GF191, GF233, GF233Nist, GF257, GF283, GF307, GF367, GF409, GF431, GF571,
+};
 use crate::affine_point::AffinePoint;
-use crate::helpers::{generate_random_affine_point, pack_affine_point, unpack_affine_point};
+use crate::binary_edwards::BinaryEdwardsCurve;
+use crate::countermeasures::{mul_secret, Countermeasures};
+use crate::curve_point::CurvePoint;
+use crate::ecdlp::discrete_log;
+use crate::error::{EcError, Result};
+use crate::hash_to_curve::hash_to_curve;
+use crate::point_counting::count_points;
+use crate::projective_point::ProjectivePoint;
+use crate::toy_curves::{enumerate_points, group_structure};
+use crate::twist::{check_x_coordinate, mul_x_coordinate, twist, twist_order, twist_security, TwistSecurity};
+use crate::helpers::{
+  batch_add_affine_points, batch_normalize_projective_points, decompress_affine_point, find_embedding_degree,
+  generate_random_affine_point, is_probably_prime, mul_affine_point, pack_affine_point, try_unpack_affine_point,
+  unpack_affine_point,
+};
 
 
 /// Koblitz elliptic curve over binary field that is represented by equation:
@@ -18,6 +40,7 @@ pub struct BinaryEC<T>
   pub(crate) b : T,
   pub(crate) bp : AffinePoint<T>,
   pub(crate) n : BigUint,
+  pub(crate) h : BigUint,
 }
 
 /// Represents `A` coefficient in the EC equation, exactly 0 or 1 values.
@@ -54,6 +77,7 @@ impl BinaryEC<GF163>
         y : GF163::from(BigUint::from_str_radix("3826F008A8C51D7B95284D9D03FF0E00CE2CD723A", 16).unwrap()),
       },
       n : BigUint::from_str_radix("400000000000000000002BEC12BE2262D39BCF14D", 16).unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 
@@ -68,6 +92,38 @@ impl BinaryEC<GF163>
         y : GF163::from(BigUint::from_str_radix("0224A9C3947852B97C5599D5F4AB81122ADC3FD9B", 16).unwrap()),
       },
       n : BigUint::from_str_radix("400000000000000000002BEC12BE2262D39BCF14D", 16).unwrap(),
+      h : BigUint::from(2_u8),
+    }
+  }
+
+  /// Generates Koblitz EC `K-163` over [GF163] field from the FIPS 186 standard,
+  /// which shares the same prime polynomial.
+  pub fn generate_k163_curve() -> BinaryEC<GF163>
+  {
+    BinaryEC::<GF163> {
+      a : ACoefficient::One(PhantomData::<GF163>),
+      b : GF163::one(),
+      bp : AffinePoint::Point {
+        x : GF163::from(BigUint::from_str_radix("2FE13C0537BBC11ACAA07D793DE4E6D5E5C94EEE8", 16).unwrap()),
+        y : GF163::from(BigUint::from_str_radix("289070FB05D38FF58321F2E800536D538CCDAA3D9", 16).unwrap()),
+      },
+      n : BigUint::from_str_radix("4000000000000000000020108A2E0CC0D99F8A5EF", 16).unwrap(),
+      h : BigUint::from(2_u8),
+    }
+  }
+
+  /// Generates pseudo-random EC `B-163` over [GF163] field from the FIPS 186 standard.
+  pub fn generate_b163_curve() -> BinaryEC<GF163>
+  {
+    BinaryEC::<GF163> {
+      a : ACoefficient::One(PhantomData::<GF163>),
+      b : GF163::from(BigUint::from_str_radix("20A601907B8C953CA1481EB10512F78744A3205FD", 16).unwrap()),
+      bp : AffinePoint::Point {
+        x : GF163::from(BigUint::from_str_radix("3F0EBA16286A2D57EA0991168D4994637E8343E36", 16).unwrap()),
+        y : GF163::from(BigUint::from_str_radix("D51FBC6C71A0094FA2CDD545B11C5C0C797324F1", 16).unwrap()),
+      },
+      n : BigUint::from_str_radix("40000000000000000000292FE77E70C12A4234C33", 16).unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
@@ -85,6 +141,7 @@ impl BinaryEC<GF167>
         y : GF167::from(BigUint::from_str_radix("5F49EB26781C0EC6B8909156D98ED435E45FD59918", 16).unwrap()),
       },
       n : BigUint::from_str_radix("3FFFFFFFFFFFFFFFFFFFFFB12EBCC7D7F29FF7701F", 16).unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
@@ -101,6 +158,7 @@ impl BinaryEC<GF173>
         y : GF173::from(BigUint::from_str_radix("10B51CC12849B234C75E6DD2028BF7FF5C1CE0D991A1", 16).unwrap()),
       },
       n : BigUint::from_str_radix("800000000000000000000189B4E67606E3825BB2831", 16).unwrap(),
+      h : BigUint::from(4_u8),
     }
   }
 }
@@ -117,6 +175,7 @@ impl BinaryEC<GF179>
         y : GF179::from(BigUint::from_str_radix("25FBC363582DCEC065080CA8287AAFF09788A66DC3A9E", 16).unwrap()),
       },
       n : BigUint::from_str_radix("3FFFFFFFFFFFFFFFFFFFFFFB981960435FE5AB64236EF", 16).unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
@@ -133,6 +192,7 @@ impl BinaryEC<GF191>
         y : GF191::from(BigUint::from_str_radix("29C41E568B77C617EFE5902F11DB96FA9613CD8D03DB08DA", 16).unwrap()),
       },
       n : BigUint::from_str_radix("40000000000000000000000069A779CAC1DABC6788F7474F", 16).unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
@@ -149,6 +209,7 @@ impl BinaryEC<GF233>
         y : GF233::from(BigUint::from_str_radix("9CA8B57A934C54DEEDA9E54A7BBAD95E3B2E91C54D32BE0B9DF96D8D35", 16).unwrap()),
       },
       n : BigUint::from_str_radix("1000000000000000000000000000013E974E72F8A6922031D2603CFE0D7", 16).unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
@@ -169,6 +230,7 @@ impl BinaryEC<GF257>
         ),
       },
       n : BigUint::from_str_radix("800000000000000000000000000000006759213AF182E987D3E17714907D470D", 16).unwrap(),
+      h : BigUint::from(4_u8),
     }
   }
 }
@@ -207,6 +269,7 @@ impl BinaryEC<GF307>
         16,
       )
       .unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
@@ -245,6 +308,7 @@ impl BinaryEC<GF367>
         16,
       )
       .unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
@@ -283,12 +347,418 @@ impl BinaryEC<GF431>
         16,
       )
       .unwrap(),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+impl BinaryEC<GF233Nist>
+{
+  /// Generates Koblitz EC `K-233` over [GF233Nist] field from the FIPS 186 standard.
+  pub fn generate_k233_curve() -> BinaryEC<GF233Nist>
+  {
+    BinaryEC::<GF233Nist> {
+      a : ACoefficient::Zero(PhantomData::<GF233Nist>),
+      b : GF233Nist::one(),
+      bp : AffinePoint::Point {
+        x : GF233Nist::from(BigUint::from_str_radix("17232BA853A7E731AF129F22FF4149563A419C26BF50A4C9D6EEFAD6126", 16).unwrap()),
+        y : GF233Nist::from(BigUint::from_str_radix("1DB537DECE819B7F70F555A67C427A8CD9BF18AEB9B56E0C11056FAE6A3", 16).unwrap()),
+      },
+      n : BigUint::from_str_radix("8000000000000000000000000000069D5BB915BCD46EFB1AD5F173ABDF", 16).unwrap(),
+      h : BigUint::from(4_u8),
+    }
+  }
+
+  /// Generates pseudo-random EC `B-233` over [GF233Nist] field from the FIPS 186 standard.
+  pub fn generate_b233_curve() -> BinaryEC<GF233Nist>
+  {
+    BinaryEC::<GF233Nist> {
+      a : ACoefficient::One(PhantomData::<GF233Nist>),
+      b : GF233Nist::from(BigUint::from_str_radix("66647EDE6C332C7F8C0923BB58213B333B20E9CE4281FE115F7D8F90AD", 16).unwrap()),
+      bp : AffinePoint::Point {
+        x : GF233Nist::from(BigUint::from_str_radix("FAC9DFCBAC8313BB2139F1BB755FEF65BC391F8B36F8F8EB7371FD558B", 16).unwrap()),
+        y : GF233Nist::from(BigUint::from_str_radix("1006A08A41903350678E58528BEBF8A0BEFF867A7CA36716F7E01F81052", 16).unwrap()),
+      },
+      n : BigUint::from_str_radix("1000000000000000000000000000013E974E72F8A6922031D2603CFE0D7", 16).unwrap(),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+impl BinaryEC<GF283>
+{
+  /// Generates Koblitz EC `K-283` over [GF283] field from the FIPS 186 standard.
+  pub fn generate_k283_curve() -> BinaryEC<GF283>
+  {
+    BinaryEC::<GF283> {
+      a : ACoefficient::Zero(PhantomData::<GF283>),
+      b : GF283::one(),
+      bp : AffinePoint::Point {
+        x : GF283::from(
+          BigUint::from_str_radix("503213F78CA44883F1A3B8162F188E553CD265F23C1567A16876913B0C2AC2458492836", 16).unwrap(),
+        ),
+        y : GF283::from(
+          BigUint::from_str_radix("1CCDA380F1C9E318D90F95D07E5426FE87E45C0E8184698E45962364E34116177DD2259", 16).unwrap(),
+        ),
+      },
+      n : BigUint::from_str_radix("1FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE9AE2ED07577265DFF7F94451E061E163C61", 16).unwrap(),
+      h : BigUint::from(4_u8),
+    }
+  }
+
+  /// Generates pseudo-random EC `B-283` over [GF283] field from the FIPS 186 standard.
+  pub fn generate_b283_curve() -> BinaryEC<GF283>
+  {
+    BinaryEC::<GF283> {
+      a : ACoefficient::One(PhantomData::<GF283>),
+      b : GF283::from(
+        BigUint::from_str_radix("27B680AC8B8596DA5A4AF8A19A0303FCA97FD7645309FA2A581485AF6263E313B79A2F5", 16).unwrap(),
+      ),
+      bp : AffinePoint::Point {
+        x : GF283::from(
+          BigUint::from_str_radix("5F939258DB7DD90E1934F8C70B0DFEC2EED25B8557EAC9C80E2E198F8CDBECD86B12053", 16).unwrap(),
+        ),
+        y : GF283::from(
+          BigUint::from_str_radix("3676854FE24141CB98FE6D4B20D02B4516FF702350EDDB0826779C813F0DF45BE8112F4", 16).unwrap(),
+        ),
+      },
+      n : BigUint::from_str_radix("3FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEF90399660FC938A90165B042A7CEFADB307", 16).unwrap(),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+impl BinaryEC<GF409>
+{
+  /// Generates Koblitz EC `K-409` over [GF409] field from the FIPS 186 standard.
+  pub fn generate_k409_curve() -> BinaryEC<GF409>
+  {
+    BinaryEC::<GF409> {
+      a : ACoefficient::Zero(PhantomData::<GF409>),
+      b : GF409::one(),
+      bp : AffinePoint::Point {
+        x : GF409::from(
+          BigUint::from_str_radix(
+            "60F05F658F49C1AD3AB1890F7184210EFD0987E307C84C27ACCFB8F9F67CC2C460189EB5AAAA62EE222EB1B35540CFE9023746",
+            16,
+          )
+          .unwrap(),
+        ),
+        y : GF409::from(
+          BigUint::from_str_radix(
+            "1E369050B7C4E42ACBA1DACBF04299C3460782F918EA427E6325165E9EA10E3DA5F6C42E9C55215AA9CA27A5863EC48D8E0286B",
+            16,
+          )
+          .unwrap(),
+        ),
+      },
+      n : BigUint::from_str_radix(
+        "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE5F83B2D4EA20400EC4557D5ED3E3E7CA5B4B5C83B8E01E5FCF",
+        16,
+      )
+      .unwrap(),
+      h : BigUint::from(4_u8),
+    }
+  }
+
+  /// Generates pseudo-random EC `B-409` over [GF409] field from the FIPS 186 standard.
+  pub fn generate_b409_curve() -> BinaryEC<GF409>
+  {
+    BinaryEC::<GF409> {
+      a : ACoefficient::One(PhantomData::<GF409>),
+      b : GF409::from(
+        BigUint::from_str_radix(
+          "21A5C2C8EE9FEB5C4B9A753B7B476B7FD6422EF1F3DD674761FA99D6AC27C8A9A197B272822F6CD57A55AA4F50AE317B13545F",
+          16,
+        )
+        .unwrap(),
+      ),
+      bp : AffinePoint::Point {
+        x : GF409::from(
+          BigUint::from_str_radix(
+            "15D4860D088DDB3496B0C6064756260441CDE4AF1771D4DB01FFE5B34E59703DC255A868A1180515603AEAB60794E54BB7996A7",
+            16,
+          )
+          .unwrap(),
+        ),
+        y : GF409::from(
+          BigUint::from_str_radix(
+            "61B1CFAB6BE5F32BBFA78324ED106A7636B9C5A7BD198D0158AA4F5488D08F38514F1FDF4B4F40D2181B3681C364BA0273C706",
+            16,
+          )
+          .unwrap(),
+        ),
+      },
+      n : BigUint::from_str_radix(
+        "10000000000000000000000000000000000000000000000000001E2AAD6A612F33307BE5FA47C3C9E052F838164CD37D9A21173",
+        16,
+      )
+      .unwrap(),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+impl BinaryEC<GF571>
+{
+  /// Generates Koblitz EC `K-571` over [GF571] field from the FIPS 186 standard.
+  pub fn generate_k571_curve() -> BinaryEC<GF571>
+  {
+    BinaryEC::<GF571> {
+      a : ACoefficient::Zero(PhantomData::<GF571>),
+      b : GF571::one(),
+      bp : AffinePoint::Point {
+        x : GF571::from(
+          BigUint::from_str_radix(
+            "26EB7A859923FBC82189631F8103FE4AC9CA2970012D5D46024804801841CA44370958493B205E647DA304DB4CEB08CBBD1BA39494776FB988B47174DCA88C7E2945283A01C8972",
+            16,
+          )
+          .unwrap(),
+        ),
+        y : GF571::from(
+          BigUint::from_str_radix(
+            "349DC807F4FBF374F4AEADE3BCA95314DD58CEC9F307A54FFC61EFC006D8A2C9D4979C0AC44AEA74FBEBBB9F772AEDCB620B01A7BA7AF1B320430C8591984F601CD4C143EF1C7A3",
+            16,
+          )
+          .unwrap(),
+        ),
+      },
+      n : BigUint::from_str_radix(
+        "20000000000000000000000000000000000000000000000000000000000000000000000131850E1F19A63E4B391A8DB917F4138B630D84BE5D639381E91DEB45CFE778F637C1001",
+        16,
+      )
+      .unwrap(),
+      h : BigUint::from(4_u8),
+    }
+  }
+
+  /// Generates pseudo-random EC `B-571` over [GF571] field from the FIPS 186 standard.
+  pub fn generate_b571_curve() -> BinaryEC<GF571>
+  {
+    BinaryEC::<GF571> {
+      a : ACoefficient::One(PhantomData::<GF571>),
+      b : GF571::from(
+        BigUint::from_str_radix(
+          "2F40E7E2221F295DE297117B7F3D62F5C6A97FFCB8CEFF1CD6BA8CE4A9A18AD84FFABBD8EFA59332BE7AD6756A66E294AFD185A78FF12AA520E4DE739BACA0C7FFEFF7F2955727A",
+          16,
+        )
+        .unwrap(),
+      ),
+      bp : AffinePoint::Point {
+        x : GF571::from(
+          BigUint::from_str_radix(
+            "303001D34B856296C16C0D40D3CD7750A93D1D2955FA80AA5F40FC8DB7B2ABDBDE53950F4C0D293CDD711A35B67FB1499AE60038614F1394ABFA3B4C850D927E1E7769C8EEC2D19",
+            16,
+          )
+          .unwrap(),
+        ),
+        y : GF571::from(
+          BigUint::from_str_radix(
+            "37BF27342DA639B6DCCFFFEB73D69D78C6C27A6009CBBCA1980F8533921E8A684423E43BAB08A576291AF8F461BB2A8B3531D2F0485C19B16E2F1516E23DD3C1A4827AF1B8AC15B",
+            16,
+          )
+          .unwrap(),
+        ),
+      },
+      n : BigUint::from_str_radix(
+        "3FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE661CE18FF55987308059B186823851EC7DD9CA1161DE93D5174D66E8382E9BB2FE84E47",
+        16,
+      )
+      .unwrap(),
+      h : BigUint::from(2_u8),
     }
   }
 }
 
+impl<'a, T : GFArithmetic<'a>> fmt::Debug for BinaryEC<T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result
+  {
+    f.debug_struct("BinaryEC")
+      .field("a", &self.a.as_biguint())
+      .field("b", &self.b)
+      .field("bp", &self.bp)
+      .field("n", &self.n)
+      .field("h", &self.h)
+      .finish()
+  }
+}
+
+/// Bound for the embedding degree $k$ that is checked in the MOV condition: $2^{mk} \not\equiv 1 \mod n$.
+pub const MOV_DEGREE_BOUND : u32 = 32;
+
+/// Length of the seed in bytes, which random EC is generated from.
+pub const CURVE_SEED_LEN : usize = 32;
+
+/// Number of random pairs $(A, B)$, which are tried in [BinaryEC::generate_from_seed] before giving up.
+pub const CURVE_GENERATION_ATTEMPTS : u32 = 1 << 12;
+
 impl<'a, T : GFArithmetic<'a>> BinaryEC<T>
 {
+  /// Function creates EC from custom parameters and validates them:
+  /// * $B \ne 0$;
+  /// * base point $P$ belongs to the EC and $P \ne O$;
+  /// * $n$ is probably prime (Miller–Rabin test);
+  /// * number of points $h \cdot n$ lies within the Hasse bound $|2^m + 1 - hn| \le 2\sqrt{2^m}$;
+  /// * EC isn't anomalous: $hn \ne 2^m$;
+  /// * MOV condition: $2^{mk} \not\equiv 1 \mod n$ for $k \in \[1, $ [MOV_DEGREE_BOUND] $\]$;
+  /// * $nP = O$.
+  pub fn new(a : ACoefficient<T>, b : T, bp : AffinePoint<T>, n : BigUint, cofactor : BigUint) -> Result<Self>
+  {
+    if b.is_zero()
+    {
+      return Err(EcError::ZeroBCoefficient);
+    }
+    let ec = BinaryEC {
+      a,
+      b,
+      bp,
+      n,
+      h : cofactor,
+    };
+    if ec.bp.is_inf()
+    {
+      return Err(EcError::BasePointIsInfinity);
+    }
+    if !ec.check_affine_point(&ec.bp)
+    {
+      return Err(EcError::BasePointNotOnCurve);
+    }
+    if !is_probably_prime(&ec.n)
+    {
+      return Err(EcError::CompositeOrder(ec.n.to_str_radix(16)));
+    }
+    let m = T::get_m();
+    let q = BigInt::one() << m;
+    let points_num = BigInt::from(ec.get_curve_ord());
+    // $t^2 \le 4q$, where $t = q + 1 - \#E$
+    let t = &q + BigInt::one() - &points_num;
+    if &t * &t > 4 * &q
+    {
+      return Err(EcError::HasseBoundViolated(points_num.to_str_radix(16)));
+    }
+    if points_num == q
+    {
+      return Err(EcError::AnomalousCurve);
+    }
+    if let Some(k) = find_embedding_degree(&ec.n, m, MOV_DEGREE_BOUND)
+    {
+      return Err(EcError::MovConditionViolated(k));
+    }
+    if !mul_affine_point(&ec.bp, &ec, &ec.n).is_inf()
+    {
+      return Err(EcError::WrongBasePointOrder(ec.n.to_str_radix(16)));
+    }
+    Ok(ec)
+  }
+
+  /// Function generates random EC over GF(2^m) according to the procedure from DSTU 4145-2002.
+  /// Degree $m$ and prime polynomial of the field aren't passed as arguments, because they are determined
+  /// by the type `T`, like for the rest of the crate, where the field arithmetic is resolved at compile time.
+  /// Seed is taken from `rng` and returned together with the EC,
+  /// so the same EC can be reproduced with [BinaryEC::generate_from_seed].
+  pub fn generate_random(rng : &mut impl CryptoRngCore) -> Result<(Self, [u8; CURVE_SEED_LEN])>
+  {
+    let mut seed = [0_u8; CURVE_SEED_LEN];
+    rng.fill_bytes(&mut seed);
+    Ok((Self::generate_from_seed(&seed)?, seed))
+  }
+
+  /// Function deterministically generates EC from the seed, that is used to initialize PRNG:
+  /// 1. $A \in \{0, 1\}$ and $B \ne 0$ are chosen randomly;
+  /// 2. number of points $\#E$ is counted with help of [count_points];
+  /// 3. $\#E = hn$ is required, where $n$ is prime and $h \in \{2, 4\}$ is derived from $\#E$;
+  /// 4. base point of order $n$ is chosen and parameters are validated with [BinaryEC::new],
+  ///    which checks MOV condition among others.
+  ///
+  /// Steps are repeated at most [CURVE_GENERATION_ATTEMPTS] times, otherwise
+  /// [EcError::CurveGenerationFailed] is returned, e.g. for tiny fields, where there may be no suitable EC at all.
+  pub fn generate_from_seed(seed : &[u8; CURVE_SEED_LEN]) -> Result<Self>
+  {
+    let mut rng = ChaCha20Rng::from_seed(*seed);
+    for _ in 0 .. CURVE_GENERATION_ATTEMPTS
+    {
+      let a = if rng.next_u32() & 1 == 0
+      {
+        ACoefficient::Zero(PhantomData)
+      }
+      else
+      {
+        ACoefficient::One(PhantomData)
+      };
+      let b = T::from_poly(generate_num(&mut rng, T::get_m()));
+      if b.is_zero()
+      {
+        continue;
+      }
+      let points_num = count_points(&a, &b);
+      let Some(h) = [2_u8, 4]
+        .into_iter()
+        .map(BigUint::from)
+        .find(|h| (&points_num % h).is_zero() && is_probably_prime(&(&points_num / h)))
+      else
+      {
+        continue;
+      };
+      let mut ec = BinaryEC {
+        a,
+        b,
+        bp : AffinePoint::Infinity,
+        n : &points_num / &h,
+        h,
+      };
+      ec.bp = ec.generate_base_point(&mut rng);
+      if let Ok(ec) = BinaryEC::new(ec.a, ec.b, ec.bp, ec.n, ec.h)
+      {
+        return Ok(ec);
+      }
+    }
+    Err(EcError::CurveGenerationFailed(CURVE_GENERATION_ATTEMPTS))
+  }
+
+  /// Function generates base point of the EC, i.e. random point of order $n$:
+  /// random point $R$ is chosen and multiplied on the cofactor, $P = hR$, until $P \ne O$.
+  /// Because $n$ is prime, such $P$ always has exact order $n$.
+  pub fn generate_base_point(&self, rng : &mut impl CryptoRngCore) -> AffinePoint<T>
+  {
+    loop
+    {
+      let point = self.clear_cofactor(&self.generate_random_affine_point(rng));
+      if !point.is_inf()
+      {
+        return point;
+      }
+    }
+  }
+
+  /// Function checks that point satisfies requirements to the base point from DSTU 4145-2002:
+  /// * $P \ne O$;
+  /// * $P$ belongs to the EC;
+  /// * $n$ is prime and $nP = O$, so $P$ has exact order $n$.
+  pub fn verify_base_point(&self, point : &AffinePoint<T>) -> Result<()>
+  {
+    if point.is_inf()
+    {
+      return Err(EcError::BasePointIsInfinity);
+    }
+    if !self.check_affine_point(point)
+    {
+      return Err(EcError::BasePointNotOnCurve);
+    }
+    if !is_probably_prime(&self.n)
+    {
+      return Err(EcError::CompositeOrder(self.n.to_str_radix(16)));
+    }
+    if !mul_affine_point(point, self, &self.n).is_inf()
+    {
+      return Err(EcError::WrongBasePointOrder(self.n.to_str_radix(16)));
+    }
+    Ok(())
+  }
+
+  /// Function hashes message into the point of the prime order subgroup.
+  /// Related to function [hash_to_curve].
+  pub fn hash_to_curve<D : Digest + BlockSizeUser>(&self, msg : &[u8], dst : &[u8]) -> Result<AffinePoint<T>>
+  {
+    hash_to_curve::<T, D>(msg, dst, self)
+  }
+
   /// Function generates random point on the EC.
   pub fn generate_random_affine_point(&self, rng : &mut impl CryptoRngCore) -> AffinePoint<T>
   {
@@ -321,6 +791,61 @@ impl<'a, T : GFArithmetic<'a>> BinaryEC<T>
   /// Function gets reference to the big prime order of base point.
   pub fn get_ref_ord(&self) -> &BigUint { &self.n }
 
+  /// Function gets cofactor $h$ of the EC, so that number of points on the EC is $h \cdot n$.
+  pub fn get_cofactor(&self) -> BigUint { self.h.clone() }
+
+  /// Function gets reference to the cofactor $h$ of the EC.
+  pub fn get_ref_cofactor(&self) -> &BigUint { &self.h }
+
+  /// Function gets order of the EC group, i.e. number of points $h \cdot n$.
+  pub fn get_curve_ord(&self) -> BigUint { &self.h * &self.n }
+
+  /// Function counts number of points on the EC with help of AGM.
+  /// Related to function [count_points].
+  pub fn count_points(&self) -> BigUint { count_points(&self.a, &self.b) }
+
+  /// Function finds birationally equivalent complete binary Edwards curve.
+  /// Related to function [BinaryEdwardsCurve::new].
+  pub fn to_edwards(&self) -> Result<BinaryEdwardsCurve<T>> { BinaryEdwardsCurve::new(self.clone()) }
+
+  /// Function enumerates all points of the EC, feasible only for small fields.
+  /// Related to function [enumerate_points].
+  pub fn enumerate_points(&self) -> Vec<AffinePoint<T>> { enumerate_points(&self.a, &self.b) }
+
+  /// Function finds structure of the group of points $\mathbb{Z}_{n_1} \times \mathbb{Z}_{n_2}$ for small fields.
+  /// Related to function [group_structure].
+  pub fn group_structure(&self) -> (BigUint, BigUint) { group_structure(&self.a, &self.b) }
+
+  /// Function constructs quadratic twist of the EC with flipped coefficient $A$.
+  /// Related to function [twist].
+  pub fn twist(&self) -> Result<BinaryEC<T>> { twist(self) }
+
+  /// Function calculates order of the quadratic twist from the order of the EC.
+  /// Related to function [twist_order].
+  pub fn twist_order(&self) -> BigUint { twist_order(self) }
+
+  /// Function factorizes order of the quadratic twist.
+  /// Related to function [twist_security].
+  pub fn twist_security(&self) -> TwistSecurity { twist_security(self) }
+
+  /// Function checks $x$ coordinate of the point, that is received without $y$, and rejects twist points.
+  /// Related to function [check_x_coordinate].
+  pub fn check_x_coordinate(&self, x : &T) -> Result<()> { check_x_coordinate(self, x) }
+
+  /// Function calculates $x$ coordinate of $kP$ from $x$ coordinate of $P$ with x-only Montgomery ladder.
+  /// Related to function [mul_x_coordinate].
+  pub fn mul_x_coordinate<N : Into<BigUint>>(&self, x : &T, k : N) -> Result<Option<T>> { mul_x_coordinate(self, x, k) }
+
+  /// Function checks whether point belongs to the subgroup of big prime order $n$,
+  /// i.e. point belongs to the EC and $nP = O$ without reduction of the $n$.
+  pub fn is_in_prime_subgroup(&self, point : &AffinePoint<T>) -> bool
+  {
+    (point.is_inf() || self.check_affine_point(point)) && mul_affine_point(point, self, &self.n).is_inf()
+  }
+
+  /// Function maps point into the subgroup of big prime order by multiplication on cofactor: $P \to hP$.
+  pub fn clear_cofactor(&self, point : &AffinePoint<T>) -> AffinePoint<T> { mul_affine_point(point, self, &self.h) }
+
   /// Function checks whether point belongs to the specified curve
   /// by calculating this equation: $y^2 + xy = x^3 + Ax^2 + B$.
   pub fn check_affine_point(&self, point : &AffinePoint<T>) -> bool
@@ -335,11 +860,44 @@ impl<'a, T : GFArithmetic<'a>> BinaryEC<T>
     }
   }
 
+  /// Function creates validated point from coordinates, which have to satisfy the EC equation.
+  pub fn point_from_coordinates(&self, x : T, y : T) -> Result<CurvePoint<'_, T>>
+  {
+    let point = AffinePoint::Point { x, y };
+    if !self.check_affine_point(&point)
+    {
+      return Err(EcError::PointNotOnCurve);
+    }
+    Ok(CurvePoint::new_unchecked(self, point))
+  }
+
+  /// Function creates validated point from the packed one.
+  /// Related to function [BinaryEC::unpack_affine_point].
+  pub fn unpack_point(&self, num : &T) -> Result<CurvePoint<'_, T>>
+  {
+    let point = decompress_affine_point(num, self).ok_or(EcError::NoSquareRoot)?;
+    if !self.check_affine_point(&point)
+    {
+      return Err(EcError::PointNotOnCurve);
+    }
+    Ok(CurvePoint::new_unchecked(self, point))
+  }
+
+  /// Function gets base point as validated point.
+  pub fn base_point(&self) -> CurvePoint<'_, T> { CurvePoint::new_unchecked(self, self.bp.clone()) }
+
+  /// Function gets point at infinity $O_e$ as validated point.
+  pub fn identity(&self) -> CurvePoint<'_, T> { CurvePoint::new_unchecked(self, AffinePoint::Infinity) }
+
   /// Function performs _packing_ of point that has odd prime order in EC over GF(2^m)
   /// according to the algorithm `6.9`.
   /// Overrides function from [AffinePoint::unpack].
   pub fn unpack_affine_point(&self, num : &T) -> AffinePoint<T> { unpack_affine_point(num, self) }
 
+  /// Function performs _unpacking_ of point with validation of the result.
+  /// Related to function [AffinePoint::try_unpack].
+  pub fn try_unpack_affine_point(&self, num : &T) -> Result<AffinePoint<T>> { try_unpack_affine_point(num, self) }
+
   /// Function performs _unpacking_ of point that has odd prime order in EC over GF(2^m)
   /// according to the algorithm `6.10`.
   /// Overrides function from [AffinePoint::pack].
@@ -353,7 +911,78 @@ impl<'a, T : GFArithmetic<'a>> BinaryEC<T>
   /// Related to function [AffinePoint::double].
   pub fn double(&self, p : &AffinePoint<T>) -> AffinePoint<T> { p.double(self) }
 
+  /// Function adds points pairwise, where equal points are doubled, with usage of single field inversion.
+  /// Related to function [batch_add_affine_points].
+  pub fn batch_add(&self, pairs : &[(AffinePoint<T>, AffinePoint<T>)]) -> Vec<AffinePoint<T>>
+  {
+    batch_add_affine_points(self, pairs)
+  }
+
+  /// Function doubles every point with usage of single field inversion.
+  /// Related to function [batch_add_affine_points].
+  pub fn batch_double(&self, points : &[AffinePoint<T>]) -> Vec<AffinePoint<T>>
+  {
+    batch_add_affine_points(self, &points.iter().map(|p| (p.clone(), p.clone())).collect::<Vec<_>>())
+  }
+
+  /// Function converts points from the projective coordinates into the affine ones with usage of single field inversion.
+  /// Related to function [batch_normalize_projective_points].
+  pub fn batch_normalize(&self, points : &[ProjectivePoint<T>]) -> Vec<AffinePoint<T>>
+  {
+    batch_normalize_projective_points(points)
+  }
+
   /// Function performs multiplication on number in affine coordinates.
   /// Related to function [AffinePoint::mul].
   pub fn mul<N : Into<BigUint>>(&self, point : &AffinePoint<T>, n : N) -> AffinePoint<T> { point.mul(self, n) }
+
+  /// Function performs multiplication on secret number with countermeasures against side-channel attacks.
+  /// Related to function [mul_secret].
+  pub fn mul_secret<N : Into<BigUint>>(
+    &self,
+    point : &AffinePoint<T>,
+    n : N,
+    countermeasures : Countermeasures,
+    rng : &mut impl CryptoRngCore,
+  ) -> AffinePoint<T>
+  {
+    mul_secret(self, point, n, countermeasures, rng)
+  }
+
+  /// Function performs halving of point in affine coordinates.
+  /// Related to function [AffinePoint::halve].
+  pub fn halve(&self, p : &AffinePoint<T>) -> AffinePoint<T> { p.halve(self) }
+
+  /// Function performs multiplication on number with usage of halve-and-add method.
+  /// Related to function [AffinePoint::mul_halve_and_add].
+  pub fn mul_halve_and_add<N : Into<BigUint>>(&self, point : &AffinePoint<T>, n : N) -> AffinePoint<T>
+  {
+    point.mul_halve_and_add(self, n)
+  }
+
+  /// Function finds discrete logarithm $k$, such that $Q = kP$, it's feasible only for small EC's.
+  /// Related to function [discrete_log].
+  pub fn discrete_log(&self, p : &AffinePoint<T>, q : &AffinePoint<T>, rng : &mut impl CryptoRngCore) -> Option<BigUint>
+  {
+    discrete_log(self, p, q, rng)
+  }
+
+  /// Function checks whether EC is anomalous binary (Koblitz) curve, i.e. $B = 1$,
+  /// so Frobenius endomorphism can be used for the multiplication.
+  pub fn is_koblitz(&self) -> bool { self.b.is_one() }
+
+  /// Function applies Frobenius endomorphism to the point.
+  /// Related to function [AffinePoint::frobenius].
+  pub fn frobenius(&self, p : &AffinePoint<T>) -> AffinePoint<T> { p.frobenius() }
+
+  /// Function performs multiplication on number with usage of TNAF.
+  /// Related to function [AffinePoint::mul_tnaf].
+  pub fn mul_tnaf<N : Into<BigUint>>(&self, point : &AffinePoint<T>, n : N) -> Option<AffinePoint<T>> { point.mul_tnaf(self, n) }
+
+  /// Function performs multiplication on number with usage of width-w TNAF.
+  /// Related to function [AffinePoint::mul_wtnaf].
+  pub fn mul_wtnaf<N : Into<BigUint>>(&self, point : &AffinePoint<T>, n : N, w : u8) -> Option<AffinePoint<T>>
+  {
+    point.mul_wtnaf(self, n, w)
+  }
 }
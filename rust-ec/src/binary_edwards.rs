@@ -0,0 +1,302 @@
+//! Binary Edwards curves $E_{B, d_1, d_2} : d_1 (x + y) + d_2 (x^2 + y^2) = xy + xy(x + y) + x^2 y^2$
+//! from the paper «Binary Edwards Curves» (Bernstein, Lange, Rezaeian Farashahi).
+//!
+//! Curve is complete when $d_1 \ne 0$ and $Tr(d_2) = 1$: addition law has no exceptional cases, so the same formula
+//! is used for $P + Q$, $P + P$, $P + (-P)$ and the neutral point $(0, 0)$. Such curve is birationally equivalent
+//! to the ordinary [BinaryEC], so points are mapped back and forth and the group law stays the same.
+use num_bigint::BigUint;
+use num_traits::One;
+use poly_algebra::gf::GFArithmetic;
+use poly_algebra::helpers::solve_quadratic_equation_in_field;
+use subtle::Choice;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::BinaryEC;
+use crate::error::{EcError, Result};
+use crate::helpers::conditional_swap_field_elements;
+
+/// Number of candidates $d_1$, that are checked when complete binary Edwards curve is searched.
+pub const EDWARDS_SEARCH_BOUND : u64 = 1 << 16;
+
+/// Complete binary Edwards curve, that is birationally equivalent to the [BinaryEC]
+/// $y^2 + xy = x^3 + Ax^2 + B$. Equivalence goes through the curve $v^2 + uv = u^3 + (d_1^2 + d_2)u^2 + B$,
+/// which is isomorphic to the [BinaryEC] with help of $(u, v) \to (u, v + su)$, where $s^2 + s = d_1^2 + d_2 + A$.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct BinaryEdwardsCurve<T>
+{
+  pub(crate) d_1 : T,
+  pub(crate) d_2 : T,
+  pub(crate) s : T,
+  pub(crate) ec : BinaryEC<T>,
+}
+
+/// Point $(x, y)$ of the binary Edwards curve, neutral point is $(0, 0)$,
+/// so there is no need in the point at infinity.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EdwardsPoint<T>
+{
+  pub(crate) x : T,
+  pub(crate) y : T,
+}
+
+impl<'a, T : GFArithmetic<'a>> EdwardsPoint<T>
+{
+  /// Function creates point from coordinates as is, use [BinaryEdwardsCurve::check_point] to validate it.
+  pub fn new(x : T, y : T) -> Self { EdwardsPoint { x, y } }
+
+  /// Function gets neutral point $(0, 0)$.
+  pub fn neutral() -> Self
+  {
+    EdwardsPoint {
+      x : T::zero(),
+      y : T::zero(),
+    }
+  }
+
+  /// Checks whether point is neutral.
+  pub fn is_neutral(&self) -> bool { self.x.is_zero() && self.y.is_zero() }
+
+  /// Function generates negative point: $-(x, y) = (y, x)$.
+  pub fn negative(&self) -> Self
+  {
+    EdwardsPoint {
+      x : self.y.clone(),
+      y : self.x.clone(),
+    }
+  }
+
+  pub fn get_ref_x(&self) -> &T { &self.x }
+
+  pub fn get_ref_y(&self) -> &T { &self.y }
+}
+
+/// Point $(X : Y : Z)$ of the binary Edwards curve in projective coordinates, that is used in [BinaryEdwardsCurve::mul].
+struct EdwardsProjectivePoint<T>
+{
+  x : T,
+  y : T,
+  z : T,
+}
+
+impl<'a, T : GFArithmetic<'a>> EdwardsProjectivePoint<T>
+{
+  /// Function swaps points, when `choice` is set.
+  fn conditional_swap(&mut self, other : &mut Self, choice : Choice)
+  {
+    conditional_swap_field_elements(&mut self.x, &mut other.x, choice);
+    conditional_swap_field_elements(&mut self.y, &mut other.y, choice);
+    conditional_swap_field_elements(&mut self.z, &mut other.z, choice);
+  }
+
+  /// Function converts point into the affine coordinates, $Z \ne 0$ for every point of the complete curve.
+  fn to_affine(&self) -> EdwardsPoint<T>
+  {
+    let z_inv = self.z.inverse();
+    EdwardsPoint {
+      x : self.x.clone() * z_inv.clone(),
+      y : self.y.clone() * z_inv,
+    }
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> From<EdwardsPoint<T>> for EdwardsProjectivePoint<T>
+{
+  fn from(point : EdwardsPoint<T>) -> Self
+  {
+    EdwardsProjectivePoint {
+      x : point.x,
+      y : point.y,
+      z : T::one(),
+    }
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> BinaryEdwardsCurve<T>
+{
+  /// Function finds complete binary Edwards curve, that is birationally equivalent to the EC.
+  /// $d_2 = \sqrt{B} / d_1^2 + d_1^2 + d_1$ is chosen so that $d_1^4 (d_1^4 + d_1^2 + d_2^2) = B$,
+  /// then $d_1 = 1, 2, \ldots$ are checked until $Tr(d_2) = 1$ (completeness) and $Tr(d_1^2 + d_2) = Tr(A)$
+  /// (curves are isomorphic). Returns [EcError::NoEdwardsForm] if there is no suitable $d_1$
+  /// among first [EDWARDS_SEARCH_BOUND] candidates.
+  pub fn new(ec : BinaryEC<T>) -> Result<Self>
+  {
+    let a = ec.a.as_field_el();
+    let b_sqrt = ec.b.sqrt();
+    let bound = 1_u64.checked_shl(T::get_m()).map_or(EDWARDS_SEARCH_BOUND, |q| q.min(EDWARDS_SEARCH_BOUND));
+    for d_1 in (1 .. bound).map(T::from_poly)
+    {
+      let d_1_squared = d_1.square();
+      let d_2 = b_sqrt.clone() * d_1_squared.inverse() + d_1_squared.clone() + d_1.clone();
+      let a_2 = d_1_squared + d_2.clone();
+      if d_2.trace().is_one() && a_2.trace() == a.trace()
+      {
+        let (s, _) = solve_quadratic_equation_in_field(&T::one(), &(a_2 + a.clone()))
+          .expect("trace of the coefficient is zero, so equation has solution");
+        return Ok(BinaryEdwardsCurve { d_1, d_2, s, ec });
+      }
+    }
+    Err(EcError::NoEdwardsForm)
+  }
+
+  pub fn get_ref_d_1(&self) -> &T { &self.d_1 }
+
+  pub fn get_ref_d_2(&self) -> &T { &self.d_2 }
+
+  /// Function gets reference to the birationally equivalent EC.
+  pub fn get_ref_ec(&self) -> &BinaryEC<T> { &self.ec }
+
+  /// Function gets image of the base point of the equivalent EC.
+  pub fn get_bp(&self) -> EdwardsPoint<T> { self.from_affine_point(self.ec.get_ref_bp()) }
+
+  /// Function checks whether point belongs to the curve.
+  pub fn check_point(&self, point : &EdwardsPoint<T>) -> bool
+  {
+    let (x, y) = (point.x.clone(), point.y.clone());
+    let xy = x.clone() * y.clone();
+    self.d_1.clone() * (x.clone() + y.clone()) + self.d_2.clone() * (x.square() + y.square())
+      == xy.clone() + xy.clone() * (x + y) + xy.square()
+  }
+
+  /// Function adds points with help of the complete addition law:
+  /// * $x_3 = \frac{d_1(x_1 + x_2) + d_2(x_1 + y_1)(x_2 + y_2) + (x_1 + x_1^2)(x_2(y_1 + y_2 + 1) + y_1 y_2)}
+  ///   {d_1 + (x_1 + x_1^2)(x_2 + y_2)}$;
+  /// * $y_3 = \frac{d_1(y_1 + y_2) + d_2(x_1 + y_1)(x_2 + y_2) + (y_1 + y_1^2)(y_2(x_1 + x_2 + 1) + x_1 x_2)}
+  ///   {d_1 + (y_1 + y_1^2)(x_2 + y_2)}$.
+  ///
+  /// Denominators are nonzero for every pair of points of the complete curve, so there are no branches.
+  pub fn add(&self, p : &EdwardsPoint<T>, q : &EdwardsPoint<T>) -> EdwardsPoint<T>
+  {
+    let (x_1, y_1, x_2, y_2) = (p.x.clone(), p.y.clone(), q.x.clone(), q.y.clone());
+    let w_1 = x_1.clone() + y_1.clone();
+    let w_2 = x_2.clone() + y_2.clone();
+    let d_2_w = self.d_2.clone() * w_1 * w_2.clone();
+    let a = x_1.clone() + x_1.square();
+    let b = y_1.clone() + y_1.square();
+    let x_numerator = self.d_1.clone() * (x_1.clone() + x_2.clone())
+      + d_2_w.clone()
+      + a.clone() * (x_2.clone() * (y_1.clone() + y_2.clone() + T::one()) + y_1.clone() * y_2.clone());
+    let y_numerator =
+      self.d_1.clone() * (y_1 + y_2.clone()) + d_2_w + b.clone() * (y_2 * (x_1.clone() + x_2.clone() + T::one()) + x_1 * x_2);
+    let x_denominator = self.d_1.clone() + a * w_2.clone();
+    let y_denominator = self.d_1.clone() + b * w_2;
+    EdwardsPoint {
+      x : x_numerator * x_denominator.inverse(),
+      y : y_numerator * y_denominator.inverse(),
+    }
+  }
+
+  /// Function doubles point with the same complete addition law.
+  pub fn double(&self, p : &EdwardsPoint<T>) -> EdwardsPoint<T> { self.add(p, p) }
+
+  /// Function multiplies point by the scalar with help of Montgomery ladder: $k$ is reduced modulo $h \cdot n$
+  /// and all $L(hn)$ bits are processed with one addition and one doubling, points are swapped with
+  /// [conditional_swap_field_elements] instead of branching on the bits of $k$.
+  /// Ladder runs in projective coordinates, so the result is normalized with the only inversion.
+  pub fn mul<N : Into<BigUint>>(&self, p : &EdwardsPoint<T>, k : N) -> EdwardsPoint<T>
+  {
+    let ord = self.ec.get_curve_ord();
+    let k = k.into() % &ord;
+    let mut r_0 = EdwardsProjectivePoint::from(EdwardsPoint::neutral());
+    let mut r_1 = EdwardsProjectivePoint::from(p.clone());
+    let mut swap = Choice::from(0);
+    for i in (0 .. ord.bits()).rev()
+    {
+      let bit = Choice::from(k.bit(i) as u8);
+      swap ^= bit;
+      r_0.conditional_swap(&mut r_1, swap);
+      swap = bit;
+      r_1 = self.add_projective(&r_0, &r_1);
+      r_0 = self.add_projective(&r_0, &r_0);
+    }
+    r_0.conditional_swap(&mut r_1, swap);
+    r_0.to_affine()
+  }
+
+  /// Function adds points in projective coordinates $x = X/Z$, $y = Y/Z$ with the complete addition law
+  /// multiplied by the common denominators:
+  /// * $N_x = (d_1(X_1 Z_2 + X_2 Z_1) + d_2 W_1 W_2) Z_1^2 Z_2 + X_1(Z_1 + X_1)(X_2(Y_1 Z_2 + Y_2 Z_1 + Z_1 Z_2)
+  ///   + Y_1 Y_2 Z_2)$, $D_x = d_1 Z_1^2 Z_2 + X_1(Z_1 + X_1) W_2$, where $W_i = X_i + Y_i$;
+  /// * $N_y$ and $D_y$ are the same with swapped $X_i$ and $Y_i$;
+  /// * $(X_3 : Y_3 : Z_3) = (N_x D_y : N_y D_x : Z_1 Z_2 D_x D_y)$.
+  fn add_projective(&self, p : &EdwardsProjectivePoint<T>, q : &EdwardsProjectivePoint<T>) -> EdwardsProjectivePoint<T>
+  {
+    let (x_1, y_1, z_1, x_2, y_2, z_2) = (&p.x, &p.y, &p.z, &q.x, &q.y, &q.z);
+    let w_2 = x_2.clone() + y_2.clone();
+    let z_1_z_2 = z_1.clone() * z_2.clone();
+    let z = z_1.clone() * z_1_z_2.clone();
+    let d_2_w = self.d_2.clone() * (x_1.clone() + y_1.clone()) * w_2.clone();
+    let a = x_1.clone() * (z_1.clone() + x_1.clone());
+    let b = y_1.clone() * (z_1.clone() + y_1.clone());
+    let x_1_z_2_x_2_z_1 = x_1.clone() * z_2.clone() + x_2.clone() * z_1.clone();
+    let y_1_z_2_y_2_z_1 = y_1.clone() * z_2.clone() + y_2.clone() * z_1.clone();
+    let x_numerator = (self.d_1.clone() * x_1_z_2_x_2_z_1.clone() + d_2_w.clone()) * z.clone()
+      + a.clone() * (x_2.clone() * (y_1_z_2_y_2_z_1.clone() + z_1_z_2.clone()) + y_1.clone() * y_2.clone() * z_2.clone());
+    let y_numerator = (self.d_1.clone() * y_1_z_2_y_2_z_1 + d_2_w) * z.clone()
+      + b.clone() * (y_2.clone() * (x_1_z_2_x_2_z_1 + z_1_z_2.clone()) + x_1.clone() * x_2.clone() * z_2.clone());
+    let d_1_z = self.d_1.clone() * z;
+    let x_denominator = d_1_z.clone() + a * w_2.clone();
+    let y_denominator = d_1_z + b * w_2;
+    EdwardsProjectivePoint {
+      x : x_numerator * y_denominator.clone(),
+      y : y_numerator * x_denominator.clone(),
+      z : z_1_z_2 * x_denominator * y_denominator,
+    }
+  }
+
+  /// Function maps point of the binary Edwards curve into the point of the equivalent EC:
+  /// * $u = \frac{d_1 e (x + y)}{xy + d_1(x + y)}$, $v = d_1 e \left(\frac{x}{xy + d_1(x + y)} + d_1 + 1\right)$,
+  ///   where $e = d_1^2 + d_1 + d_2$;
+  /// * $(u, v) \to (u, v + su)$.
+  ///
+  /// Neutral point $(0, 0)$ is mapped into $O_e$.
+  pub fn to_affine_point(&self, point : &EdwardsPoint<T>) -> AffinePoint<T>
+  {
+    let (x, y) = (point.x.clone(), point.y.clone());
+    let denominator = x.clone() * y.clone() + self.d_1.clone() * (x.clone() + y.clone());
+    if denominator.is_zero()
+    {
+      return AffinePoint::Infinity;
+    }
+    let denominator_inv = denominator.inverse();
+    let f = self.d_1.clone() * self.e();
+    let u = f.clone() * (x.clone() + y) * denominator_inv.clone();
+    let v = f * (x * denominator_inv + self.d_1.clone() + T::one());
+    AffinePoint::Point {
+      x : u.clone(),
+      y : v + self.s.clone() * u,
+    }
+  }
+
+  /// Function maps point of the equivalent EC into the point of the binary Edwards curve:
+  /// * $(u, v) \to (u, v + su)$;
+  /// * $x = \frac{d_1 (u + e)}{u + v + (d_1^2 + d_1) e}$, $y = \frac{d_1 (u + e)}{v + (d_1^2 + d_1) e}$,
+  ///   where $e = d_1^2 + d_1 + d_2$.
+  ///
+  /// $O_e$ is mapped into the neutral point $(0, 0)$, both fractions are $0/0$ for $u = e$, such points are mapped into
+  /// $(0, d_1/d_2)$ when $v = (d_1^2 + d_1) e$ and into $(d_1/d_2, 0)$ otherwise.
+  pub fn from_affine_point(&self, point : &AffinePoint<T>) -> EdwardsPoint<T>
+  {
+    match point
+    {
+      AffinePoint::Point { x: u, y: v } =>
+      {
+        let v = v.clone() + self.s.clone() * u.clone();
+        let e = self.e();
+        let c = (self.d_1.square() + self.d_1.clone()) * e.clone();
+        if *u == e
+        {
+          let t = self.d_1.clone() * self.d_2.inverse();
+          return if v == c { EdwardsPoint::new(T::zero(), t) } else { EdwardsPoint::new(t, T::zero()) };
+        }
+        let numerator = self.d_1.clone() * (u.clone() + e);
+        EdwardsPoint {
+          x : numerator.clone() * (u.clone() + v.clone() + c.clone()).inverse(),
+          y : numerator * (v + c).inverse(),
+        }
+      }
+      AffinePoint::Infinity => EdwardsPoint::neutral(),
+    }
+  }
+
+  /// Function calculates $e = d_1^2 + d_1 + d_2$, that is used in the birational maps.
+  fn e(&self) -> T { self.d_1.square() + self.d_1.clone() + self.d_2.clone() }
+}
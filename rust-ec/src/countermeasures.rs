@@ -0,0 +1,156 @@
+//! Countermeasures against side-channel attacks for multiplication of the point on secret scalar.
+//!
+//! Every countermeasure randomizes intermediate values of the multiplication, while the result stays the same:
+//! * scalar blinding — $kP = (k + r \cdot h \cdot n)P$ for random $r$ of [BLINDING_FACTOR_BITS] bits,
+//!   so bits of the processed scalar differ on every call;
+//! * randomized projective coordinates — point is multiplied with the [montgomery_ladder], that starts from
+//!   $(\lambda x : \lambda)$ for random $\lambda \ne 0$, so intermediate coordinates are unpredictable;
+//! * randomized base point split — $kP = kR + k(P - R)$ for random point $R$, so the multiplied points are unknown.
+//!
+//! Ladder processes fixed number of bits and swaps points without branches on the bits of the scalar.
+//! _Without randomized coordinates point is multiplied with double-and-add, which adds point only for the set bits,
+//! so its timing depends on the scalar and such multiplication isn't constant time._
+//! Field elements keep polynomials in [BigUint], so arithmetic itself isn't constant time either.
+use num_bigint::BigUint;
+use rand_core::CryptoRngCore;
+use poly_algebra::gf::GFArithmetic;
+use poly_algebra::helpers::generate_num;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::BinaryEC;
+use crate::helpers::{generate_random_affine_point, montgomery_ladder, mul_affine_point};
+use crate::projective_point::ProjectivePoint;
+
+/// Bit length of the random factor $r$ in the scalar blinding $k + r \cdot h \cdot n$.
+pub const BLINDING_FACTOR_BITS : u64 = 64;
+
+/// Set of countermeasures, that are applied in [mul_secret], every one can be switched on separately.
+/// [Countermeasures::DEFAULT] is used by default.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Countermeasures
+{
+  pub scalar_blinding : bool,
+  pub randomized_coordinates : bool,
+  pub base_point_split : bool,
+}
+
+impl Countermeasures
+{
+  /// Multiplication is performed as is.
+  pub const NONE : Countermeasures = Countermeasures {
+    scalar_blinding : false,
+    randomized_coordinates : false,
+    base_point_split : false,
+  };
+  /// All countermeasures are switched on.
+  pub const ALL : Countermeasures = Countermeasures {
+    scalar_blinding : true,
+    randomized_coordinates : true,
+    base_point_split : true,
+  };
+  /// Scalar blinding and randomized coordinates. Base point split is switched off, because it generates random point
+  /// and doubles the number of multiplications, while randomized coordinates already hide the multiplied point.
+  pub const DEFAULT : Countermeasures = Countermeasures {
+    scalar_blinding : true,
+    randomized_coordinates : true,
+    base_point_split : false,
+  };
+
+  pub fn with_scalar_blinding(self, scalar_blinding : bool) -> Self { Countermeasures { scalar_blinding, ..self } }
+
+  pub fn with_randomized_coordinates(self, randomized_coordinates : bool) -> Self
+  {
+    Countermeasures {
+      randomized_coordinates,
+      ..self
+    }
+  }
+
+  pub fn with_base_point_split(self, base_point_split : bool) -> Self { Countermeasures { base_point_split, ..self } }
+}
+
+impl Default for Countermeasures
+{
+  fn default() -> Self { Self::DEFAULT }
+}
+
+/// Function multiplies point on the secret scalar with the chosen countermeasures, result is equal to [AffinePoint::mul].
+/// Scalar is blinded with the order of the whole group $h \cdot n$ rather than $n$,
+/// so multiplication is correct for every point on the EC, not only for the points of order $n$.
+pub fn mul_secret<'a, T : GFArithmetic<'a>, N : Into<BigUint>>(
+  ec : &BinaryEC<T>,
+  point : &AffinePoint<T>,
+  k : N,
+  countermeasures : Countermeasures,
+  rng : &mut impl CryptoRngCore,
+) -> AffinePoint<T>
+{
+  let k = k.into() % ec.get_curve_ord();
+  let points = if countermeasures.base_point_split
+  {
+    let r = generate_random_affine_point(rng, ec);
+    vec![point.add(ec, &r.negative()), r]
+  }
+  else
+  {
+    vec![point.clone()]
+  };
+  points.iter().fold(AffinePoint::Infinity, |acc, point| {
+    let k = if countermeasures.scalar_blinding
+    {
+      &k + generate_num(rng, BLINDING_FACTOR_BITS) * ec.get_curve_ord()
+    }
+    else
+    {
+      k.clone()
+    };
+    let product = if countermeasures.randomized_coordinates
+    {
+      // $k + r \cdot h \cdot n < 2^{64} \cdot h \cdot n$, so the length of the ladder doesn't depend on $k$
+      let bits = ec.get_curve_ord().bits() + if countermeasures.scalar_blinding { BLINDING_FACTOR_BITS } else { 0 };
+      mul_randomized_projective(ec, point, &k, bits, rng)
+    }
+    else
+    {
+      mul_affine_point(point, ec, &k)
+    };
+    acc.add(ec, &product)
+  })
+}
+
+/// Function multiplies point on number with the [montgomery_ladder] over `bits` lower bits, that starts from the
+/// randomized representation $(\lambda x : \lambda)$ of the point. $y$ of $kP$ is recovered from $x$ of $kP$ and $(k + 1)P$
+/// (Guide to ECC, algorithm 3.40): $y_k = (x + x_k)((X_1 + x Z_1)(X_2 + x Z_2) + (x^2 + y) Z_1 Z_2)(x Z_1 Z_2)^{-1} + y$.
+fn mul_randomized_projective<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  point : &AffinePoint<T>,
+  k : &BigUint,
+  bits : u64,
+  rng : &mut impl CryptoRngCore,
+) -> AffinePoint<T>
+{
+  let (x, y) = match point
+  {
+    AffinePoint::Point { x, y } if !x.is_zero() => (x, y),
+    // Point of order 2 or $O$
+    _ => return if k.bit(0) { point.clone() } else { AffinePoint::Infinity },
+  };
+  let r = ProjectivePoint::from(point.clone()).randomize(rng);
+  let ((x_1, z_1), (x_2, z_2)) = montgomery_ladder(ec, (r.x, r.z), k, bits);
+  if z_1.is_zero()
+  {
+    return AffinePoint::Infinity;
+  }
+  if z_2.is_zero()
+  {
+    return point.negative();
+  }
+  let x_z_1 = x.clone() * z_1.clone();
+  let x_z_2 = x.clone() * z_2.clone();
+  let inv = (x_z_1.clone() * z_2.clone()).inverse();
+  let x_k = x_1.clone() * x_z_2.clone() * inv.clone();
+  let y_k = (x.clone() + x_k.clone())
+    * ((x_1 + x_z_1) * (x_2 + x_z_2) + (x.square() + y.clone()) * z_1 * z_2)
+    * inv
+    + y.clone();
+  AffinePoint::Point { x : x_k, y : y_k }
+}
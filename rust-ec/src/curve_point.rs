@@ -0,0 +1,150 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::ptr;
+use num_bigint::BigUint;
+use poly_algebra::gf::GFArithmetic;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::BinaryEC;
+
+/// Point that is guaranteed to belong to the EC it's bound to.
+/// It can't be constructed from arbitrary coordinates, only with help of
+/// [BinaryEC::point_from_coordinates], [BinaryEC::unpack_point], [BinaryEC::base_point], [BinaryEC::identity]
+/// or as a result of arithmetic on other validated points, which protects from invalid-curve attacks.
+#[derive(Clone)]
+pub struct CurvePoint<'ec, T>
+{
+  ec : &'ec BinaryEC<T>,
+  point : AffinePoint<T>,
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> CurvePoint<'ec, T>
+{
+  /// Function wraps point that is already known to belong to the EC.
+  pub(crate) fn new_unchecked(ec : &'ec BinaryEC<T>, point : AffinePoint<T>) -> Self { CurvePoint { ec, point } }
+
+  /// Function gets reference to the EC which point belongs to.
+  pub fn get_ref_ec(&self) -> &'ec BinaryEC<T> { self.ec }
+
+  /// Function gets reference to the underlying affine point.
+  pub fn get_ref_point(&self) -> &AffinePoint<T> { &self.point }
+
+  /// Function gets underlying affine point.
+  pub fn into_affine(self) -> AffinePoint<T> { self.point }
+
+  /// Checks whether point is $O_e$.
+  pub fn is_inf(&self) -> bool { self.point.is_inf() }
+
+  /// Function generates negative point.
+  /// Related to function [AffinePoint::negative].
+  pub fn negative(&self) -> Self { CurvePoint::new_unchecked(self.ec, self.point.negative()) }
+
+  /// Function performs doubling of point.
+  /// Related to function [AffinePoint::double].
+  pub fn double(&self) -> Self { CurvePoint::new_unchecked(self.ec, self.point.double(self.ec)) }
+
+  /// Function performs _packing_ of point.
+  /// Related to function [AffinePoint::pack].
+  pub fn pack(&self) -> T { self.point.pack() }
+
+  /// Function performs addition of points, which have to be bound to the same EC.
+  fn add_point(&self, rhs : &Self) -> Self
+  {
+    self.assert_same_ec(rhs);
+    CurvePoint::new_unchecked(self.ec, self.point.add(self.ec, &rhs.point))
+  }
+
+  fn mul_number(&self, n : BigUint) -> Self { CurvePoint::new_unchecked(self.ec, self.point.mul(self.ec, n)) }
+
+  fn assert_same_ec(&self, rhs : &Self)
+  {
+    assert!(
+      ptr::eq(self.ec, rhs.ec) || self.ec == rhs.ec,
+      "Arithmetic is performed on points from different EC's"
+    );
+  }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> PartialEq for CurvePoint<'ec, T>
+{
+  fn eq(&self, other : &Self) -> bool { self.point == other.point && (ptr::eq(self.ec, other.ec) || self.ec == other.ec) }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> Eq for CurvePoint<'ec, T> {}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> Hash for CurvePoint<'ec, T>
+{
+  fn hash<H : Hasher>(&self, state : &mut H) { self.point.hash(state) }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> fmt::Debug for CurvePoint<'ec, T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&self.point, f) }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> fmt::Display for CurvePoint<'ec, T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { fmt::Display::fmt(&self.point, f) }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> From<CurvePoint<'ec, T>> for AffinePoint<T>
+{
+  fn from(value : CurvePoint<'ec, T>) -> Self { value.point }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> Add for CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn add(self, rhs : Self) -> Self::Output { self.add_point(&rhs) }
+}
+
+impl<'ec, 'a, 'c, T : GFArithmetic<'a>> Add<&'c CurvePoint<'ec, T>> for &CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn add(self, rhs : &'c CurvePoint<'ec, T>) -> Self::Output { self.add_point(rhs) }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> Sub for CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn sub(self, rhs : Self) -> Self::Output { self.add_point(&rhs.negative()) }
+}
+
+impl<'ec, 'a, 'c, T : GFArithmetic<'a>> Sub<&'c CurvePoint<'ec, T>> for &CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn sub(self, rhs : &'c CurvePoint<'ec, T>) -> Self::Output { self.add_point(&rhs.negative()) }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> Neg for CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn neg(self) -> Self::Output { self.negative() }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>> Neg for &CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn neg(self) -> Self::Output { self.negative() }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>, N : Into<BigUint>> Mul<N> for CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn mul(self, rhs : N) -> Self::Output { self.mul_number(rhs.into()) }
+}
+
+impl<'ec, 'a, T : GFArithmetic<'a>, N : Into<BigUint>> Mul<N> for &CurvePoint<'ec, T>
+{
+  type Output = CurvePoint<'ec, T>;
+
+  fn mul(self, rhs : N) -> Self::Output { self.mul_number(rhs.into()) }
+}
@@ -0,0 +1,354 @@
+//! Solving of the elliptic curve discrete logarithm problem $Q = kP$ on small (toy) EC's, for example over
+//! the fields `GF23` … `GF59` from `poly_algebra`. It's intended for teaching and for demonstration why
+//! parameters of the EC have to be validated with [BinaryEC::new]: logarithm in the group of composite order
+//! is reduced to logarithms in the subgroups of prime order (Pohlig–Hellman), and each of them is found
+//! with Pollard's rho in $O(\sqrt{p})$ group operations.
+//!
+//! Algorithms are taken from the «Guide to Elliptic Curve Cryptography» (Hankerson, Menezes, Vanstone), section `4.1`.
+use std::collections::HashMap;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Signed, Zero};
+use rand_core::CryptoRngCore;
+use poly_algebra::gf::GFArithmetic;
+use poly_algebra::helpers::generate_num;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::BinaryEC;
+use crate::helpers::{is_probably_prime, mul_affine_point};
+
+/// Number of partitions in the $r$-adding walk of Pollard's rho.
+pub const RHO_PARTITIONS : usize = 32;
+/// Multiplier of the Fibonacci hashing, that defines distinguished points and partitions from $x$ coordinate.
+const RHO_HASH_MULTIPLIER : u64 = 0x9E37_79B9_7F4A_7C15;
+/// Logarithms in the subgroups of order less than this bound are found with exhaustive search.
+pub const BRUTE_FORCE_BOUND : u64 = 1 << 10;
+/// Bound of the trial division in [factorize], remaining cofactor is split with Pollard's rho.
+const TRIAL_DIVISION_BOUND : u64 = 1 << 12;
+/// Number of collisions with equal coefficients after which Pollard's rho gives up,
+/// it happens when $Q \notin \langle P \rangle$.
+const RHO_MAX_FRUITLESS_COLLISIONS : usize = 8;
+/// Number of iterations of Pollard's rho in [factorize], after which gcd is calculated.
+const RHO_GCD_BATCH : u64 = 64;
+
+/// Function finds $k \in \[0, ord(P))$, such that $Q = kP$, or returns `None` when $Q \notin \langle P \rangle$.
+/// Order of $P$ is found with help of [point_order] and logarithm is calculated with [pohlig_hellman].
+pub fn discrete_log<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  p : &AffinePoint<T>,
+  q : &AffinePoint<T>,
+  rng : &mut impl CryptoRngCore,
+) -> Option<BigUint>
+{
+  let ord = point_order(ec, p);
+  pohlig_hellman(ec, p, q, &ord, rng)
+}
+
+/// Function finds exact order of the point, that divides number of points $h \cdot n$ of the EC.
+pub fn point_order<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, p : &AffinePoint<T>) -> BigUint
+{
+  let mut ord = ec.get_curve_ord();
+  for (r, _) in factorize(&ec.get_curve_ord())
+  {
+    while (&ord % &r).is_zero() && mul_affine_point(p, ec, &(&ord / &r)).is_inf()
+    {
+      ord /= &r;
+    }
+  }
+  ord
+}
+
+/// Function finds $k \in \[0, ord)$, such that $Q = kP$, where $ord$ is the order of $P$ (algorithm `4.2`):
+/// $k \mod r^e$ is found digit by digit for every prime power $r^e \| ord$ in the subgroup of order $r$,
+/// then results are combined with help of the Chinese remainder theorem.
+/// Returns `None` when $Q \notin \langle P \rangle$.
+pub fn pohlig_hellman<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  p : &AffinePoint<T>,
+  q : &AffinePoint<T>,
+  ord : &BigUint,
+  rng : &mut impl CryptoRngCore,
+) -> Option<BigUint>
+{
+  let mut k = BigUint::zero();
+  for (r, e) in factorize(ord)
+  {
+    let r_e = r.pow(e);
+    // $\gamma = (ord / r) P$ has order $r$
+    let gamma = mul_affine_point(p, ec, &(ord / &r));
+    let mut k_r = BigUint::zero();
+    let mut r_i = BigUint::one();
+    for _ in 0 .. e
+    {
+      r_i *= &r;
+      let h = mul_affine_point(&q.add(ec, &mul_affine_point(p, ec, &k_r).negative()), ec, &(ord / &r_i));
+      if !mul_affine_point(&h, ec, &r).is_inf()
+      {
+        return None;
+      }
+      let digit = pollard_rho(ec, &gamma, &h, &r, rng)?;
+      k_r += digit * (&r_i / &r);
+    }
+    // $k \equiv k_r \mod r^e$
+    let m = ord / &r_e;
+    let m_inv = mod_inverse(&(&m % &r_e), &r_e)?;
+    k = (k + k_r * &m * m_inv) % ord;
+  }
+  if mul_affine_point(p, ec, &k) == *q
+  {
+    Some(k)
+  }
+  else
+  {
+    None
+  }
+}
+
+/// Function finds $k \in \[0, n)$, such that $Q = kP$, where $P$ has prime order $n$ (algorithm `4.3`).
+/// Parallelized version of the method with distinguished points is used: $r$-adding walks
+/// $X \to X + R_{j(X)}$, where $R_j = a_j P + b_j Q$, are started from random points $aP + bQ$ and are stopped
+/// on the distinguished points, whose hash of $x$ has $d$ leading zero bits. Collision of two walks in
+/// the same distinguished point $a_1 P + b_1 Q = a_2 P + b_2 Q$ gives $k = (a_1 - a_2)(b_2 - b_1)^{-1} \mod n$.
+/// Returns `None` when $Q \notin \langle P \rangle$.
+pub fn pollard_rho<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  p : &AffinePoint<T>,
+  q : &AffinePoint<T>,
+  n : &BigUint,
+  rng : &mut impl CryptoRngCore,
+) -> Option<BigUint>
+{
+  if q.is_inf()
+  {
+    return Some(BigUint::zero());
+  }
+  if *n < BigUint::from(BRUTE_FORCE_BOUND)
+  {
+    return brute_force(ec, p, q, n);
+  }
+  let linear_combination = |a : &BigUint, b : &BigUint| mul_affine_point(p, ec, a).add(ec, &mul_affine_point(q, ec, b));
+  let mut steps = Vec::with_capacity(RHO_PARTITIONS);
+  for _ in 0 .. RHO_PARTITIONS
+  {
+    let (a, b) = (generate_num(rng, n.bits()) % n, generate_num(rng, n.bits()) % n);
+    steps.push((linear_combination(&a, &b), a, b));
+  }
+  // Expected length of the walk is $2^d \approx \sqrt{n} / 2^5$
+  let d = (n.bits() / 2).saturating_sub(5);
+  let max_walk_len = 20_u64 << d;
+  // Bits of $x$ aren't used directly, because for the points of odd order $Tr(x) = Tr(A)$ is fixed,
+  // and trace is often equal to the least significant bit
+  let hash = |x : &T| x.get_ref_value().iter_u64_digits().next().unwrap_or(0).wrapping_mul(RHO_HASH_MULTIPLIER);
+  let mut distinguished = HashMap::new();
+  let mut fruitless_collisions = 0;
+  loop
+  {
+    let (mut a, mut b) = (generate_num(rng, n.bits()) % n, generate_num(rng, n.bits()) % n);
+    let mut x = linear_combination(&a, &b);
+    for _ in 0 .. max_walk_len
+    {
+      let x_value = match &x
+      {
+        AffinePoint::Point { x, .. } => x.clone(),
+        AffinePoint::Infinity => break,
+      };
+      let x_hash = hash(&x_value);
+      if x_hash.leading_zeros() as u64 >= d
+      {
+        match distinguished.insert(x.clone(), (a.clone(), b.clone()))
+        {
+          Some((a_2, b_2)) if b_2 != b =>
+          {
+            let k = ((&a + n - &a_2) % n * mod_inverse(&((&b_2 + n - &b) % n), n)?) % n;
+            return (mul_affine_point(p, ec, &k) == *q).then_some(k);
+          }
+          Some(_) =>
+          {
+            fruitless_collisions += 1;
+            if fruitless_collisions > RHO_MAX_FRUITLESS_COLLISIONS
+            {
+              return None;
+            }
+          }
+          None => (),
+        }
+        break;
+      }
+      let j = (x_hash.rotate_left(d as u32 + RHO_PARTITIONS.trailing_zeros()) as usize) % RHO_PARTITIONS;
+      let (r_j, a_j, b_j) = &steps[j];
+      x = x.add(ec, r_j);
+      a = (a + a_j) % n;
+      b = (b + b_j) % n;
+    }
+  }
+}
+
+/// Function finds logarithm by sequential enumeration of $P, 2P, \ldots, (n - 1)P$.
+fn brute_force<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  p : &AffinePoint<T>,
+  q : &AffinePoint<T>,
+  n : &BigUint,
+) -> Option<BigUint>
+{
+  let mut k = BigUint::zero();
+  let mut r = AffinePoint::Infinity;
+  while k < *n
+  {
+    if r == *q
+    {
+      return Some(k);
+    }
+    r = r.add(ec, p);
+    k += 1_u8;
+  }
+  None
+}
+
+/// Function factorizes number into prime powers $\prod r_i^{e_i}$ sorted by $r_i$.
+/// Small factors are removed with trial division, remaining ones are found with Pollard's rho (Floyd's cycle detection).
+pub fn factorize(n : &BigUint) -> Vec<(BigUint, u32)>
+{
+  let (factors, _) = factorize_with_bound(n, None);
+  factors
+}
+
+/// Function factorizes number the same way as [factorize], but Pollard's rho is stopped after `rho_iterations`
+/// for every composite factor. Returns found prime powers and the remaining cofactor, which is product of
+/// composite factors that weren't split (it's $1$ when number is fully factorized).
+pub fn factorize_partially(n : &BigUint, rho_iterations : u64) -> (Vec<(BigUint, u32)>, BigUint)
+{
+  factorize_with_bound(n, Some(rho_iterations))
+}
+
+fn factorize_with_bound(n : &BigUint, rho_iterations : Option<u64>) -> (Vec<(BigUint, u32)>, BigUint)
+{
+  let mut primes = Vec::new();
+  let mut n = n.clone();
+  let mut r = 2_u64;
+  while r < TRIAL_DIVISION_BOUND && BigUint::from(r * r) <= n
+  {
+    while (&n % r).is_zero()
+    {
+      primes.push(BigUint::from(r));
+      n /= r;
+    }
+    r += if r == 2 { 1 } else { 2 };
+  }
+  let mut cofactor = BigUint::one();
+  let mut composites = vec![n];
+  while let Some(n) = composites.pop()
+  {
+    if n.is_one()
+    {
+      continue;
+    }
+    if is_probably_prime(&n)
+    {
+      primes.push(n);
+      continue;
+    }
+    match find_factor(&n, rho_iterations)
+    {
+      Some(d) =>
+      {
+        composites.push(&n / &d);
+        composites.push(d);
+      }
+      None => cofactor *= n,
+    }
+  }
+  primes.sort();
+  let mut factors : Vec<(BigUint, u32)> = Vec::new();
+  for r in primes
+  {
+    match factors.last_mut()
+    {
+      Some((last, e)) if *last == r => *e += 1,
+      _ => factors.push((r, 1)),
+    }
+  }
+  (factors, cofactor)
+}
+
+/// Function finds nontrivial factor of the odd composite number with help of Pollard's rho $x \to x^2 + c$.
+/// Differences are accumulated into the product modulo $n$, so gcd is calculated once per [RHO_GCD_BATCH] iterations,
+/// batch is repeated step by step when the product is divisible by $n$.
+/// Returns `None` when factor isn't found within `max_iterations`.
+fn find_factor(n : &BigUint, max_iterations : Option<u64>) -> Option<BigUint>
+{
+  let mut iterations = 0;
+  let mut c = BigUint::one();
+  loop
+  {
+    let f = |x : &BigUint| (x * x + &c) % n;
+    let diff = |x : &BigUint, y : &BigUint| if x > y { x - y } else { y - x };
+    let (mut x, mut y) = (BigUint::from(2_u8), BigUint::from(2_u8));
+    let mut d = BigUint::one();
+    while d.is_one()
+    {
+      if max_iterations.is_some_and(|max_iterations| iterations >= max_iterations)
+      {
+        return None;
+      }
+      let (x_saved, y_saved) = (x.clone(), y.clone());
+      let mut product = BigUint::one();
+      for _ in 0 .. RHO_GCD_BATCH
+      {
+        x = f(&x);
+        y = f(&f(&y));
+        product = product * diff(&x, &y) % n;
+      }
+      iterations += RHO_GCD_BATCH;
+      d = gcd(product, n.clone());
+      if d == *n
+      {
+        (x, y) = (x_saved, y_saved);
+        loop
+        {
+          x = f(&x);
+          y = f(&f(&y));
+          d = gcd(diff(&x, &y), n.clone());
+          if !d.is_one()
+          {
+            break;
+          }
+        }
+      }
+    }
+    if d != *n
+    {
+      return Some(d);
+    }
+    c += 1_u8;
+  }
+}
+
+fn gcd(mut a : BigUint, mut b : BigUint) -> BigUint
+{
+  while !b.is_zero()
+  {
+    let t = &a % &b;
+    a = b;
+    b = t;
+  }
+  a
+}
+
+/// Function finds $a^{-1} \mod m$ with help of the extended Euclidean algorithm.
+fn mod_inverse(a : &BigUint, m : &BigUint) -> Option<BigUint>
+{
+  let (mut old_r, mut r) = (BigInt::from(a.clone()), BigInt::from(m.clone()));
+  let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+  while !r.is_zero()
+  {
+    let quotient = &old_r / &r;
+    (old_r, r) = (r.clone(), old_r - &quotient * r);
+    (old_s, s) = (s.clone(), old_s - &quotient * s);
+  }
+  if !old_r.is_one()
+  {
+    return None;
+  }
+  let m = BigInt::from(m.clone());
+  let inv = if old_s.is_negative() { old_s % &m + &m } else { old_s % &m };
+  inv.to_biguint()
+}
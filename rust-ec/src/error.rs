@@ -0,0 +1,50 @@
+use thiserror::Error;
+
+pub type Result<T> = core::result::Result<T, EcError>;
+
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum EcError
+{
+  #[error("Coefficient B of the EC has to be nonzero.")]
+  ZeroBCoefficient,
+  #[error("Base point has to be distinct from the point at infinity.")]
+  BasePointIsInfinity,
+  #[error("Base point doesn't belong to the EC.")]
+  BasePointNotOnCurve,
+  #[error("Order of the base point isn't equal to n: '{0}'.")]
+  WrongBasePointOrder(String),
+  #[error("Order of the base point isn't prime, n: '{0}'.")]
+  CompositeOrder(String),
+  #[error("Number of points h*n: '{0}' lies outside of the Hasse bound.")]
+  HasseBoundViolated(String),
+  #[error("EC doesn't satisfy MOV condition, n divides 2^(mk) - 1 for k = {0}.")]
+  MovConditionViolated(u32),
+  #[error("EC is anomalous, number of points is equal to 2^m.")]
+  AnomalousCurve,
+  #[error("Point doesn't belong to the EC.")]
+  PointNotOnCurve,
+  #[error("Packed point can't be unpacked, quadratic equation has no solution.")]
+  NoSquareRoot,
+  #[error("Point is the point at infinity.")]
+  PointIsInfinity,
+  #[error("Order of the point isn't equal to n: '{0}'.")]
+  WrongPointOrder(String),
+  #[error("Invalid SEC1 encoding of the point, error: '{0}'.")]
+  InvalidSec1Encoding(String),
+  #[error("Invalid params of the message expansion, error: '{0}'.")]
+  InvalidExpandMessageParams(String),
+  #[error("Unknown OID of the EC: '{0}'.")]
+  UnknownOid(String),
+  #[error("EC with OID '{0}' isn't defined over GF(2^{1}).")]
+  OidFieldMismatch(String, u32),
+  #[error("EC is defined over GF(2^{0}), not over GF(2^{1}).")]
+  FieldMismatch(u32, u32),
+  #[error("EC isn't birationally equivalent to any complete binary Edwards curve.")]
+  NoEdwardsForm,
+  #[error("Point belongs to the quadratic twist of the EC.")]
+  PointOnTwist,
+  #[error("Map into the EC is defined only over GF(2^m) with odd m, got m = {0}.")]
+  EvenDegree(u32),
+  #[error("Suitable EC wasn't found in {0} attempts.")]
+  CurveGenerationFailed(u32),
+}
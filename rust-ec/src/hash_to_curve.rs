@@ -0,0 +1,147 @@
+//! Hashing of the byte strings into points of the prime order subgroup of the EC
+//! $y^2 + xy = x^3 + Ax^2 + B$ over GF(2^m) with odd $m$.
+//!
+//! Structure follows the RFC 9380: message is expanded with `expand_message_xmd` and domain separation tag (DST),
+//! then bytes are converted into field elements, mapped into the EC with the map of Shallue and van de Woestijne
+//! for the characteristic 2 («Construction of Rational Points on Elliptic Curves over Finite Fields»)
+//! and cofactor is cleared.
+use digest::core_api::BlockSizeUser;
+use digest::Digest;
+use poly_algebra::gf::GFArithmetic;
+use poly_algebra::helpers::{create_field_el_from_hash, solve_quadratic_equation_in_field};
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::BinaryEC;
+use crate::error::{EcError, Result};
+
+/// Maximal length of the domain separation tag in bytes.
+pub const MAX_DST_LEN : usize = 255;
+
+/// Function expands message into `len` pseudorandom bytes according to the `expand_message_xmd` (RFC 9380, section `5.3.1`).
+pub fn expand_message_xmd<D : Digest + BlockSizeUser>(msg : &[u8], dst : &[u8], len : usize) -> Result<Vec<u8>>
+{
+  let b_len = <D as Digest>::output_size();
+  let ell = len.div_ceil(b_len);
+  if ell > 255 || len > u16::MAX as usize
+  {
+    return Err(EcError::InvalidExpandMessageParams(format!(
+      "requested length {len} is too big"
+    )));
+  }
+  if dst.len() > MAX_DST_LEN
+  {
+    return Err(EcError::InvalidExpandMessageParams(format!(
+      "DST length {} exceeds {MAX_DST_LEN} bytes",
+      dst.len()
+    )));
+  }
+  let dst_prime = [dst, &[dst.len() as u8]].concat();
+  let z_pad = vec![0_u8; D::block_size()];
+  let b_0 = D::new()
+    .chain_update(z_pad)
+    .chain_update(msg)
+    .chain_update((len as u16).to_be_bytes())
+    .chain_update([0_u8])
+    .chain_update(&dst_prime)
+    .finalize();
+  let mut b_i = D::new()
+    .chain_update(&b_0)
+    .chain_update([1_u8])
+    .chain_update(&dst_prime)
+    .finalize();
+  let mut uniform_bytes = b_i.to_vec();
+  for i in 2 ..= ell
+  {
+    let xored = b_0.iter().zip(b_i.iter()).map(|(x, y)| x ^ y).collect::<Vec<_>>();
+    b_i = D::new()
+      .chain_update(xored)
+      .chain_update([i as u8])
+      .chain_update(&dst_prime)
+      .finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+  }
+  uniform_bytes.truncate(len);
+  Ok(uniform_bytes)
+}
+
+/// Function hashes message into `count` field elements, each of them is created from $\lceil m/8 \rceil$ bytes
+/// of the expanded message with help of [create_field_el_from_hash].
+pub fn hash_to_field<'a, T : GFArithmetic<'a>, D : Digest + BlockSizeUser>(
+  msg : &[u8],
+  dst : &[u8],
+  count : usize,
+) -> Result<Vec<T>>
+{
+  let len = T::get_m().div_ceil(8) as usize;
+  let uniform_bytes = expand_message_xmd::<D>(msg, dst, count * len)?;
+  Ok(uniform_bytes.chunks(len).map(create_field_el_from_hash).collect())
+}
+
+/// Function maps field element into the point of the EC in the way of Shallue and van de Woestijne for the characteristic 2.
+/// Point with $x \ne 0$ exists iff $z^2 + xz = x^3 + Ax^2 + B$ has solution, i.e. $Tr(g(x)) = 0$ for $g(x) = x + A + B/x^2$.
+/// Candidates $x_1 = (u^2 + u + 1) / (s(u^2 + u))$, $x_2 = ux_1$, $x_3 = x_1 + x_2$ with $s^2 = (A + u^2 + u) / B$
+/// give $g(x_1) + g(x_2) + g(x_3) = u^2 + u$, which trace is zero, so the first candidate with the solution
+/// of [solve_quadratic_equation_in_field] is taken.
+/// Returns [EcError::EvenDegree] for even $m$, where half-trace can't solve the equation,
+/// and [EcError::PointIsInfinity] for $u$ with $u^2 + u = 0$ or $u^2 + u = A$, where candidates aren't defined.
+/// _Choice of the candidate depends on $u$, so map isn't constant time._
+pub fn map_to_curve<'a, T : GFArithmetic<'a>>(u : &T, ec : &BinaryEC<T>) -> Result<AffinePoint<T>>
+{
+  if T::get_m() % 2 == 0
+  {
+    return Err(EcError::EvenDegree(T::get_m()));
+  }
+  let a = ec.get_ref_a().as_field_el();
+  let u_2_u = u.square() + u.clone();
+  let s_2 = (a.clone() + u_2_u.clone()) * ec.get_ref_b().inverse();
+  if u_2_u.is_zero() || s_2.is_zero()
+  {
+    return Err(EcError::PointIsInfinity);
+  }
+  let x_1 = (u_2_u.clone() + T::one()) * (s_2.sqrt() * u_2_u).inverse();
+  let x_2 = u.clone() * x_1.clone();
+  let x_3 = x_1.clone() + x_2.clone();
+  [x_1, x_2, x_3]
+    .into_iter()
+    .find_map(|x| {
+      let w = x.square() * (x.clone() + a.clone()) + ec.get_b();
+      solve_quadratic_equation_in_field(&x, &w).map(|(y, _)| AffinePoint::Point { x, y })
+    })
+    .ok_or(EcError::NoSquareRoot)
+}
+
+/// Function hashes message into the point of the prime order subgroup (random oracle variant of the RFC 9380):
+/// two field elements are mapped into the EC, points are added and cofactor is cleared.
+/// Returns [EcError::PointIsInfinity] if result is $O_e$, it happens only with negligible probability.
+pub fn hash_to_curve<'a, T : GFArithmetic<'a>, D : Digest + BlockSizeUser>(
+  msg : &[u8],
+  dst : &[u8],
+  ec : &BinaryEC<T>,
+) -> Result<AffinePoint<T>>
+{
+  let u = hash_to_field::<T, D>(msg, dst, 2)?;
+  let q = map_to_curve(&u[0], ec)?.add(ec, &map_to_curve(&u[1], ec)?);
+  non_infinity(ec.clear_cofactor(&q))
+}
+
+/// Function encodes message into the point of the prime order subgroup (nonuniform variant of the RFC 9380):
+/// one field element is mapped into the EC and cofactor is cleared.
+/// Returns [EcError::PointIsInfinity] if result is $O_e$, it happens only with negligible probability.
+pub fn encode_to_curve<'a, T : GFArithmetic<'a>, D : Digest + BlockSizeUser>(
+  msg : &[u8],
+  dst : &[u8],
+  ec : &BinaryEC<T>,
+) -> Result<AffinePoint<T>>
+{
+  let u = hash_to_field::<T, D>(msg, dst, 1)?;
+  non_infinity(ec.clear_cofactor(&map_to_curve(&u[0], ec)?))
+}
+
+/// Function rejects the point at infinity, which isn't suitable as a hash of the message.
+fn non_infinity<T>(p : AffinePoint<T>) -> Result<AffinePoint<T>>
+{
+  match p
+  {
+    AffinePoint::Infinity => Err(EcError::PointIsInfinity),
+    p => Ok(p),
+  }
+}
@@ -1,10 +1,77 @@
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
 use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable};
 use poly_algebra::gf::GFArithmetic;
 use poly_algebra::helpers::solve_quadratic_equation_in_field;
 use crate::affine_point::AffinePoint;
 use crate::binary_ec::BinaryEC;
+use crate::error::{EcError, Result};
+use crate::projective_point::ProjectivePoint;
+
+/// Function swaps field elements, when `choice` is set. Polynomials are swapped limb by limb with masks of [subtle],
+/// so the sequence of operations doesn't depend on the choice.
+/// _Elements keep polynomials in [BigUint], so their conversion into limbs isn't constant time._
+pub fn conditional_swap_field_elements<'a, T : GFArithmetic<'a>>(a : &mut T, b : &mut T, choice : Choice)
+{
+  let limbs_num = T::get_m().div_ceil(64) as usize;
+  let to_limbs = |el : &T| {
+    let mut limbs = vec![0_u64; limbs_num];
+    for (limb, digit) in limbs.iter_mut().zip(el.get_ref_value().iter_u64_digits())
+    {
+      *limb = digit;
+    }
+    limbs
+  };
+  let from_limbs = |limbs : &[u64]| {
+    T::from_poly(BigUint::new(limbs.iter().flat_map(|limb| [*limb as u32, (limb >> 32) as u32]).collect()))
+  };
+  let (mut a_limbs, mut b_limbs) = (to_limbs(a), to_limbs(b));
+  for (a_limb, b_limb) in a_limbs.iter_mut().zip(b_limbs.iter_mut())
+  {
+    u64::conditional_swap(a_limb, b_limb, choice);
+  }
+  *a = from_limbs(&a_limbs);
+  *b = from_limbs(&b_limbs);
+}
+
+/// Function runs Montgomery ladder over $x$ coordinates in López–Dahab projective coordinates $x = X/Z$
+/// (Guide to ECC, algorithm 3.40). Pair $(P_1, P_2) = (jP, (j + 1)P)$ keeps difference $P$, so addition needs $x$ only:
+/// * $Z_3 = (X_1 Z_2 + X_2 Z_1)^2$, $X_3 = x Z_3 + X_1 Z_2 X_2 Z_1$;
+/// * $2(X, Z) = (X^4 + B Z^4, X^2 Z^2)$.
+///
+/// Ladder starts from $(O, P)$ with $O = (1 : 0)$ and `p` $= (X : Z)$ of $P$, so every one of `bits` lower bits of $k$
+/// is processed the same way and points are swapped with [conditional_swap_field_elements] instead of branching.
+/// Returns $(kP, (k + 1)P)$ as pairs $(X, Z)$.
+pub fn montgomery_ladder<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, p : (T, T), k : &BigUint, bits : u64) -> ((T, T), (T, T))
+{
+  let x = p.0.clone() * p.1.inverse();
+  let add = |x_1 : &T, z_1 : &T, x_2 : &T, z_2 : &T| {
+    let (t, u) = (x_1.clone() * z_2.clone(), x_2.clone() * z_1.clone());
+    let z = (t.clone() + u.clone()).square();
+    (x.clone() * z.clone() + t * u, z)
+  };
+  let double = |x_1 : &T, z_1 : &T| {
+    let (x_2, z_2) = (x_1.square(), z_1.square());
+    (x_2.square() + ec.b.clone() * z_2.square(), x_2 * z_2)
+  };
+  let (mut x_1, mut z_1) = (T::one(), T::zero());
+  let (mut x_2, mut z_2) = p;
+  let mut swap = Choice::from(0);
+  for i in (0 .. bits).rev()
+  {
+    let bit = Choice::from(k.bit(i) as u8);
+    swap ^= bit;
+    conditional_swap_field_elements(&mut x_1, &mut x_2, swap);
+    conditional_swap_field_elements(&mut z_1, &mut z_2, swap);
+    swap = bit;
+    (x_2, z_2) = add(&x_2, &z_2, &x_1, &z_1);
+    (x_1, z_1) = double(&x_1, &z_1);
+  }
+  conditional_swap_field_elements(&mut x_1, &mut x_2, swap);
+  conditional_swap_field_elements(&mut z_1, &mut z_2, swap);
+  ((x_1, z_1), (x_2, z_2))
+}
 
 /// Function packs point according to the algorithm `6.9`.
 pub fn pack_affine_point<'a, T : GFArithmetic<'a>>(point : &AffinePoint<T>) -> T
@@ -55,7 +122,170 @@ pub fn pack_affine_point<'a, T : GFArithmetic<'a>>(point : &AffinePoint<T>) -> T
 }
 
 /// Function unpacks compressed point from GF(2^m) field element according to the algorithm `6.10`.
+/// _Panics if the quadratic equation has no solution, use [try_unpack_affine_point] for untrusted input._
 pub fn unpack_affine_point<'a, T : GFArithmetic<'a>>(num : &T, ec : &BinaryEC<T>) -> AffinePoint<T>
+{
+  decompress_affine_point(num, ec).expect("packed point has to be a valid one")
+}
+
+/// Function unpacks compressed point from GF(2^m) field element according to the algorithm `6.10`
+/// and checks that the result is suitable as a public key, i.e. it belongs to the EC and has order $n$.
+/// Zero is the packed form of both $O_e$ and point $(0, \sqrt{B})$ of order 2, so it's rejected as infinity.
+pub fn try_unpack_affine_point<'a, T : GFArithmetic<'a>>(num : &T, ec : &BinaryEC<T>) -> Result<AffinePoint<T>>
+{
+  if num.is_zero()
+  {
+    return Err(EcError::PointIsInfinity);
+  }
+  let point = decompress_affine_point(num, ec).ok_or(EcError::NoSquareRoot)?;
+  if !ec.check_affine_point(&point)
+  {
+    return Err(EcError::PointNotOnCurve);
+  }
+  if !ec.is_in_prime_subgroup(&point)
+  {
+    return Err(EcError::WrongPointOrder(ec.get_ref_ord().to_str_radix(16)));
+  }
+  Ok(point)
+}
+
+/// Prefix of the point at infinity in SEC1 encoding.
+pub const SEC1_INFINITY_TAG : u8 = 0x00;
+/// Prefix of the compressed point in SEC1 encoding, the last bit stands for $\tilde{y}$.
+pub const SEC1_COMPRESSED_TAG : u8 = 0x02;
+/// Prefix of the uncompressed point in SEC1 encoding.
+pub const SEC1_UNCOMPRESSED_TAG : u8 = 0x04;
+
+/// Function encodes point according to the SEC1 (section `2.3.3`):
+/// * $O_e$ as `0x00`;
+/// * compressed point as `0x02 | y~` followed by $x$, where $\tilde{y}$ is the rightmost bit of $y \cdot x^{-1}$
+///   ($\tilde{y} = 0$ for $x = 0$);
+/// * uncompressed point as `0x04` followed by $x$ and $y$.
+///
+/// Field elements are encoded as big-endian octet strings of length $\lceil m/8 \rceil$.
+pub fn point_to_sec1<'a, T : GFArithmetic<'a>>(point : &AffinePoint<T>, compressed : bool) -> Vec<u8>
+{
+  match point
+  {
+    AffinePoint::Point { x, y } =>
+    {
+      let mut bytes = Vec::with_capacity(1 + 2 * field_el_len::<T>());
+      if compressed
+      {
+        let y_bit = !x.is_zero() && (y.clone() * x.inverse()).get_ref_value().bit(0);
+        bytes.push(SEC1_COMPRESSED_TAG | y_bit as u8);
+        bytes.extend(field_el_to_bytes(x));
+      }
+      else
+      {
+        bytes.push(SEC1_UNCOMPRESSED_TAG);
+        bytes.extend(field_el_to_bytes(x));
+        bytes.extend(field_el_to_bytes(y));
+      }
+      bytes
+    }
+    AffinePoint::Infinity => vec![SEC1_INFINITY_TAG],
+  }
+}
+
+/// Function decodes point from SEC1 encoding (section `2.3.4`) and checks that it belongs to the EC.
+/// Compressed point is restored from the solution $z$ of the $z^2 + z = x + A + Bx^{-2}$, $y = xz$,
+/// whose rightmost bit is equal to $\tilde{y}$. For $x = 0$ only $\tilde{y} = 0$ is accepted, as SEC1 requires.
+pub fn point_from_sec1<'a, T : GFArithmetic<'a>>(bytes : &[u8], ec : &BinaryEC<T>) -> Result<AffinePoint<T>>
+{
+  let len = field_el_len::<T>();
+  let (tag, data) = bytes
+    .split_first()
+    .ok_or(EcError::InvalidSec1Encoding("empty encoding".to_string()))?;
+  let expected_len = match *tag
+  {
+    SEC1_INFINITY_TAG => 0,
+    SEC1_UNCOMPRESSED_TAG => 2 * len,
+    tag if tag & !1 == SEC1_COMPRESSED_TAG => len,
+    tag => return Err(EcError::InvalidSec1Encoding(format!("unknown prefix: {tag:#04x}"))),
+  };
+  if data.len() != expected_len
+  {
+    return Err(EcError::InvalidSec1Encoding(format!(
+      "invalid length, desired: {}, got: {}",
+      expected_len + 1,
+      bytes.len()
+    )));
+  }
+  if *tag == SEC1_INFINITY_TAG
+  {
+    return Ok(AffinePoint::Infinity);
+  }
+  let x = field_el_from_bytes::<T>(&data[.. len])?;
+  let point = if *tag == SEC1_UNCOMPRESSED_TAG
+  {
+    AffinePoint::Point {
+      x,
+      y : field_el_from_bytes::<T>(&data[len ..])?,
+    }
+  }
+  else if x.is_zero()
+  {
+    if *tag != SEC1_COMPRESSED_TAG
+    {
+      return Err(EcError::InvalidSec1Encoding("y~ has to be 0 for x = 0".to_string()));
+    }
+    // Because prime poly has one bit more that ordinary number
+    let power = BigUint::one() << (T::get_m() - 1);
+    AffinePoint::Point { x, y : ec.b.pow(power) }
+  }
+  else
+  {
+    let beta = {
+      let x_inv = x.inverse();
+      x.clone() + ec.a.as_field_el() + ec.b.clone() * x_inv.square()
+    };
+    let z = solve_quadratic_equation_in_field::<T>(&T::one(), &beta)
+      .ok_or(EcError::NoSquareRoot)?
+      .0;
+    let z = if z.get_ref_value().bit(0) == (tag & 1 == 1)
+    {
+      z
+    }
+    else
+    {
+      z + T::one()
+    };
+    AffinePoint::Point { y : z * x.clone(), x }
+  };
+  if !ec.check_affine_point(&point)
+  {
+    return Err(EcError::PointNotOnCurve);
+  }
+  Ok(point)
+}
+
+/// Function calculates length of the field element encoding in bytes: $\lceil m/8 \rceil$.
+fn field_el_len<'a, T : GFArithmetic<'a>>() -> usize { T::get_m().div_ceil(8) as usize }
+
+fn field_el_to_bytes<'a, T : GFArithmetic<'a>>(el : &T) -> Vec<u8>
+{
+  let value = el.get_ref_value().to_bytes_be();
+  let mut bytes = vec![0_u8; field_el_len::<T>() - value.len()];
+  bytes.extend(value);
+  bytes
+}
+
+fn field_el_from_bytes<'a, T : GFArithmetic<'a>>(bytes : &[u8]) -> Result<T>
+{
+  let value = BigUint::from_bytes_be(bytes);
+  if value.bits() > T::get_m() as u64
+  {
+    return Err(EcError::InvalidSec1Encoding(
+      "field element exceeds the field size".to_string(),
+    ));
+  }
+  Ok(T::from_poly(value))
+}
+
+/// Function restores point from the packed form according to the algorithm `6.10`,
+/// returns `None` if the quadratic equation has no solution.
+pub(crate) fn decompress_affine_point<'a, T : GFArithmetic<'a>>(num : &T, ec : &BinaryEC<T>) -> Option<AffinePoint<T>>
 {
   let mut x_p = num.get_value();
   if x_p.is_zero()
@@ -63,10 +293,10 @@ pub fn unpack_affine_point<'a, T : GFArithmetic<'a>>(num : &T, ec : &BinaryEC<T>
     // Because prime poly has one bit more that ordinary number
     let power = num.get_prime_poly().bits() - 1 - 1;
     let power = BigUint::one() << power;
-    return AffinePoint::Point {
+    return Some(AffinePoint::Point {
       x : T::zero(),
       y : ec.b.pow(power),
-    };
+    });
   }
   let k = {
     if (&x_p & BigUint::one()).is_one()
@@ -95,8 +325,7 @@ pub fn unpack_affine_point<'a, T : GFArithmetic<'a>>(num : &T, ec : &BinaryEC<T>
     let x_p_inv = x_p_field.inverse();
     x_p_inv.square()
   };
-  // Anyway we'll have 2 roots from the equation
-  let z = solve_quadratic_equation_in_field::<T>(&T::one(), &(w * x_p_inv_2)).unwrap().0;
+  let z = solve_quadratic_equation_in_field::<T>(&T::one(), &(w * x_p_inv_2))?.0;
   let y_p = if z.trace() == k
   {
     z * x_p_field.clone()
@@ -105,7 +334,7 @@ pub fn unpack_affine_point<'a, T : GFArithmetic<'a>>(num : &T, ec : &BinaryEC<T>
   {
     (z + BigUint::one()) * x_p_field.clone()
   };
-  AffinePoint::Point { x : x_p_field, y : y_p }
+  Some(AffinePoint::Point { x : x_p_field, y : y_p })
 }
 
 /// Function generates affine point by generating one point and solving quadratic equation according to the algorithm `6.8`.
@@ -127,3 +356,213 @@ pub fn generate_random_affine_point<'a, T : GFArithmetic<'a>>(rng : &mut impl Cr
     }
   }
 }
+
+/// Function performs multiplication of the point on number without reduction of the number by the order,
+/// so it can be used for checking the order itself.
+pub(crate) fn mul_affine_point<'a, T : GFArithmetic<'a>>(
+  point : &AffinePoint<T>,
+  ec : &BinaryEC<T>,
+  n : &BigUint,
+) -> AffinePoint<T>
+{
+  let mut r = AffinePoint::neutral();
+  let mut tmp = point.clone();
+  // from LSB to MSB
+  for i in 0 .. n.bits()
+  {
+    if n.bit(i)
+    {
+      r = r.add(ec, &tmp)
+    }
+    tmp = tmp.double(ec)
+  }
+  r
+}
+
+/// Function inverts every element of the slice with usage of single field inversion (Montgomery's trick):
+/// prefix products $c_i = a_0 \cdots a_i$ are accumulated, $c_{k-1}^{-1}$ is calculated and then unwound back.
+/// _All elements have to be nonzero._
+pub fn batch_inverse<'a, T : GFArithmetic<'a>>(elements : &[T]) -> Vec<T>
+{
+  if elements.is_empty()
+  {
+    return Vec::new();
+  }
+  let mut prefix = Vec::with_capacity(elements.len());
+  let mut acc = T::one();
+  for el in elements
+  {
+    acc = acc * el.clone();
+    prefix.push(acc.clone());
+  }
+  let mut acc_inv = acc.inverse();
+  let mut inverted = vec![T::zero(); elements.len()];
+  for i in (1 .. elements.len()).rev()
+  {
+    inverted[i] = acc_inv.clone() * prefix[i - 1].clone();
+    acc_inv = acc_inv * elements[i].clone();
+  }
+  inverted[0] = acc_inv;
+  inverted
+}
+
+/// Kind of operation that is performed on the pair of points in [batch_add_affine_points].
+enum BatchOp<T>
+{
+  /// Result is already known and doesn't require inversion.
+  Ready(AffinePoint<T>),
+  /// $P + Q$, where $x_P \ne x_Q$, denominator is $x_P + x_Q$.
+  Add,
+  /// $2P$, where $x_P \ne 0$, denominator is $x_P$.
+  Double,
+}
+
+/// Function adds points pairwise with the same formulas as [AffinePoint::add] and [AffinePoint::double],
+/// but denominators of all $\lambda$'s are inverted at once with help of [batch_inverse].
+pub fn batch_add_affine_points<'a, T : GFArithmetic<'a>>(
+  ec : &BinaryEC<T>,
+  pairs : &[(AffinePoint<T>, AffinePoint<T>)],
+) -> Vec<AffinePoint<T>>
+{
+  let mut denominators = Vec::with_capacity(pairs.len());
+  let ops = pairs
+    .iter()
+    .map(|(p, q)| match (p, q)
+    {
+      (AffinePoint::Infinity, _) => BatchOp::Ready(q.clone()),
+      (_, AffinePoint::Infinity) => BatchOp::Ready(p.clone()),
+      (AffinePoint::Point { x: x_p, y: y_p }, AffinePoint::Point { x: x_q, y: y_q }) =>
+      {
+        if x_p != x_q
+        {
+          denominators.push(x_p.clone() + x_q.clone());
+          BatchOp::Add
+        }
+        else if y_p != y_q || x_p.is_zero()
+        {
+          // $Q = -P$ or $P = Q$ has order 2
+          BatchOp::Ready(AffinePoint::Infinity)
+        }
+        else
+        {
+          denominators.push(x_p.clone());
+          BatchOp::Double
+        }
+      }
+    })
+    .collect::<Vec<_>>();
+  let mut inverted = batch_inverse(&denominators).into_iter();
+  pairs
+    .iter()
+    .zip(ops)
+    .map(|((p, q), op)| match (op, p, q)
+    {
+      (BatchOp::Ready(r), _, _) => r,
+      (BatchOp::Add, AffinePoint::Point { x: x_p, y: y_p }, AffinePoint::Point { x: x_q, y: y_q }) =>
+      {
+        let lambda = (y_p.clone() + y_q.clone()) * inverted.next().unwrap();
+        let x_r = lambda.square() + lambda.clone() + x_p.clone() + x_q.clone() + ec.a.as_field_el();
+        let y_r = lambda * (x_p.clone() + x_r.clone()) + x_r.clone() + y_p.clone();
+        AffinePoint::Point { x : x_r, y : y_r }
+      }
+      (BatchOp::Double, AffinePoint::Point { x: x_p, y: y_p }, _) =>
+      {
+        // $\lambda = x_P + y_P / x_P$
+        let lambda = x_p.clone() + y_p.clone() * inverted.next().unwrap();
+        let x_r = lambda.square() + lambda.clone() + ec.a.as_field_el();
+        let y_r = x_p.square() + (lambda + T::one()) * x_r.clone();
+        AffinePoint::Point { x : x_r, y : y_r }
+      }
+      _ => unreachable!("operation that requires inversion is chosen only for finite points"),
+    })
+    .collect()
+}
+
+/// Function converts points from the López–Dahab coordinates into the affine ones,
+/// where all $Z$ coordinates are inverted at once with help of [batch_inverse].
+pub fn batch_normalize_projective_points<'a, T : GFArithmetic<'a>>(points : &[ProjectivePoint<T>]) -> Vec<AffinePoint<T>>
+{
+  let z_coordinates = points.iter().filter(|p| !p.is_inf()).map(|p| p.z.clone()).collect::<Vec<_>>();
+  let mut z_inverted = batch_inverse(&z_coordinates).into_iter();
+  points
+    .iter()
+    .map(|p| {
+      if p.is_inf()
+      {
+        return AffinePoint::Infinity;
+      }
+      let z_inv = z_inverted.next().unwrap();
+      AffinePoint::Point {
+        x : p.x.clone() * z_inv.clone(),
+        y : p.y.clone() * z_inv.square(),
+      }
+    })
+    .collect()
+}
+
+/// Small primes that are used as bases in the Miller–Rabin test.
+const MILLER_RABIN_BASES : [u32; 24] = [
+  2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
+];
+
+/// Function checks whether number is probably prime with help of the Miller–Rabin test,
+/// where first prime numbers are taken as bases.
+pub fn is_probably_prime(n : &BigUint) -> bool
+{
+  let two = BigUint::from(2_u8);
+  if *n < two
+  {
+    return false;
+  }
+  for p in MILLER_RABIN_BASES
+  {
+    if *n == BigUint::from(p)
+    {
+      return true;
+    }
+    if (n % p).is_zero()
+    {
+      return false;
+    }
+  }
+  // $n - 1 = 2^s \cdot d$
+  let n_minus_one = n - BigUint::one();
+  let s = n_minus_one.trailing_zeros().unwrap();
+  let d = &n_minus_one >> s;
+  'bases: for p in MILLER_RABIN_BASES
+  {
+    let mut x = BigUint::from(p).modpow(&d, n);
+    if x.is_one() || x == n_minus_one
+    {
+      continue;
+    }
+    for _ in 1 .. s
+    {
+      x = x.modpow(&two, n);
+      if x == n_minus_one
+      {
+        continue 'bases;
+      }
+    }
+    return false;
+  }
+  true
+}
+
+/// Function finds the smallest $k \le bound$, such that $2^{mk} \equiv 1 \mod n$, i.e. embedding degree of the
+/// subgroup of order $n$ in EC over GF(2^m).
+pub fn find_embedding_degree(n : &BigUint, m : u32, bound : u32) -> Option<u32>
+{
+  let q = BigUint::one() << m;
+  let q = q % n;
+  let mut t = BigUint::one();
+  for k in 1 ..= bound
+  {
+    t = (t * &q) % n;
+    if t.is_one()
+    {
+      return Some(k);
+    }
+  }
+  None
+}
@@ -0,0 +1,299 @@
+//! Arithmetic in $\mathbb{Z}\[\tau\]$ for the anomalous binary (Koblitz) curves $y^2 + xy = x^3 + Ax^2 + 1$,
+//! where $\tau$ is the Frobenius endomorphism $(x, y) \to (x^2, y^2)$ that satisfies
+//! $\tau^2 = \mu\tau - 2$, $\mu = (-1)^{1 - A}$.
+//!
+//! Algorithms are taken from the «Guide to Elliptic Curve Cryptography» (Hankerson, Menezes, Vanstone), section `3.4`.
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Signed, Zero};
+use crate::binary_ec::ACoefficient;
+
+/// Element of $\mathbb{Z}\[\tau\]$ that is represented as $r_0 + r_1\tau$.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TauElement
+{
+  pub r0 : BigInt,
+  pub r1 : BigInt,
+}
+
+impl TauElement
+{
+  pub fn new<N : Into<BigInt>, M : Into<BigInt>>(r0 : N, r1 : M) -> Self
+  {
+    TauElement {
+      r0 : r0.into(),
+      r1 : r1.into(),
+    }
+  }
+
+  pub fn is_zero(&self) -> bool { self.r0.is_zero() && self.r1.is_zero() }
+
+  /// Function calculates norm of the element: $N(r_0 + r_1\tau) = r_0^2 + \mu r_0 r_1 + 2r_1^2$.
+  pub fn norm(&self, mu : i8) -> BigInt { &self.r0 * &self.r0 + mu * &self.r0 * &self.r1 + 2 * &self.r1 * &self.r1 }
+
+  /// Function calculates complex conjugate of the element: $\overline{\tau} = \mu - \tau$.
+  pub fn conjugate(&self, mu : i8) -> Self { TauElement::new(&self.r0 + mu * &self.r1, -&self.r1) }
+
+  pub fn add(&self, other : &Self) -> Self { TauElement::new(&self.r0 + &other.r0, &self.r1 + &other.r1) }
+
+  pub fn sub(&self, other : &Self) -> Self { TauElement::new(&self.r0 - &other.r0, &self.r1 - &other.r1) }
+
+  /// Function multiplies two elements with usage of $\tau^2 = \mu\tau - 2$.
+  pub fn mul(&self, other : &Self, mu : i8) -> Self
+  {
+    let r1r1 = &self.r1 * &other.r1;
+    TauElement::new(
+      &self.r0 * &other.r0 - 2 * &r1r1,
+      &self.r0 * &other.r1 + &self.r1 * &other.r0 + mu * r1r1,
+    )
+  }
+
+  /// Function multiplies element on $\tau$: $(r_0 + r_1\tau)\tau = -2r_1 + (r_0 + \mu r_1)\tau$.
+  pub fn mul_tau(&self, mu : i8) -> Self { TauElement::new(-2 * &self.r1, &self.r0 + mu * &self.r1) }
+
+  /// Function restores element from its TNAF (digits go from the least significant one).
+  pub fn from_tnaf(digits : &[i8], mu : i8) -> Self
+  {
+    digits
+      .iter()
+      .rev()
+      .fold(TauElement::new(0, 0), |acc, u| acc.mul_tau(mu).add(&TauElement::new(*u, 0)))
+  }
+}
+
+/// Function calculates $\mu = (-1)^{1 - A}$ for $A \in \{0, 1\}$.
+pub fn get_mu<T>(a : &ACoefficient<T>) -> i8
+{
+  match a
+  {
+    ACoefficient::Zero(_) => -1,
+    ACoefficient::One(_) => 1,
+  }
+}
+
+/// Function calculates Lucas sequence $U_0 = 0$, $U_1 = 1$, $U_{k+1} = \mu U_k - 2U_{k-1}$,
+/// returns pair $(U_{k-1}, U_k)$ for $k \ge 1$.
+pub fn lucas_sequence(mu : i8, k : u32) -> (BigInt, BigInt)
+{
+  assert!(k >= 1, "Lucas sequence index has to be positive");
+  let (mut u_prev, mut u) = (BigInt::zero(), BigInt::one());
+  for _ in 1 .. k
+  {
+    let u_next = mu * &u - 2 * &u_prev;
+    u_prev = u;
+    u = u_next;
+  }
+  (u_prev, u)
+}
+
+/// Function calculates $\tau^k = U_k\tau - 2U_{k-1}$.
+pub fn tau_pow(mu : i8, k : u32) -> TauElement
+{
+  if k == 0
+  {
+    return TauElement::new(1, 0);
+  }
+  let (u_prev, u) = lucas_sequence(mu, k);
+  TauElement::new(-2 * u_prev, u)
+}
+
+/// Function calculates $\delta = (\tau^m - 1)/(\tau - 1)$, whose norm is equal to the
+/// $\#E(GF(2^m))/\#E(GF(2))$.
+pub fn get_delta(mu : i8, m : u32) -> TauElement
+{
+  let numerator = tau_pow(mu, m).sub(&TauElement::new(1, 0));
+  let denominator = TauElement::new(-1, 1);
+  let norm = denominator.norm(mu);
+  let t = numerator.mul(&denominator.conjugate(mu), mu);
+  TauElement::new(t.r0 / &norm, t.r1 / &norm)
+}
+
+/// Function performs rounding of the $\lambda = \lambda_0 + \lambda_1\tau$, where $\lambda_i = n_i/d$,
+/// to the element of $\mathbb{Z}\[\tau\]$ that is the closest in the norm according to the algorithm `3.63`.
+fn round(n0 : &BigInt, n1 : &BigInt, d : &BigInt, mu : i8) -> TauElement
+{
+  // $f_i = \lfloor\lambda_i + 1/2\rfloor$
+  let f0 = floor_div(&(2 * n0 + d), &(2 * d));
+  let f1 = floor_div(&(2 * n1 + d), &(2 * d));
+  // $\eta_i = e_i / d$
+  let e0 = n0 - &f0 * d;
+  let e1 = n1 - &f1 * d;
+  let (mut h0, mut h1) = (BigInt::zero(), BigInt::zero());
+  let eta = 2 * &e0 + mu * &e1;
+  let eta_3 = &e0 - 3 * mu * &e1;
+  let eta_4 = &e0 + 4 * mu * &e1;
+  if eta >= *d
+  {
+    if eta_3 < -d
+    {
+      h1 = BigInt::from(mu);
+    }
+    else
+    {
+      h0 = BigInt::one();
+    }
+  }
+  else if eta_4 >= 2 * d
+  {
+    h1 = BigInt::from(mu);
+  }
+  if eta < -d
+  {
+    if eta_3 >= *d
+    {
+      h1 = BigInt::from(-mu);
+    }
+    else
+    {
+      h0 = -BigInt::one();
+    }
+  }
+  else if eta_4 < -2 * d
+  {
+    h1 = BigInt::from(-mu);
+  }
+  TauElement::new(f0 + h0, f1 + h1)
+}
+
+/// Function calculates $\rho = k$ _partmod_ $\delta$, which is the element of the small norm, so that $\rho P = kP$
+/// for every point $P$ of the main subgroup. Reduction is performed with the help of exact division and rounding.
+pub fn partial_reduction(k : &BigUint, mu : i8, m : u32) -> TauElement
+{
+  let delta = get_delta(mu, m);
+  let norm = delta.norm(mu);
+  let k = BigInt::from(k.clone());
+  let conj = delta.conjugate(mu);
+  let q = round(&(&k * &conj.r0), &(&k * &conj.r1), &norm, mu);
+  TauElement::new(k, 0).sub(&delta.mul(&q, mu))
+}
+
+/// Function calculates TNAF of the element according to the algorithm `3.61`.
+/// Digits are from the set $\{-1, 0, 1\}$ and go from the least significant one.
+pub fn tnaf(r : &TauElement, mu : i8) -> Vec<i8>
+{
+  let (mut r0, mut r1) = (r.r0.clone(), r.r1.clone());
+  let mut digits = Vec::new();
+  while !r0.is_zero() || !r1.is_zero()
+  {
+    let u = if r0.bit(0)
+    {
+      let u = 2 - mod_pow2(&(&r0 - 2 * &r1), 2);
+      r0 -= u;
+      u
+    }
+    else
+    {
+      0
+    };
+    digits.push(u as i8);
+    (r0, r1) = divide_by_tau(r0, r1, mu);
+  }
+  digits
+}
+
+/// Function calculates representatives $\alpha_u = u \mod \tau^w$ for odd $u \in \[1, 2^{w-1} - 1\]$,
+/// that are used as digits in the width-w TNAF. Element $\alpha_u$ is stored under the index $(u - 1)/2$.
+pub fn wtnaf_representatives(mu : i8, w : u8) -> Vec<TauElement>
+{
+  let tau_w = tau_pow(mu, w as u32);
+  let norm = tau_w.norm(mu);
+  let conj = tau_w.conjugate(mu);
+  (1_i64 .. 1 << (w - 1))
+    .step_by(2)
+    .map(|u| {
+      let u = BigInt::from(u);
+      let q = round(&(&u * &conj.r0), &(&u * &conj.r1), &norm, mu);
+      TauElement::new(u, 0).sub(&tau_w.mul(&q, mu))
+    })
+    .collect()
+}
+
+/// Function calculates width-w TNAF of the element according to the algorithm `3.69`.
+/// Digit $u$ stands for $\pm\alpha_{|u|}$ from the [wtnaf_representatives], digits go from the least significant one.
+/// Returns `None` when width isn't in range $\[2, 8\]$.
+pub fn wtnaf(r : &TauElement, mu : i8, w : u8) -> Option<Vec<i8>>
+{
+  if !(2 ..= 8).contains(&w)
+  {
+    return None;
+  }
+  let alphas = wtnaf_representatives(mu, w);
+  // $t_w = 2U_{w-1}U_w^{-1} \mod 2^w$
+  let t_w = {
+    let (u_prev, u) = lucas_sequence(mu, w as u32);
+    mod_pow2(&(2 * u_prev * inverse_mod_pow2(&u, w)), w)
+  };
+  let (mut r0, mut r1) = (r.r0.clone(), r.r1.clone());
+  let mut digits = Vec::new();
+  while !r0.is_zero() || !r1.is_zero()
+  {
+    let u = if r0.bit(0)
+    {
+      let mut u = mod_pow2(&(&r0 + &r1 * t_w), w);
+      if u >= 1 << (w - 1)
+      {
+        u -= 1 << w;
+      }
+      let alpha = &alphas[(u.unsigned_abs() as usize - 1) / 2];
+      if u > 0
+      {
+        r0 -= &alpha.r0;
+        r1 -= &alpha.r1;
+      }
+      else
+      {
+        r0 += &alpha.r0;
+        r1 += &alpha.r1;
+      }
+      u
+    }
+    else
+    {
+      0
+    };
+    digits.push(u as i8);
+    (r0, r1) = divide_by_tau(r0, r1, mu);
+  }
+  Some(digits)
+}
+
+/// Function divides $r_0 + r_1\tau$ by $\tau$, $r_0$ has to be even.
+fn divide_by_tau(r0 : BigInt, r1 : BigInt, mu : i8) -> (BigInt, BigInt)
+{
+  let half : BigInt = r0 / 2;
+  (r1 + mu * &half, -half)
+}
+
+/// Function calculates $a \mod 2^w$ that is always in range $\[0, 2^w)$.
+fn mod_pow2(a : &BigInt, w : u8) -> i64
+{
+  let modulus = BigInt::one() << w;
+  let r = a % &modulus;
+  let r = if r.is_negative() { r + modulus } else { r };
+  r.try_into().unwrap()
+}
+
+/// Function calculates inverse of the odd number modulo $2^w$ with the help of Newton's iteration.
+fn inverse_mod_pow2(a : &BigInt, w : u8) -> i64
+{
+  let a = mod_pow2(a, w);
+  let mut x = 1_i64;
+  for _ in 0 .. w
+  {
+    x = (x * (2 - a * x)).rem_euclid(1 << w);
+  }
+  x
+}
+
+/// Function calculates $\lfloor a/b \rfloor$ for $b > 0$.
+fn floor_div(a : &BigInt, b : &BigInt) -> BigInt
+{
+  let q = a / b;
+  if a.is_negative() && &q * b != *a
+  {
+    q - 1
+  }
+  else
+  {
+    q
+  }
+}
@@ -1,4 +1,19 @@
 #![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
 pub mod affine_point;
+pub mod base_point_table;
 pub mod binary_ec;
+pub mod binary_edwards;
+pub mod countermeasures;
+pub mod curve_point;
+pub mod ecdlp;
+pub mod error;
+pub mod hash_to_curve;
 pub mod helpers;
+pub mod koblitz;
+pub mod point_counting;
+#[cfg(feature = "group")]
+pub mod preset_group;
+pub mod projective_point;
+pub mod registry;
+pub mod toy_curves;
+pub mod twist;
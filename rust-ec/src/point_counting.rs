@@ -0,0 +1,237 @@
+//! Point counting on the ordinary EC $y^2 + xy = x^3 + Ax^2 + B$ over GF(2^m) with help of the
+//! arithmetic-geometric mean (Mestre's AGM algorithm).
+//!
+//! Computations are performed in the ring $\mathbb{Z}_q = \mathbb{Z}\[t\]/(F(t))$ modulo $2^w$, where $F(t)$ is the
+//! prime polynomial of the field lifted to integers. Elements are stored as vectors of $m$ coefficients and
+//! multiplied with help of Kronecker substitution, i.e. as a single big integer.
+//!
+//! Brute-force cross-checks use small fields `GF5` … `GF59` from the `toy-fields` feature, which are defined
+//! at compile time like the other fields, since [GFArithmetic] has no runtime-defined field.
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+use poly_algebra::gf::GFArithmetic;
+use crate::binary_ec::ACoefficient;
+
+/// Function counts number of points $\#E$ (including $O_e$) on the EC $y^2 + xy = x^3 + Ax^2 + B$ over GF(2^m).
+/// Trace of Frobenius $t$ is found modulo $2^{\lceil m/2 \rceil + 2}$ with help of AGM and then it's
+/// uniquely restored from the Hasse bound $|t| \le 2\sqrt{2^m}$, so $\#E = 2^m + 1 - t$.
+/// _Coefficient $B$ has to be nonzero, i.e. EC has to be ordinary._
+pub fn count_points<'a, T : GFArithmetic<'a>>(a : &ACoefficient<T>, b : &T) -> BigUint
+{
+  assert!(!b.is_zero(), "coefficient B has to be nonzero");
+  let m = T::get_m();
+  let q : BigInt = BigInt::one() << m;
+  // Precision of the trace and working precision of the ring
+  let n = m.div_ceil(2) + 2;
+  let w = n as u64 + 3;
+  let ring = QuotientRing::new(&b.get_prime_poly());
+
+  // $A_0 = 1 + 8B$, $B_0 = 1$
+  let mut a_k = ring.one();
+  for (i, coefficient) in a_k.iter_mut().enumerate()
+  {
+    if b.get_ref_value().bit(i as u64)
+    {
+      *coefficient += 8_u8;
+    }
+  }
+  let mut b_k = ring.one();
+  for _ in 0 ..= n
+  {
+    (a_k, b_k) = ring.agm_step(&a_k, &b_k, w);
+  }
+  let a_start = a_k.clone();
+  for _ in 0 .. m
+  {
+    (a_k, b_k) = ring.agm_step(&a_k, &b_k, w);
+  }
+  let quotient = ring.mul(&a_start, &ring.inverse::<T>(&a_k, w), w);
+
+  let modulus = BigInt::one() << n;
+  let mut t = BigInt::from(quotient[0].clone()) % &modulus;
+  if &t * &t > 4 * &q
+  {
+    t -= modulus;
+  }
+  // AGM gives trace for the EC with $Tr(A) = 0$, EC with $Tr(A) = 1$ is its quadratic twist
+  if !a.as_field_el().trace().is_zero()
+  {
+    t = -t;
+  }
+  (q + BigInt::one() - t).to_biguint().unwrap()
+}
+
+/// Ring $\mathbb{Z}\[t\]/(F(t))$ modulo $2^w$, where $F(t) = t^m + \sum t^{r_i}$.
+/// Precision $w$ is passed into every operation, so Newton's iterations can be performed with the increasing precision.
+struct QuotientRing
+{
+  m : usize,
+  r_degs : Vec<usize>,
+}
+
+impl QuotientRing
+{
+  fn new(prime_poly : &BigUint) -> Self
+  {
+    let m = prime_poly.bits() as usize - 1;
+    let r_degs = (0 .. m).filter(|deg| prime_poly.bit(*deg as u64)).collect();
+    QuotientRing { m, r_degs }
+  }
+
+  fn one(&self) -> Vec<BigUint>
+  {
+    let mut one = vec![BigUint::zero(); self.m];
+    one[0] = BigUint::one();
+    one
+  }
+
+  /// Function performs one step of AGM: $(A, B) \to ((A + B)/2, \sqrt{AB})$.
+  fn agm_step(&self, a : &[BigUint], b : &[BigUint], w : u64) -> (Vec<BigUint>, Vec<BigUint>)
+  {
+    let half_sum = a.iter().zip(b).map(|(x, y)| truncate((x + y) >> 1, w)).collect();
+    (half_sum, self.sqrt(&self.mul(a, b, w), w))
+  }
+
+  fn mul(&self, a : &[BigUint], b : &[BigUint], w : u64) -> Vec<BigUint>
+  {
+    // Product of two polynomials has coefficients less than $m \cdot 2^{2w}$, so it's the size of one slot
+    let slot = (2 * w + 64 - (self.m as u64).leading_zeros() as u64).div_ceil(32) as usize;
+    let product = pack(a, slot, w) * pack(b, slot, w);
+    let digits = product.to_u32_digits();
+    let mut c = digits
+      .chunks(slot)
+      .map(|chunk| truncate(BigUint::from_slice(chunk), w))
+      .collect::<Vec<_>>();
+    c.resize(2 * self.m - 1, BigUint::zero());
+    // $t^m = -\sum t^{r_i}$
+    let modulus = BigUint::one() << w;
+    for k in (self.m .. 2 * self.m - 1).rev()
+    {
+      let high = truncate(std::mem::take(&mut c[k]), w);
+      if high.is_zero()
+      {
+        continue;
+      }
+      let negative = &modulus - high;
+      for deg in self.r_degs.iter()
+      {
+        c[k - self.m + deg] += &negative;
+      }
+    }
+    c.truncate(self.m);
+    c.into_iter().map(|x| truncate(x, w)).collect()
+  }
+
+  /// Function calculates $1 - a$.
+  fn one_minus(&self, a : &[BigUint], w : u64) -> Vec<BigUint>
+  {
+    let modulus = BigUint::one() << w;
+    let mut r = a.iter().map(|x| truncate(&modulus - x, w)).collect::<Vec<_>>();
+    r[0] = truncate(&r[0] + 1_u8, w);
+    r
+  }
+
+  /// Function calculates square root of element $a \equiv 1 \mod 8$ as $a \cdot a^{-1/2}$,
+  /// where inverse square root is found with Newton's iteration $y \to y + y(1 - ay^2)/2$.
+  fn sqrt(&self, a : &[BigUint], w : u64) -> Vec<BigUint>
+  {
+    let mut y = self.one();
+    // Each iteration almost doubles the precision, starting from 2 bits
+    let mut precision = 2;
+    while precision < w
+    {
+      precision = (2 * precision - 1).min(w);
+      // One extra bit is lost in the division by 2
+      let p = (precision + 1).min(w);
+      let e = self.one_minus(&self.mul(a, &self.mul(&y, &y, p), p), p);
+      let correction = self.mul(&y, &e, p);
+      y = y
+        .iter()
+        .zip(correction.iter())
+        .map(|(y, c)| truncate(y + (c >> 1), p))
+        .collect();
+    }
+    self.mul(a, &y, w)
+  }
+
+  /// Function calculates inverse of the element with Newton's iteration $y \to y(2 - ay)$,
+  /// starting from the inverse in the field GF(2^m).
+  fn inverse<'a, T : GFArithmetic<'a>>(&self, a : &[BigUint], w : u64) -> Vec<BigUint>
+  {
+    let reduced = a
+      .iter()
+      .enumerate()
+      .filter(|(_, x)| x.bit(0))
+      .fold(BigUint::zero(), |acc, (i, _)| acc | (BigUint::one() << i));
+    let inverse = T::from_poly(reduced).inverse().get_value();
+    let mut y = (0 .. self.m)
+      .map(|i| {
+        if inverse.bit(i as u64)
+        {
+          BigUint::one()
+        }
+        else
+        {
+          BigUint::zero()
+        }
+      })
+      .collect::<Vec<_>>();
+    let mut precision = 1;
+    while precision < w
+    {
+      precision = (2 * precision).min(w);
+      let e = self.one_minus(&self.mul(a, &y, precision), precision);
+      // $y(2 - ay) = y + y(1 - ay)$
+      let correction = self.mul(&y, &e, precision);
+      y = y
+        .iter()
+        .zip(correction.iter())
+        .map(|(y, c)| truncate(y + c, precision))
+        .collect();
+    }
+    y
+  }
+}
+
+/// Function packs polynomial into one number with $2^{32 \cdot slot}$ base, coefficients are reduced modulo $2^w$.
+fn pack(a : &[BigUint], slot : usize, w : u64) -> BigUint
+{
+  let mut digits = vec![0_u32; slot * a.len()];
+  let len = w.div_ceil(32) as usize;
+  for (i, x) in a.iter().enumerate()
+  {
+    for (j, digit) in x.iter_u32_digits().take(len).enumerate()
+    {
+      digits[i * slot + j] = digit;
+    }
+  }
+  truncate_digits(&mut digits, slot, w);
+  BigUint::new(digits)
+}
+
+/// Function masks the highest digit in every slot, so coefficients are reduced modulo $2^w$.
+fn truncate_digits(digits : &mut [u32], slot : usize, w : u64)
+{
+  let (len, rem) = ((w / 32) as usize, (w % 32) as u32);
+  for chunk in digits.chunks_mut(slot)
+  {
+    if len < chunk.len()
+    {
+      chunk[len] &= (1_u32 << rem).wrapping_sub(1);
+      chunk[len + 1 ..].iter_mut().for_each(|d| *d = 0);
+    }
+  }
+}
+
+/// Function calculates $x \mod 2^w$.
+fn truncate(x : BigUint, w : u64) -> BigUint
+{
+  if x.bits() <= w
+  {
+    return x;
+  }
+  let mut digits = x.to_u32_digits();
+  digits.truncate(w.div_ceil(32) as usize);
+  truncate_digits(&mut digits, usize::MAX, w);
+  BigUint::new(digits)
+}
@@ -0,0 +1,642 @@
+//! Implementation of the [group::Group] and [group::Curve] for points of the EC presets from DSTU 4145-2002,
+//! that is enabled with feature `group`, so crates, which are generic over `ff` and `group` traits, can use them.
+//!
+//! Traits require [Copy] elements and constant parameters, so the curve is chosen by the field `T` with
+//! [DstuPresetField]: [PresetScalar] is an element of $\mathbb{Z}_n$ and [PresetPoint] is a point of the subgroup
+//! of big prime order $n$. Both keep values in the fixed number of 64-bit limbs, arithmetic is delegated
+//! to [BigUint] and [ProjectivePoint] on the EC from [DstuPresetField::preset], that is generated once per field,
+//! so _it isn't constant time_.
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::fmt;
+use std::fmt::Formatter;
+use ff::helpers::{sqrt_ratio_generic, sqrt_tonelli_shanks};
+use ff::{Field, PrimeField};
+use group::{Curve, Group};
+use num_bigint::BigUint;
+use num_traits::One;
+use poly_algebra::ff_field::{biguint_to_limbs, limbs_to_biguint, FieldElement, FIELD_ELEMENT_LIMBS};
+use poly_algebra::gf::{GFArithmetic, GF163, GF167, GF173, GF179, GF191, GF233, GF257, GF367};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::BinaryEC;
+use crate::error::{EcError, Result};
+use crate::projective_point::ProjectivePoint;
+use crate::registry::DstuPresetField;
+
+/// Number of 64-bit limbs in the [PresetScalar], that is enough for the order of the biggest preset.
+pub const SCALAR_LIMBS : usize = FIELD_ELEMENT_LIMBS;
+
+/// Little-endian bytes of the [PresetScalar], that are used as [PrimeField::Repr].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ScalarRepr(pub [u8; 8 * SCALAR_LIMBS]);
+
+impl Default for ScalarRepr
+{
+  fn default() -> Self { ScalarRepr([0; 8 * SCALAR_LIMBS]) }
+}
+
+impl AsRef<[u8]> for ScalarRepr
+{
+  fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl AsMut<[u8]> for ScalarRepr
+{
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.0 }
+}
+
+/// Constants of the [PrimeField] for the order $n$ of the preset over the field, numbers are in hex.
+/// They are precomputed from the factorization of $n - 1$: multiplicative generator $g$ has order $n - 1$,
+/// $n - 1 = 2^S t$ with odd $t$, root of unity is $g^t$ and $\delta = g^{2^S}$.
+/// _Presets over [GF307](poly_algebra::gf::GF307) and [GF431](poly_algebra::gf::GF431) aren't supported,
+/// because $n - 1$ has unfactorized composite parts of 283 and 418 bits respectively, so the generator can't be verified._
+pub trait PresetScalarParams: DstuPresetField
+{
+  const MODULUS : &'static str;
+  const MULTIPLICATIVE_GENERATOR : u64;
+  const S : u32;
+  const ROOT_OF_UNITY : &'static str;
+  const ROOT_OF_UNITY_INV : &'static str;
+  const DELTA : &'static str;
+}
+
+/// Macro implements [PresetScalarParams] for the field.
+macro_rules! impl_preset_scalar_params {
+  ($gf:ident, $modulus:expr, $generator:expr, $s:expr, $root_of_unity:expr, $root_of_unity_inv:expr, $delta:expr) => {
+    impl PresetScalarParams for $gf
+    {
+      const MODULUS : &'static str = $modulus;
+      const MULTIPLICATIVE_GENERATOR : u64 = $generator;
+      const S : u32 = $s;
+      const ROOT_OF_UNITY : &'static str = $root_of_unity;
+      const ROOT_OF_UNITY_INV : &'static str = $root_of_unity_inv;
+      const DELTA : &'static str = $delta;
+    }
+  };
+}
+
+impl_preset_scalar_params!(
+  GF163,
+  "0x400000000000000000002bec12be2262d39bcf14d",
+  2,
+  2,
+  "0x2f588cc6443675e5dfe745fa6aaa02fa1c90185a1",
+  "0x10a77339bbc98a1a2018e5f1a8141f68b70bb6bac",
+  "0x10"
+);
+impl_preset_scalar_params!(
+  GF167,
+  "0x3fffffffffffffffffffffb12ebcc7d7f29ff7701f",
+  11,
+  1,
+  "0x3fffffffffffffffffffffb12ebcc7d7f29ff7701e",
+  "0x3fffffffffffffffffffffb12ebcc7d7f29ff7701e",
+  "0x79"
+);
+impl_preset_scalar_params!(
+  GF173,
+  "0x800000000000000000000189b4e67606e3825bb2831",
+  5,
+  4,
+  "0x35c7c0bd95dd81784b89f4f8aacdb17e914af4827f6",
+  "0x3fe4d7c96b595cfa27fc2c485ec0d384073755c93a1",
+  "0x2386f26fc1"
+);
+impl_preset_scalar_params!(
+  GF179,
+  "0x3ffffffffffffffffffffffb981960435fe5ab64236ef",
+  19,
+  1,
+  "0x3ffffffffffffffffffffffb981960435fe5ab64236ee",
+  "0x3ffffffffffffffffffffffb981960435fe5ab64236ee",
+  "0x169"
+);
+impl_preset_scalar_params!(
+  GF191,
+  "0x40000000000000000000000069a779cac1dabc6788f7474f",
+  3,
+  1,
+  "0x40000000000000000000000069a779cac1dabc6788f7474e",
+  "0x40000000000000000000000069a779cac1dabc6788f7474e",
+  "0x9"
+);
+impl_preset_scalar_params!(
+  GF233,
+  "0x1000000000000000000000000000013e974e72f8a6922031d2603cfe0d7",
+  5,
+  1,
+  "0x1000000000000000000000000000013e974e72f8a6922031d2603cfe0d6",
+  "0x1000000000000000000000000000013e974e72f8a6922031d2603cfe0d6",
+  "0x19"
+);
+impl_preset_scalar_params!(
+  GF257,
+  "0x800000000000000000000000000000006759213af182e987d3e17714907d470d",
+  3,
+  2,
+  "0x34940da708a7fbebcd5d93eb3ed443e15d1fcb6db974178dc20565fb491b2e30",
+  "0x4b6bf258f758041432a26c14c12bbc1f0a3955cd380ed1fa11dc1119476218dd",
+  "0x51"
+);
+impl_preset_scalar_params!(
+  GF367,
+  "0x40000000000000000000000000000000000000000000009c300b75a3fa824f22428fd28ce8812245ef44049b2d49",
+  14,
+  3,
+  "0x3c4dc9894816fe80e84b6415e2ecc0f91b770609cc0bd05a01af0ebcc3e7ba86a0d42e1acc54afc60f91934aae02",
+  "0x125b71dffb7803624e336e2960eff00f90b3238a0f679fac8df8f0524f91fdb9ad1f4c9b3fb423b9cd50ae274e7d",
+  "0x57f6c100"
+);
+
+/// Function parses hex number with optional `0x` prefix into the little-endian limbs at compile time.
+const fn limbs_from_hex(hex : &str) -> [u64; SCALAR_LIMBS]
+{
+  let bytes = hex.as_bytes();
+  let start = if bytes.len() > 1 && bytes[0] == b'0' && bytes[1] == b'x' { 2 } else { 0 };
+  let mut limbs = [0; SCALAR_LIMBS];
+  let mut pos = bytes.len();
+  let mut i = 0;
+  while pos > start
+  {
+    pos -= 1;
+    let digit = match bytes[pos]
+    {
+      b'0' ..= b'9' => bytes[pos] - b'0',
+      b'a' ..= b'f' => bytes[pos] - b'a' + 10,
+      b'A' ..= b'F' => bytes[pos] - b'A' + 10,
+      _ => panic!("invalid hex digit"),
+    };
+    limbs[i / 16] |= (digit as u64) << (4 * (i % 16));
+    i += 1;
+  }
+  limbs
+}
+
+/// Function calculates $(n + 1) / 2$ for odd $n$ at compile time.
+const fn half_of_successor(limbs : [u64; SCALAR_LIMBS]) -> [u64; SCALAR_LIMBS]
+{
+  let mut result = [0; SCALAR_LIMBS];
+  let mut i = SCALAR_LIMBS;
+  let mut carry = 0;
+  while i > 0
+  {
+    i -= 1;
+    result[i] = (limbs[i] >> 1) | carry;
+    carry = limbs[i] << 63;
+  }
+  // $n$ is odd, so $(n + 1) / 2 = \lfloor n / 2 \rfloor + 1$ without carry to the next limb
+  result[0] += 1;
+  result
+}
+
+/// Function calculates bit length of the number at compile time.
+const fn bit_length(limbs : [u64; SCALAR_LIMBS]) -> u32
+{
+  let mut i = SCALAR_LIMBS;
+  while i > 0
+  {
+    i -= 1;
+    if limbs[i] != 0
+    {
+      return 64 * i as u32 + 64 - limbs[i].leading_zeros();
+    }
+  }
+  0
+}
+
+/// Element of $\mathbb{Z}_n$, where $n$ is the order of the base point of the preset over the field `T`.
+pub struct PresetScalar<T>
+{
+  limbs : [u64; SCALAR_LIMBS],
+  _field : PhantomData<fn() -> T>,
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> PresetScalar<T>
+{
+  const fn from_hex(hex : &str) -> Self { PresetScalar::from_limbs(limbs_from_hex(hex)) }
+
+  const fn from_limbs(limbs : [u64; SCALAR_LIMBS]) -> Self
+  {
+    PresetScalar {
+      limbs,
+      _field : PhantomData,
+    }
+  }
+
+  /// Function gets the order $n$ of the base point.
+  pub fn modulus() -> BigUint { limbs_to_biguint(&limbs_from_hex(T::MODULUS)) }
+
+  /// Function reduces number modulo $n$.
+  pub fn from_biguint(n : &BigUint) -> Self
+  {
+    PresetScalar::from_limbs(biguint_to_limbs(&(n % Self::modulus())))
+  }
+
+  /// Function converts scalar into the number in range $[0, n)$.
+  pub fn to_biguint(&self) -> BigUint { limbs_to_biguint(&self.limbs) }
+}
+
+impl<T> Clone for PresetScalar<T>
+{
+  fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for PresetScalar<T> {}
+
+impl<T> PartialEq for PresetScalar<T>
+{
+  fn eq(&self, other : &Self) -> bool { self.limbs == other.limbs }
+}
+
+impl<T> Eq for PresetScalar<T> {}
+
+impl<T> Hash for PresetScalar<T>
+{
+  fn hash<H : Hasher>(&self, state : &mut H) { self.limbs.hash(state) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Default for PresetScalar<T>
+{
+  fn default() -> Self { Self::ZERO }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> fmt::Debug for PresetScalar<T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { write!(f, "PresetScalar({:x})", self.to_biguint()) }
+}
+
+impl<T> ConstantTimeEq for PresetScalar<T>
+{
+  fn ct_eq(&self, other : &Self) -> Choice { self.limbs[..].ct_eq(&other.limbs[..]) }
+}
+
+impl<T> ConditionallySelectable for PresetScalar<T>
+{
+  fn conditional_select(a : &Self, b : &Self, choice : Choice) -> Self
+  {
+    let mut limbs = [0; SCALAR_LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate()
+    {
+      *limb = u64::conditional_select(&a.limbs[i], &b.limbs[i], choice);
+    }
+    PresetScalar {
+      limbs,
+      _field : PhantomData,
+    }
+  }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> From<u64> for PresetScalar<T>
+{
+  fn from(value : u64) -> Self { PresetScalar::from_biguint(&BigUint::from(value)) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> From<PresetScalar<T>> for BigUint
+{
+  fn from(value : PresetScalar<T>) -> Self { value.to_biguint() }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Neg for PresetScalar<T>
+{
+  type Output = Self;
+
+  fn neg(self) -> Self { PresetScalar::from_biguint(&(Self::modulus() - self.to_biguint())) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Add<&PresetScalar<T>> for PresetScalar<T>
+{
+  type Output = Self;
+
+  fn add(self, rhs : &Self) -> Self { PresetScalar::from_biguint(&(self.to_biguint() + rhs.to_biguint())) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Sub<&PresetScalar<T>> for PresetScalar<T>
+{
+  type Output = Self;
+
+  fn sub(self, rhs : &Self) -> Self { self.add(&rhs.neg()) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Mul<&PresetScalar<T>> for PresetScalar<T>
+{
+  type Output = Self;
+
+  fn mul(self, rhs : &Self) -> Self { PresetScalar::from_biguint(&(self.to_biguint() * rhs.to_biguint())) }
+}
+
+/// Macro implements owned and assigning variants of the binary operation through the one with reference.
+macro_rules! impl_scalar_ops {
+  ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+    impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> $op<PresetScalar<T>> for PresetScalar<T>
+    {
+      type Output = Self;
+
+      fn $op_fn(self, rhs : Self) -> Self { self.$op_fn(&rhs) }
+    }
+
+    impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> $op_assign<PresetScalar<T>> for PresetScalar<T>
+    {
+      fn $op_assign_fn(&mut self, rhs : Self) { *self = self.$op_fn(&rhs) }
+    }
+
+    impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> $op_assign<&PresetScalar<T>> for PresetScalar<T>
+    {
+      fn $op_assign_fn(&mut self, rhs : &Self) { *self = self.$op_fn(rhs) }
+    }
+  };
+}
+
+impl_scalar_ops!(Add, add, AddAssign, add_assign);
+impl_scalar_ops!(Sub, sub, SubAssign, sub_assign);
+impl_scalar_ops!(Mul, mul, MulAssign, mul_assign);
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Sum for PresetScalar<T>
+{
+  fn sum<I : Iterator<Item = Self>>(iter : I) -> Self { iter.fold(Self::ZERO, |acc, x| acc + x) }
+}
+
+impl<'b, T : for<'a> GFArithmetic<'a> + PresetScalarParams> Sum<&'b PresetScalar<T>> for PresetScalar<T>
+{
+  fn sum<I : Iterator<Item = &'b Self>>(iter : I) -> Self { iter.fold(Self::ZERO, |acc, x| acc + x) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Product for PresetScalar<T>
+{
+  fn product<I : Iterator<Item = Self>>(iter : I) -> Self { iter.fold(Self::ONE, |acc, x| acc * x) }
+}
+
+impl<'b, T : for<'a> GFArithmetic<'a> + PresetScalarParams> Product<&'b PresetScalar<T>> for PresetScalar<T>
+{
+  fn product<I : Iterator<Item = &'b Self>>(iter : I) -> Self { iter.fold(Self::ONE, |acc, x| acc * x) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Field for PresetScalar<T>
+{
+  const ZERO : Self = PresetScalar::from_limbs([0; SCALAR_LIMBS]);
+  const ONE : Self = PresetScalar::from_hex("1");
+
+  /// Function generates scalar from $L(n) + 64$ random bits reduced modulo $n$, so bias is negligible.
+  fn random(mut rng : impl RngCore) -> Self
+  {
+    let mut bytes = vec![0; (Self::NUM_BITS as usize).div_ceil(8) + 8];
+    rng.fill_bytes(&mut bytes);
+    PresetScalar::from_biguint(&BigUint::from_bytes_le(&bytes))
+  }
+
+  fn square(&self) -> Self { *self * self }
+
+  fn double(&self) -> Self { *self + self }
+
+  fn invert(&self) -> CtOption<Self>
+  {
+    let inverse = self.to_biguint().modinv(&Self::modulus()).unwrap_or_default();
+    CtOption::new(PresetScalar::from_biguint(&inverse), !self.is_zero())
+  }
+
+  fn sqrt_ratio(num : &Self, div : &Self) -> (Choice, Self) { sqrt_ratio_generic(num, div) }
+
+  /// Function calculates square root with help of Tonelli–Shanks algorithm, where $n - 1 = 2^S t$.
+  fn sqrt(&self) -> CtOption<Self>
+  {
+    let t = (Self::modulus() - BigUint::one()) >> Self::S;
+    let tm1d2 : BigUint = (t - BigUint::one()) >> 1;
+    sqrt_tonelli_shanks(self, tm1d2.to_u64_digits())
+  }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> PrimeField for PresetScalar<T>
+{
+  type Repr = ScalarRepr;
+
+  const MODULUS : &'static str = T::MODULUS;
+  const NUM_BITS : u32 = bit_length(limbs_from_hex(T::MODULUS));
+  const CAPACITY : u32 = Self::NUM_BITS - 1;
+  const TWO_INV : Self = PresetScalar::from_limbs(half_of_successor(limbs_from_hex(T::MODULUS)));
+  const MULTIPLICATIVE_GENERATOR : Self = {
+    let mut limbs = [0; SCALAR_LIMBS];
+    limbs[0] = T::MULTIPLICATIVE_GENERATOR;
+    PresetScalar::from_limbs(limbs)
+  };
+  const S : u32 = T::S;
+  const ROOT_OF_UNITY : Self = PresetScalar::from_hex(T::ROOT_OF_UNITY);
+  const ROOT_OF_UNITY_INV : Self = PresetScalar::from_hex(T::ROOT_OF_UNITY_INV);
+  const DELTA : Self = PresetScalar::from_hex(T::DELTA);
+
+  /// Function restores scalar from the little-endian bytes, number has to be less than $n$.
+  fn from_repr(repr : ScalarRepr) -> CtOption<Self>
+  {
+    let n = BigUint::from_bytes_le(&repr.0);
+    let is_reduced = Choice::from((n < Self::modulus()) as u8);
+    CtOption::new(PresetScalar::from_biguint(&n), is_reduced)
+  }
+
+  fn to_repr(&self) -> ScalarRepr
+  {
+    let mut repr = ScalarRepr::default();
+    for (bytes, limb) in repr.0.chunks_exact_mut(8).zip(self.limbs.iter())
+    {
+      bytes.copy_from_slice(&limb.to_le_bytes());
+    }
+    repr
+  }
+
+  fn is_odd(&self) -> Choice { Choice::from((self.limbs[0] & 1) as u8) }
+}
+
+/// Point of the subgroup of order $n$ of the preset over the field `T` in the López–Dahab coordinates $(X : Y : Z)$,
+/// that are kept as [FieldElement]'s. Operations don't invert field elements, point is normalized only
+/// in [Curve::to_affine].
+pub struct PresetPoint<T>
+{
+  x : FieldElement<T>,
+  y : FieldElement<T>,
+  z : FieldElement<T>,
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> PresetPoint<T>
+{
+  /// Function creates point from the affine one, it has to belong to the subgroup of order $n$.
+  pub fn from_affine(point : &AffinePoint<T>) -> Result<Self>
+  {
+    let ec = T::preset();
+    match point
+    {
+      AffinePoint::Point { .. } if !ec.check_affine_point(point) => Err(EcError::PointNotOnCurve),
+      AffinePoint::Point { .. } if !ec.is_in_prime_subgroup(point) =>
+      {
+        Err(EcError::WrongPointOrder(ec.get_ord().to_str_radix(16)))
+      }
+      _ => Ok(Self::from(ProjectivePoint::from(point.clone()))),
+    }
+  }
+
+  /// Function converts point into the [ProjectivePoint] of the preset.
+  pub fn to_projective(&self) -> ProjectivePoint<T> { ProjectivePoint::new(self.x.to_gf(), self.y.to_gf(), self.z.to_gf()) }
+
+  /// Function applies operation on the projective points of the preset.
+  fn apply(&self, f : impl FnOnce(&BinaryEC<T>, ProjectivePoint<T>) -> ProjectivePoint<T>) -> Self
+  {
+    Self::from(f(T::preset(), self.to_projective()))
+  }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> From<ProjectivePoint<T>> for PresetPoint<T>
+{
+  fn from(value : ProjectivePoint<T>) -> Self
+  {
+    PresetPoint {
+      x : FieldElement::from(value.x),
+      y : FieldElement::from(value.y),
+      z : FieldElement::from(value.z),
+    }
+  }
+}
+
+impl<T> Clone for PresetPoint<T>
+{
+  fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for PresetPoint<T> {}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> PartialEq for PresetPoint<T>
+{
+  /// Points are compared as classes of equivalence of the projective coordinates.
+  fn eq(&self, other : &Self) -> bool { self.to_projective() == other.to_projective() }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Eq for PresetPoint<T> {}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> fmt::Debug for PresetPoint<T>
+{
+  fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result { write!(f, "PresetPoint({:?})", self.to_affine()) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> From<PresetPoint<T>> for AffinePoint<T>
+{
+  fn from(value : PresetPoint<T>) -> Self { value.to_affine() }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Neg for PresetPoint<T>
+{
+  type Output = Self;
+
+  fn neg(self) -> Self { self.apply(|_, p| p.negative()) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Add<&PresetPoint<T>> for PresetPoint<T>
+{
+  type Output = Self;
+
+  fn add(self, rhs : &Self) -> Self { self.apply(|ec, p| p.add(ec, &rhs.to_projective())) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Sub<&PresetPoint<T>> for PresetPoint<T>
+{
+  type Output = Self;
+
+  fn sub(self, rhs : &Self) -> Self { self.add(&rhs.neg()) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Add<&AffinePoint<T>> for PresetPoint<T>
+{
+  type Output = Self;
+
+  fn add(self, rhs : &AffinePoint<T>) -> Self { self.apply(|ec, p| p.add_affine(ec, rhs)) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Sub<&AffinePoint<T>> for PresetPoint<T>
+{
+  type Output = Self;
+
+  fn sub(self, rhs : &AffinePoint<T>) -> Self { self.add(&rhs.negative()) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Mul<&PresetScalar<T>> for PresetPoint<T>
+{
+  type Output = Self;
+
+  /// Function multiplies point with double-and-add from MSB to LSB in the López–Dahab coordinates.
+  fn mul(self, rhs : &PresetScalar<T>) -> Self
+  {
+    let k = rhs.to_biguint();
+    self.apply(|ec, p| {
+      (0 .. k.bits()).rev().fold(ProjectivePoint::neutral(), |acc, i| {
+        let acc = acc.double(ec);
+        if k.bit(i) { acc.add(ec, &p) } else { acc }
+      })
+    })
+  }
+}
+
+/// Macro implements owned and assigning variants of the operation with point through the one with reference.
+macro_rules! impl_point_ops {
+  ($rhs:ty, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+    impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> $op<$rhs> for PresetPoint<T>
+    {
+      type Output = Self;
+
+      fn $op_fn(self, rhs : $rhs) -> Self { self.$op_fn(&rhs) }
+    }
+
+    impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> $op_assign<$rhs> for PresetPoint<T>
+    {
+      fn $op_assign_fn(&mut self, rhs : $rhs) { *self = self.$op_fn(&rhs) }
+    }
+
+    impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> $op_assign<&$rhs> for PresetPoint<T>
+    {
+      fn $op_assign_fn(&mut self, rhs : &$rhs) { *self = self.$op_fn(rhs) }
+    }
+  };
+}
+
+impl_point_ops!(PresetPoint<T>, Add, add, AddAssign, add_assign);
+impl_point_ops!(PresetPoint<T>, Sub, sub, SubAssign, sub_assign);
+impl_point_ops!(AffinePoint<T>, Add, add, AddAssign, add_assign);
+impl_point_ops!(AffinePoint<T>, Sub, sub, SubAssign, sub_assign);
+impl_point_ops!(PresetScalar<T>, Mul, mul, MulAssign, mul_assign);
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Sum for PresetPoint<T>
+{
+  fn sum<I : Iterator<Item = Self>>(iter : I) -> Self { iter.fold(Self::identity(), |acc, p| acc + p) }
+}
+
+impl<'b, T : for<'a> GFArithmetic<'a> + PresetScalarParams> Sum<&'b PresetPoint<T>> for PresetPoint<T>
+{
+  fn sum<I : Iterator<Item = &'b Self>>(iter : I) -> Self { iter.fold(Self::identity(), |acc, p| acc + p) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Group for PresetPoint<T>
+{
+  type Scalar = PresetScalar<T>;
+
+  /// Function generates random point as multiple of the base point on the random scalar.
+  fn random(rng : impl RngCore) -> Self { Self::generator() * PresetScalar::random(rng) }
+
+  fn identity() -> Self
+  {
+    PresetPoint {
+      x : FieldElement::ONE,
+      y : FieldElement::ZERO,
+      z : FieldElement::ZERO,
+    }
+  }
+
+  fn generator() -> Self { Self::from(ProjectivePoint::from(T::preset().get_bp())) }
+
+  fn is_identity(&self) -> Choice { self.z.is_zero() }
+
+  fn double(&self) -> Self { self.apply(|ec, p| p.double(ec)) }
+}
+
+impl<T : for<'a> GFArithmetic<'a> + PresetScalarParams> Curve for PresetPoint<T>
+{
+  type AffineRepr = AffinePoint<T>;
+
+  fn to_affine(&self) -> AffinePoint<T> { self.to_projective().to_affine() }
+}
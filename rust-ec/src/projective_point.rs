@@ -0,0 +1,217 @@
+use rand_core::CryptoRngCore;
+use poly_algebra::gf::GFArithmetic;
+use poly_algebra::helpers::generate_num;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::{ACoefficient, BinaryEC};
+
+/// Struct represents point in the López–Dahab projective coordinates $(X : Y : Z)$,
+/// that corresponds to the affine point $(X/Z, Y/Z^2)$, where $X, Y, Z \in GF(2^m)$.
+/// Point with $Z = 0$ is $O_e$. Arithmetic doesn't require field inversions,
+/// so it's suitable for accumulating intermediate results, use [BinaryEC::batch_normalize]
+/// to convert many points back to the affine coordinates with single inversion.
+#[derive(Clone, Debug)]
+pub struct ProjectivePoint<T>
+{
+  pub(crate) x : T,
+  pub(crate) y : T,
+  pub(crate) z : T,
+}
+
+impl<'a, T : GFArithmetic<'a>> ProjectivePoint<T>
+{
+  /// Function creates point from the projective coordinates as is.
+  pub fn new(x : T, y : T, z : T) -> Self { ProjectivePoint { x, y, z } }
+
+  /// Function gets neutral point in the projective coordinates, i.e. $O_e = (1 : 0 : 0)$.
+  pub fn neutral() -> Self
+  {
+    ProjectivePoint {
+      x : T::one(),
+      y : T::zero(),
+      z : T::zero(),
+    }
+  }
+
+  /// Checks whether point is $O_e$.
+  pub fn is_inf(&self) -> bool { self.z.is_zero() }
+
+  pub fn get_ref_x(&self) -> &T { &self.x }
+
+  pub fn get_ref_y(&self) -> &T { &self.y }
+
+  pub fn get_ref_z(&self) -> &T { &self.z }
+
+  /// Function converts point into the affine coordinates, it costs one field inversion.
+  pub fn to_affine(&self) -> AffinePoint<T>
+  {
+    if self.is_inf()
+    {
+      return AffinePoint::Infinity;
+    }
+    let z_inv = self.z.inverse();
+    AffinePoint::Point {
+      x : self.x.clone() * z_inv.clone(),
+      y : self.y.clone() * z_inv.square(),
+    }
+  }
+
+  /// Function changes representation of the point into $(\lambda X : \lambda^2 Y : \lambda Z)$ for random
+  /// $\lambda \ne 0$, affine point stays the same.
+  pub fn randomize(&self, rng : &mut impl CryptoRngCore) -> Self
+  {
+    let lambda = loop
+    {
+      let lambda = T::from(generate_num(rng, T::get_m()));
+      if !lambda.is_zero()
+      {
+        break lambda;
+      }
+    };
+    ProjectivePoint {
+      x : lambda.clone() * self.x.clone(),
+      y : lambda.square() * self.y.clone(),
+      z : lambda * self.z.clone(),
+    }
+  }
+
+  /// Function generates negative point: $-(X : Y : Z) = (X : XZ + Y : Z)$.
+  pub fn negative(&self) -> Self
+  {
+    ProjectivePoint {
+      x : self.x.clone(),
+      y : self.x.clone() * self.z.clone() + self.y.clone(),
+      z : self.z.clone(),
+    }
+  }
+
+  /// Function performs doubling of point in the López–Dahab coordinates.
+  pub fn double(&self, ec : &BinaryEC<T>) -> Self
+  {
+    if self.is_inf() || self.x.is_zero()
+    {
+      return ProjectivePoint::neutral();
+    }
+    let x_squared = self.x.square();
+    let z_squared = self.z.square();
+    let b_z_4 = ec.b.clone() * z_squared.square();
+    let z_r = x_squared.clone() * z_squared;
+    let x_r = x_squared.square() + b_z_4.clone();
+    let y_r = b_z_4.clone() * z_r.clone() + x_r.clone() * (ec.a.as_field_el() * z_r.clone() + self.y.square() + b_z_4);
+    ProjectivePoint { x : x_r, y : y_r, z : z_r }
+  }
+
+  /// Function performs addition of points in the López–Dahab coordinates. Affine formulas
+  /// $\lambda = \frac{y_1 + y_2}{x_1 + x_2}$, $x_3 = \lambda^2 + \lambda + x_1 + x_2 + a$, $y_3 = \lambda(x_1 + x_3) + x_3 + y_1$
+  /// are multiplied by the common denominators, where $A = Y_1 Z_2^2 + Y_2 Z_1^2$, $B = X_1 Z_2 + X_2 Z_1$, $C = B Z_1 Z_2$
+  /// and $E = B^2 Z_1 Z_2^2$:
+  /// * $Z_3 = C^2$, $X_3 = A^2 + AC + B^3 Z_1 Z_2 + a C^2$;
+  /// * $Y_3 = AC(X_1 E + X_3) + X_3 C^2 + Y_1 E^2$.
+  pub fn add(&self, ec : &BinaryEC<T>, q : &Self) -> Self
+  {
+    if self.is_inf()
+    {
+      return q.clone();
+    }
+    if q.is_inf()
+    {
+      return self.clone();
+    }
+    let z_1_z_2 = self.z.clone() * q.z.clone();
+    let a = self.y.clone() * q.z.square() + q.y.clone() * self.z.square();
+    let b = self.x.clone() * q.z.clone() + q.x.clone() * self.z.clone();
+    if b.is_zero()
+    {
+      return if a.is_zero() { self.double(ec) } else { ProjectivePoint::neutral() };
+    }
+    let b_squared = b.square();
+    let c = b.clone() * z_1_z_2.clone();
+    let c_squared = c.square();
+    let e = b_squared.clone() * z_1_z_2.clone() * q.z.clone();
+    let a_c = a.clone() * c;
+    let x_r = a.square() + a_c.clone() + b_squared * b * z_1_z_2;
+    let x_r = match ec.a
+    {
+      ACoefficient::One(_) => x_r + c_squared.clone(),
+      ACoefficient::Zero(_) => x_r,
+    };
+    let y_r = a_c * (self.x.clone() * e.clone() + x_r.clone()) + x_r.clone() * c_squared.clone() + self.y.clone() * e.square();
+    ProjectivePoint {
+      x : x_r,
+      y : y_r,
+      z : c_squared,
+    }
+  }
+
+  /// Function performs mixed addition of point in the López–Dahab coordinates
+  /// and affine point according to the algorithm `3.25` from the «Guide to Elliptic Curve Cryptography».
+  pub fn add_affine(&self, ec : &BinaryEC<T>, q : &AffinePoint<T>) -> Self
+  {
+    let (x_q, y_q) = match q
+    {
+      AffinePoint::Point { x, y } => (x, y),
+      AffinePoint::Infinity => return self.clone(),
+    };
+    if self.is_inf()
+    {
+      return ProjectivePoint::from(q.clone());
+    }
+    let z_squared = self.z.square();
+    let x_r = self.x.clone() + self.z.clone() * x_q.clone();
+    let y_r = self.y.clone() + z_squared.clone() * y_q.clone();
+    if x_r.is_zero()
+    {
+      return if y_r.is_zero()
+      {
+        ProjectivePoint::from(q.clone()).double(ec)
+      }
+      else
+      {
+        ProjectivePoint::neutral()
+      };
+    }
+    let t1 = self.z.clone() * x_r.clone();
+    let z_r = t1.square();
+    let t3 = t1.clone() * y_r.clone();
+    let t1 = match ec.a
+    {
+      ACoefficient::One(_) => t1 + z_squared,
+      ACoefficient::Zero(_) => t1,
+    };
+    let x_r = x_r.square() * t1 + y_r.square() + t3.clone();
+    let t2 = x_q.clone() * z_r.clone() + x_r.clone();
+    let y_r = (t3 + z_r.clone()) * t2 + z_r.square() * (x_q.clone() + y_q.clone());
+    ProjectivePoint { x : x_r, y : y_r, z : z_r }
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> From<AffinePoint<T>> for ProjectivePoint<T>
+{
+  fn from(value : AffinePoint<T>) -> Self
+  {
+    match value
+    {
+      AffinePoint::Point { x, y } => ProjectivePoint { x, y, z : T::one() },
+      AffinePoint::Infinity => ProjectivePoint::neutral(),
+    }
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> PartialEq for ProjectivePoint<T>
+{
+  /// Points are compared as classes of equivalence, i.e. $(X_1 Z_2, Y_1 Z_2^2) = (X_2 Z_1, Y_2 Z_1^2)$.
+  fn eq(&self, other : &Self) -> bool
+  {
+    match (self.is_inf(), other.is_inf())
+    {
+      (true, true) => true,
+      (false, false) =>
+      {
+        self.x.clone() * other.z.clone() == other.x.clone() * self.z.clone()
+          && self.y.clone() * other.z.square() == other.y.clone() * self.z.square()
+      }
+      _ => false,
+    }
+  }
+}
+
+impl<'a, T : GFArithmetic<'a>> Eq for ProjectivePoint<T> {}
@@ -0,0 +1,266 @@
+//! Registry of the EC presets from DSTU 4145-2002 in polynomial basis, that are identified by OIDs
+//! `1.2.804.2.1.1.1.1.3.1.1.2.0` … `1.2.804.2.1.1.1.1.3.1.1.2.9` in the Ukrainian PKI.
+//!
+//! Field of the [BinaryEC] is chosen at compile time, so [AnyBinaryEC] is used when curve is known only at runtime,
+//! e.g. when it's parsed from a certificate.
+use std::sync::LazyLock;
+use num_bigint::BigUint;
+use poly_algebra::gf::{GFArithmetic, GF163, GF167, GF173, GF179, GF191, GF233, GF257, GF307, GF367, GF431};
+use crate::binary_ec::BinaryEC;
+use crate::error::{EcError, Result};
+use crate::twist::TwistSecurity;
+
+/// Common prefix of the OIDs of the EC presets in polynomial basis.
+pub const DSTU_PB_CURVES_OID_PREFIX : &str = "1.2.804.2.1.1.1.1.3.1.1.2";
+
+/// EC presets from DSTU 4145-2002 in polynomial basis, ordered as their OIDs.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DstuCurve
+{
+  M163,
+  M167,
+  M173,
+  M179,
+  M191,
+  M233,
+  M257,
+  M307,
+  M367,
+  M431,
+}
+
+impl DstuCurve
+{
+  /// All presets in order of their OIDs.
+  pub const ALL : [DstuCurve; 10] = [
+    DstuCurve::M163,
+    DstuCurve::M167,
+    DstuCurve::M173,
+    DstuCurve::M179,
+    DstuCurve::M191,
+    DstuCurve::M233,
+    DstuCurve::M257,
+    DstuCurve::M307,
+    DstuCurve::M367,
+    DstuCurve::M431,
+  ];
+
+  /// Function gets OID of the preset.
+  pub fn oid(&self) -> &'static str
+  {
+    match self
+    {
+      DstuCurve::M163 => "1.2.804.2.1.1.1.1.3.1.1.2.0",
+      DstuCurve::M167 => "1.2.804.2.1.1.1.1.3.1.1.2.1",
+      DstuCurve::M173 => "1.2.804.2.1.1.1.1.3.1.1.2.2",
+      DstuCurve::M179 => "1.2.804.2.1.1.1.1.3.1.1.2.3",
+      DstuCurve::M191 => "1.2.804.2.1.1.1.1.3.1.1.2.4",
+      DstuCurve::M233 => "1.2.804.2.1.1.1.1.3.1.1.2.5",
+      DstuCurve::M257 => "1.2.804.2.1.1.1.1.3.1.1.2.6",
+      DstuCurve::M307 => "1.2.804.2.1.1.1.1.3.1.1.2.7",
+      DstuCurve::M367 => "1.2.804.2.1.1.1.1.3.1.1.2.8",
+      DstuCurve::M431 => "1.2.804.2.1.1.1.1.3.1.1.2.9",
+    }
+  }
+
+  /// Function gets index of the preset in the registry, i.e. the last arc of its OID.
+  pub fn index(&self) -> u8 { *self as u8 }
+
+  /// Function finds preset by its OID.
+  pub fn from_oid(oid : &str) -> Result<Self>
+  {
+    DstuCurve::ALL
+      .into_iter()
+      .find(|curve| curve.oid() == oid)
+      .ok_or(EcError::UnknownOid(oid.to_string()))
+  }
+
+  /// Function gets degree $m$ of the field GF(2^m), which preset is defined over.
+  pub fn get_m(&self) -> u32
+  {
+    match self
+    {
+      DstuCurve::M163 => GF163::get_m(),
+      DstuCurve::M167 => GF167::get_m(),
+      DstuCurve::M173 => GF173::get_m(),
+      DstuCurve::M179 => GF179::get_m(),
+      DstuCurve::M191 => GF191::get_m(),
+      DstuCurve::M233 => GF233::get_m(),
+      DstuCurve::M257 => GF257::get_m(),
+      DstuCurve::M307 => GF307::get_m(),
+      DstuCurve::M367 => GF367::get_m(),
+      DstuCurve::M431 => GF431::get_m(),
+    }
+  }
+
+  /// Function generates EC of the preset.
+  pub fn generate(&self) -> AnyBinaryEC
+  {
+    match self
+    {
+      DstuCurve::M163 => AnyBinaryEC::M163(BinaryEC::generate_m163_pb_curve()),
+      DstuCurve::M167 => AnyBinaryEC::M167(BinaryEC::generate_m167_pb_curve()),
+      DstuCurve::M173 => AnyBinaryEC::M173(BinaryEC::generate_m173_pb_curve()),
+      DstuCurve::M179 => AnyBinaryEC::M179(BinaryEC::generate_m179_pb_curve()),
+      DstuCurve::M191 => AnyBinaryEC::M191(BinaryEC::generate_m191_pb_curve()),
+      DstuCurve::M233 => AnyBinaryEC::M233(BinaryEC::generate_m233_pb_curve()),
+      DstuCurve::M257 => AnyBinaryEC::M257(BinaryEC::generate_m257_pb_curve()),
+      DstuCurve::M307 => AnyBinaryEC::M307(BinaryEC::generate_m307_pb_curve()),
+      DstuCurve::M367 => AnyBinaryEC::M367(BinaryEC::generate_m367_pb_curve()),
+      DstuCurve::M431 => AnyBinaryEC::M431(BinaryEC::generate_m431_pb_curve()),
+    }
+  }
+}
+
+/// Field, which has the EC preset from DSTU 4145-2002 defined over it.
+pub trait DstuPresetField: Sized + 'static
+{
+  /// Preset that is defined over the field.
+  const CURVE : DstuCurve;
+
+  /// Function generates EC of the preset.
+  fn generate_preset() -> BinaryEC<Self>;
+
+  /// Function gets EC of the preset, which is generated once and shared by all callers.
+  fn preset() -> &'static BinaryEC<Self>;
+}
+
+/// EC over the field that is chosen at runtime.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum AnyBinaryEC
+{
+  M163(BinaryEC<GF163>),
+  M167(BinaryEC<GF167>),
+  M173(BinaryEC<GF173>),
+  M179(BinaryEC<GF179>),
+  M191(BinaryEC<GF191>),
+  M233(BinaryEC<GF233>),
+  M257(BinaryEC<GF257>),
+  M307(BinaryEC<GF307>),
+  M367(BinaryEC<GF367>),
+  M431(BinaryEC<GF431>),
+}
+
+/// Macro calls the same expression for the EC inside any variant of [AnyBinaryEC].
+macro_rules! dispatch {
+  ($any_ec:expr, $ec:ident => $body:expr) => {
+    match $any_ec
+    {
+      AnyBinaryEC::M163($ec) => $body,
+      AnyBinaryEC::M167($ec) => $body,
+      AnyBinaryEC::M173($ec) => $body,
+      AnyBinaryEC::M179($ec) => $body,
+      AnyBinaryEC::M191($ec) => $body,
+      AnyBinaryEC::M233($ec) => $body,
+      AnyBinaryEC::M257($ec) => $body,
+      AnyBinaryEC::M307($ec) => $body,
+      AnyBinaryEC::M367($ec) => $body,
+      AnyBinaryEC::M431($ec) => $body,
+    }
+  };
+}
+
+impl AnyBinaryEC
+{
+  /// Function generates EC preset by its OID.
+  pub fn from_oid(oid : &str) -> Result<Self> { Ok(DstuCurve::from_oid(oid)?.generate()) }
+
+  /// Function gets OID of the EC, if it's one of the presets.
+  pub fn oid(&self) -> Option<&'static str> { dispatch!(self, ec => ec.oid()) }
+
+  /// Function gets degree $m$ of the field GF(2^m).
+  pub fn get_m(&self) -> u32
+  {
+    match self
+    {
+      AnyBinaryEC::M163(_) => GF163::get_m(),
+      AnyBinaryEC::M167(_) => GF167::get_m(),
+      AnyBinaryEC::M173(_) => GF173::get_m(),
+      AnyBinaryEC::M179(_) => GF179::get_m(),
+      AnyBinaryEC::M191(_) => GF191::get_m(),
+      AnyBinaryEC::M233(_) => GF233::get_m(),
+      AnyBinaryEC::M257(_) => GF257::get_m(),
+      AnyBinaryEC::M307(_) => GF307::get_m(),
+      AnyBinaryEC::M367(_) => GF367::get_m(),
+      AnyBinaryEC::M431(_) => GF431::get_m(),
+    }
+  }
+
+  /// Function gets big prime order of base point.
+  pub fn get_ord(&self) -> BigUint { dispatch!(self, ec => ec.get_ord()) }
+
+  /// Function gets cofactor $h$ of the EC.
+  pub fn get_cofactor(&self) -> BigUint { dispatch!(self, ec => ec.get_cofactor()) }
+
+  /// Function factorizes order of the quadratic twist of the EC.
+  pub fn twist_security(&self) -> TwistSecurity { dispatch!(self, ec => ec.twist_security()) }
+}
+
+/// Macro implements [DstuPresetField] for the field and conversions between [BinaryEC] and [AnyBinaryEC].
+macro_rules! impl_dstu_preset {
+  ($gf:ident, $variant:ident, $generate:ident) => {
+    impl DstuPresetField for $gf
+    {
+      const CURVE : DstuCurve = DstuCurve::$variant;
+
+      fn generate_preset() -> BinaryEC<Self> { BinaryEC::<$gf>::$generate() }
+
+      fn preset() -> &'static BinaryEC<Self>
+      {
+        static PRESET : LazyLock<BinaryEC<$gf>> = LazyLock::new(BinaryEC::<$gf>::$generate);
+        &PRESET
+      }
+    }
+
+    impl From<BinaryEC<$gf>> for AnyBinaryEC
+    {
+      fn from(value : BinaryEC<$gf>) -> Self { AnyBinaryEC::$variant(value) }
+    }
+
+    impl TryFrom<AnyBinaryEC> for BinaryEC<$gf>
+    {
+      type Error = EcError;
+
+      fn try_from(value : AnyBinaryEC) -> Result<Self>
+      {
+        match value
+        {
+          AnyBinaryEC::$variant(ec) => Ok(ec),
+          other => match other.oid()
+          {
+            Some(oid) => Err(EcError::OidFieldMismatch(oid.to_string(), $gf::get_m())),
+            None => Err(EcError::FieldMismatch(other.get_m(), $gf::get_m())),
+          },
+        }
+      }
+    }
+  };
+}
+
+impl_dstu_preset!(GF163, M163, generate_m163_pb_curve);
+impl_dstu_preset!(GF167, M167, generate_m167_pb_curve);
+impl_dstu_preset!(GF173, M173, generate_m173_pb_curve);
+impl_dstu_preset!(GF179, M179, generate_m179_pb_curve);
+impl_dstu_preset!(GF191, M191, generate_m191_pb_curve);
+impl_dstu_preset!(GF233, M233, generate_m233_pb_curve);
+impl_dstu_preset!(GF257, M257, generate_m257_pb_curve);
+impl_dstu_preset!(GF307, M307, generate_m307_pb_curve);
+impl_dstu_preset!(GF367, M367, generate_m367_pb_curve);
+impl_dstu_preset!(GF431, M431, generate_m431_pb_curve);
+
+impl<'a, T : GFArithmetic<'a> + DstuPresetField> BinaryEC<T>
+{
+  /// Function generates EC preset by its OID, preset has to be defined over the field `T`.
+  pub fn from_oid(oid : &str) -> Result<Self>
+  {
+    let curve = DstuCurve::from_oid(oid)?;
+    if curve != T::CURVE
+    {
+      return Err(EcError::OidFieldMismatch(oid.to_string(), T::get_m()));
+    }
+    Ok(T::generate_preset())
+  }
+
+  /// Function gets OID of the EC, if it's the preset defined over the field `T`.
+  pub fn oid(&self) -> Option<&'static str> { (self == T::preset()).then(|| T::CURVE.oid()) }
+}
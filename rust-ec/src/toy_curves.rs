@@ -0,0 +1,211 @@
+//! Toy EC's over small fields `GF5` … `GF17` and exhaustive enumeration of their groups of points.
+//!
+//! Such curves are useless for cryptography, for example embedding degree of tiny $n$ is small,
+//! so most of them don't pass MOV condition in [BinaryEC::new]. Instead, the whole group
+//! fits into memory, so arithmetic of the EC can be checked against brute force rather than sampled randomly.
+//! Presets are available only with the `toy-fields` feature, enumeration works for any field of small degree.
+//! Small fields are compile-time types like the cryptographic ones rather than fields defined at runtime,
+//! so every toy EC is tied to its own type `GF5` … `GF59`.
+#[cfg(feature = "toy-fields")]
+use std::marker::PhantomData;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use poly_algebra::gf::GFArithmetic;
+#[cfg(feature = "toy-fields")]
+use poly_algebra::gf::{GF11, GF13, GF17, GF5, GF7};
+use poly_algebra::helpers::solve_quadratic_equation_in_field;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::{ACoefficient, BinaryEC};
+use crate::ecdlp::factorize;
+use crate::helpers::mul_affine_point;
+
+/// Maximal degree $m$ of the field, for which points of the EC can be enumerated.
+pub const ENUMERATION_DEGREE_BOUND : u32 = 24;
+
+#[cfg(feature = "toy-fields")]
+impl<'a, T : GFArithmetic<'a>> BinaryEC<T>
+{
+  /// Function creates EC from custom parameters __without__ any validation, unlike [BinaryEC::new].
+  /// It's intended for toy and deliberately weak EC's, e.g. with smooth $\#E = h \cdot n$,
+  /// on which attacks are demonstrated. _Such EC mustn't be used for cryptography._
+  pub fn new_unchecked(a : ACoefficient<T>, b : T, bp : AffinePoint<T>, n : BigUint, cofactor : BigUint) -> BinaryEC<T>
+  {
+    BinaryEC {
+      a,
+      b,
+      bp,
+      n,
+      h : cofactor,
+    }
+  }
+}
+
+#[cfg(feature = "toy-fields")]
+impl BinaryEC<GF5>
+{
+  /// Generates toy EC over [GF5] field, $\#E = 2 \cdot 17$.
+  pub fn generate_m5_toy_curve() -> BinaryEC<GF5>
+  {
+    BinaryEC::<GF5> {
+      a : ACoefficient::One(PhantomData::<GF5>),
+      b : GF5::from(BigUint::from(0x2_u32)),
+      bp : AffinePoint::Point {
+        x : GF5::from(BigUint::from(0x3_u32)),
+        y : GF5::from(BigUint::from(0x19_u32)),
+      },
+      n : BigUint::from(0x11_u32),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+
+#[cfg(feature = "toy-fields")]
+impl BinaryEC<GF7>
+{
+  /// Generates toy EC over [GF7] field, $\#E = 2 \cdot 71$.
+  pub fn generate_m7_toy_curve() -> BinaryEC<GF7>
+  {
+    BinaryEC::<GF7> {
+      a : ACoefficient::One(PhantomData::<GF7>),
+      b : GF7::from(BigUint::from(0x3_u32)),
+      bp : AffinePoint::Point {
+        x : GF7::from(BigUint::from(0x7B_u32)),
+        y : GF7::from(BigUint::from(0x4F_u32)),
+      },
+      n : BigUint::from(0x47_u32),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+
+#[cfg(feature = "toy-fields")]
+impl BinaryEC<GF11>
+{
+  /// Generates toy EC over [GF11] field, $\#E = 2 \cdot 1009$.
+  pub fn generate_m11_toy_curve() -> BinaryEC<GF11>
+  {
+    BinaryEC::<GF11> {
+      a : ACoefficient::One(PhantomData::<GF11>),
+      b : GF11::from(BigUint::from(0x16_u32)),
+      bp : AffinePoint::Point {
+        x : GF11::from(BigUint::from(0x17_u32)),
+        y : GF11::from(BigUint::from(0x525_u32)),
+      },
+      n : BigUint::from(0x3F1_u32),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+
+#[cfg(feature = "toy-fields")]
+impl BinaryEC<GF13>
+{
+  /// Generates toy EC over [GF13] field, $\#E = 2 \cdot 4091$.
+  pub fn generate_m13_toy_curve() -> BinaryEC<GF13>
+  {
+    BinaryEC::<GF13> {
+      a : ACoefficient::One(PhantomData::<GF13>),
+      b : GF13::from(BigUint::from(0x9_u32)),
+      bp : AffinePoint::Point {
+        x : GF13::from(BigUint::from(0x180D_u32)),
+        y : GF13::from(BigUint::from(0x6C0_u32)),
+      },
+      n : BigUint::from(0xFFB_u32),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+
+#[cfg(feature = "toy-fields")]
+impl BinaryEC<GF17>
+{
+  /// Generates toy EC over [GF17] field, $\#E = 2 \cdot 65713$.
+  pub fn generate_m17_toy_curve() -> BinaryEC<GF17>
+  {
+    BinaryEC::<GF17> {
+      a : ACoefficient::One(PhantomData::<GF17>),
+      b : GF17::from(BigUint::from(0xC_u32)),
+      bp : AffinePoint::Point {
+        x : GF17::from(BigUint::from(0xD_u32)),
+        y : GF17::from(BigUint::from(0x12421_u32)),
+      },
+      n : BigUint::from(0x100B1_u32),
+      h : BigUint::from(2_u8),
+    }
+  }
+}
+
+/// Function enumerates all points of the EC $y^2 + xy = x^3 + Ax^2 + B$, starting from $O_e$ and $(0, \sqrt{B})$.
+/// For every $x \ne 0$ equation $y^2 + xy = x^3 + Ax^2 + B$ is solved with help of
+/// [solve_quadratic_equation_in_field], it gives either none or two points $(x, y)$, $(x, x + y)$.
+/// _Degree of the field has to be at most [ENUMERATION_DEGREE_BOUND] and $B$ has to be nonzero._
+pub fn enumerate_points<'a, T : GFArithmetic<'a>>(a : &ACoefficient<T>, b : &T) -> Vec<AffinePoint<T>>
+{
+  assert!(
+    T::get_m() <= ENUMERATION_DEGREE_BOUND,
+    "degree of the field has to be at most {ENUMERATION_DEGREE_BOUND}"
+  );
+  assert!(!b.is_zero(), "coefficient B has to be nonzero");
+  let mut points = vec![AffinePoint::Infinity, AffinePoint::Point {
+    x : T::zero(),
+    y : b.sqrt(),
+  }];
+  for x in 1_u64 .. 1 << T::get_m()
+  {
+    let x = T::from_poly(x);
+    let x_2 = x.square();
+    let w = x_2.clone() * x.clone() + a.as_field_el() * x_2 + b.clone();
+    if let Some((y, _)) = solve_quadratic_equation_in_field(&x, &w)
+    {
+      points.push(AffinePoint::Point {
+        x : x.clone(),
+        y : y.clone() + x.clone(),
+      });
+      points.push(AffinePoint::Point { x, y });
+    }
+  }
+  points
+}
+
+/// Function finds structure of the group of points $E \cong \mathbb{Z}_{n_1} \times \mathbb{Z}_{n_2}$,
+/// where $n_2 | n_1$ and $n_2 | 2^m - 1$, and returns $(n_1, n_2)$.
+/// Part of the group of order $r^e$ is cyclic unless $r^2 | \#E$ and $r | 2^m - 1$ (there is only one point of order 2
+/// on the ordinary EC), otherwise it's $\mathbb{Z}_{r^{e - k}} \times \mathbb{Z}_{r^k}$, where $k$ is the biggest
+/// number with $\#E\[r^k\] = r^{2k}$, and sizes of $E\[r^i\]$ are counted over all points.
+pub fn group_structure<'a, T : GFArithmetic<'a>>(a : &ACoefficient<T>, b : &T) -> (BigUint, BigUint)
+{
+  let points = enumerate_points(a, b);
+  let ec = BinaryEC {
+    a : a.clone(),
+    b : b.clone(),
+    bp : AffinePoint::Infinity,
+    n : BigUint::from(points.len()),
+    h : BigUint::one(),
+  };
+  let q_1 = (BigUint::one() << T::get_m()) - BigUint::one();
+  let (mut n_1, mut n_2) = (BigUint::one(), BigUint::one());
+  for (r, e) in factorize(&BigUint::from(points.len()))
+  {
+    if e == 1 || !(&q_1 % &r).is_zero()
+    {
+      n_1 *= r.pow(e);
+      continue;
+    }
+    // $r^i$-multiples of all points, $E\[r^i\]$ consists of points, whose multiple is $O_e$
+    let mut multiples = points.clone();
+    let mut k = 0;
+    for i in 1 ..= e
+    {
+      multiples = multiples.iter().map(|point| mul_affine_point(point, &ec, &r)).collect();
+      let torsion_size = multiples.iter().filter(|point| point.is_inf()).count();
+      if BigUint::from(torsion_size) != r.pow(2 * i)
+      {
+        break;
+      }
+      k = i;
+    }
+    n_1 *= r.pow(e - k);
+    n_2 *= r.pow(k);
+  }
+  (n_1, n_2)
+}
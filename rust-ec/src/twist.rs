@@ -0,0 +1,147 @@
+//! Quadratic twist $y^2 + xy = x^3 + (A + 1)x^2 + B$ of the EC $y^2 + xy = x^3 + Ax^2 + B$.
+//!
+//! Degree $m$ of every field is odd, so $Tr(1) = 1$ and flipping of the [ACoefficient] gives the twist,
+//! that is isomorphic to the EC over $GF(2^{2m})$ only. Every $x \ne 0$ is the coordinate of a point either on the EC
+//! or on its twist, so protocols, that use only $x$ (packed points, Montgomery ladder), have to reject twist points,
+//! otherwise secret scalar leaks modulo small factors of the twist order.
+use std::marker::PhantomData;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+use poly_algebra::gf::GFArithmetic;
+use crate::affine_point::AffinePoint;
+use crate::binary_ec::{ACoefficient, BinaryEC};
+use crate::ecdlp::factorize_partially;
+use crate::error::{EcError, Result};
+use crate::helpers::{is_probably_prime, montgomery_ladder};
+
+/// Number of iterations of Pollard's rho for every composite factor of the twist order in [twist_security].
+pub const TWIST_RHO_ITERATIONS : u64 = 1 << 18;
+
+/// Seed of the generator, which is used to pick base point of the twist, so [twist] is deterministic.
+const TWIST_BASE_POINT_SEED : [u8; 32] = [0; 32];
+
+/// Report about the order of the twist: $\#E' = \prod r_i^{e_i} \cdot c$, where $c$ is the cofactor,
+/// which wasn't factorized within [TWIST_RHO_ITERATIONS].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TwistSecurity
+{
+  pub(crate) twist_order : BigUint,
+  pub(crate) factors : Vec<(BigUint, u32)>,
+  pub(crate) cofactor : BigUint,
+}
+
+impl TwistSecurity
+{
+  pub fn get_ref_twist_order(&self) -> &BigUint { &self.twist_order }
+
+  /// Function gets found prime powers $r_i^{e_i}$ sorted by $r_i$.
+  pub fn get_ref_factors(&self) -> &[(BigUint, u32)] { &self.factors }
+
+  /// Function gets unfactorized part of the twist order, it's $1$ when the order is fully factorized.
+  pub fn get_ref_cofactor(&self) -> &BigUint { &self.cofactor }
+
+  /// Function gets the largest prime factor of the twist order, if it's known:
+  /// either the order is fully factorized or the rest of it is prime.
+  pub fn largest_prime_factor(&self) -> Option<BigUint>
+  {
+    if self.cofactor.is_one()
+    {
+      self.factors.last().map(|(r, _)| r.clone())
+    }
+    else
+    {
+      is_probably_prime(&self.cofactor).then(|| self.cofactor.clone())
+    }
+  }
+
+  /// Function estimates security of the twist in bits as $\log_2 \sqrt{r}$ for the largest prime factor $r$,
+  /// which is the cost of Pollard's rho in the biggest subgroup of the twist.
+  pub fn security_bits(&self) -> Option<u64> { self.largest_prime_factor().map(|r| r.bits() / 2) }
+}
+
+/// Function calculates order of the twist $\#E' = 2^{m + 1} + 2 - \#E$, because traces of Frobenius
+/// of the EC and its twist have opposite signs: $\#E = 2^m + 1 - t$, $\#E' = 2^m + 1 + t$.
+/// _Degree $m$ of the field has to be odd._
+pub fn twist_order<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>) -> BigUint
+{
+  assert_eq!(T::get_m() % 2, 1, "degree of the field has to be odd");
+  (BigUint::one() << (T::get_m() + 1)) + BigUint::from(2_u8) - ec.get_curve_ord()
+}
+
+/// Function constructs twist of the EC with flipped coefficient $A$, cofactor $h' = 4$ for $A' = 0$
+/// and $h' = 2$ for $A' = 1$, base point of order $n' = \#E' / h'$ is chosen deterministically.
+/// Returns [EcError::CompositeOrder] when $n'$ isn't prime, other requirements are checked in [BinaryEC::new].
+pub fn twist<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>) -> Result<BinaryEC<T>>
+{
+  let points_num = twist_order(ec);
+  let (a, h) = match ec.a
+  {
+    ACoefficient::Zero(_) => (ACoefficient::One(PhantomData), BigUint::from(2_u8)),
+    ACoefficient::One(_) => (ACoefficient::Zero(PhantomData), BigUint::from(4_u8)),
+  };
+  let n = &points_num / &h;
+  if !(&points_num % &h).is_zero() || !is_probably_prime(&n)
+  {
+    return Err(EcError::CompositeOrder(n.to_str_radix(16)));
+  }
+  let twist = BinaryEC {
+    a,
+    b : ec.b.clone(),
+    bp : AffinePoint::Infinity,
+    n,
+    h,
+  };
+  let bp = twist.generate_base_point(&mut ChaCha20Rng::from_seed(TWIST_BASE_POINT_SEED));
+  BinaryEC::new(twist.a, twist.b, bp, twist.n, twist.h)
+}
+
+/// Function factorizes order of the twist with help of [factorize_partially], Pollard's rho is stopped after
+/// [TWIST_RHO_ITERATIONS] for every composite factor.
+pub fn twist_security<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>) -> TwistSecurity
+{
+  let twist_order = twist_order(ec);
+  let (factors, cofactor) = factorize_partially(&twist_order, TWIST_RHO_ITERATIONS);
+  TwistSecurity {
+    twist_order,
+    factors,
+    cofactor,
+  }
+}
+
+/// Function checks whether $x \ne 0$ is the coordinate of a point on the twist rather than on the EC.
+/// Substitution $y = xz$ gives $z^2 + z = x + A + B/x^2$, which has solution iff $Tr(x + A + B/x^2) = 0$.
+pub fn is_on_twist<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, x : &T) -> bool
+{
+  !x.is_zero() && (x.clone() + ec.a.as_field_el() + ec.b.clone() * x.square().inverse()).trace().is_one()
+}
+
+/// Function checks $x$ coordinate, that is received without $y$:
+/// * $x = 0$ is the point of order 2, so [EcError::WrongPointOrder] is returned;
+/// * $x$ of the twist point is rejected with [EcError::PointOnTwist].
+pub fn check_x_coordinate<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, x : &T) -> Result<()>
+{
+  if x.is_zero()
+  {
+    return Err(EcError::WrongPointOrder(ec.n.to_str_radix(16)));
+  }
+  if is_on_twist(ec, x)
+  {
+    return Err(EcError::PointOnTwist);
+  }
+  Ok(())
+}
+
+/// Function calculates $x$ coordinate of $kP$ from $x$ coordinate of $P$ with help of the [montgomery_ladder],
+/// that runs over all $L(hn)$ bits of $k \bmod hn$.
+/// $x$ is validated with [check_x_coordinate], because the same formulas silently compute multiples on the twist.
+/// Returns `None` when $kP = O$.
+pub fn mul_x_coordinate<'a, T : GFArithmetic<'a>, N : Into<BigUint>>(ec : &BinaryEC<T>, x : &T, k : N) -> Result<Option<T>>
+{
+  check_x_coordinate(ec, x)?;
+  let curve_ord = ec.get_curve_ord();
+  let k = k.into() % &curve_ord;
+  let ((x_1, z_1), _) = montgomery_ladder(ec, (x.clone(), T::one()), &k, curve_ord.bits());
+  Ok((!z_1.is_zero()).then(|| x_1 * z_1.inverse()))
+}
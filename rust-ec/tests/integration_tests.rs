@@ -1,22 +1,58 @@
 #[cfg(test)]
 mod tests
 {
-  use num_bigint::BigUint;
-  use num_traits::Zero;
+  use num_bigint::{BigInt, BigUint};
+  use num_traits::{Num, One, Zero};
   use proptest::arbitrary::any;
   use proptest::collection::vec;
-  use proptest::prelude::Strategy;
+  use proptest::prelude::{Just, ProptestConfig, Strategy};
   use proptest::proptest;
-  use poly_algebra::gf::{GFArithmetic, GF163, GF167, GF173, GF179, GF191, GF233, GF257, GF307, GF367, GF431};
+  use poly_algebra::gf::{
+    GFArithmetic, GF11, GF13, GF163, GF167, GF17, GF173, GF179, GF191, GF23, GF233, GF233Nist, GF257, GF283, GF29, GF307, GF367,
+    GF409, GF431, GF5, GF571, GF7,
+  };
   use rust_ec::affine_point::AffinePoint;
-  use rust_ec::binary_ec::BinaryEC;
+  use rust_ec::base_point_table::{BasePointTable, BasePointTableCache};
+  use rust_ec::binary_ec::{ACoefficient, BinaryEC, CURVE_GENERATION_ATTEMPTS, CURVE_SEED_LEN, MOV_DEGREE_BOUND};
+  use rust_ec::binary_edwards::EdwardsPoint;
+  use rust_ec::countermeasures::Countermeasures;
+  use rust_ec::ecdlp::{factorize, pohlig_hellman, point_order, pollard_rho};
+  use rust_ec::error::EcError;
+  use rust_ec::hash_to_curve::{encode_to_curve, expand_message_xmd, hash_to_curve, map_to_curve, MAX_DST_LEN};
+  use rust_ec::helpers::{batch_inverse, find_embedding_degree, is_probably_prime};
+  use rust_ec::koblitz::{get_delta, get_mu, partial_reduction, tnaf, wtnaf, wtnaf_representatives, TauElement};
+  use rust_ec::point_counting::count_points;
+  use rust_ec::projective_point::ProjectivePoint;
+  use rust_ec::registry::{AnyBinaryEC, DstuCurve, DstuPresetField, DSTU_PB_CURVES_OID_PREFIX};
+  use rust_ec::toy_curves::{enumerate_points, group_structure};
+  use rust_ec::twist::is_on_twist;
 
+  use std::collections::{HashMap, HashSet};
+  use std::marker::PhantomData;
   use rand_chacha::ChaCha20Rng;
+  use sha2::Sha256;
   use rand_core::{CryptoRngCore, SeedableRng};
   use poly_algebra::helpers::{generate_num, solve_quadratic_equation_in_field};
-  use rust_ec::helpers::generate_random_affine_point;
 
   const PROP_TEST_BIGUINT_BYTE_LEN : usize = 128;
+  const HALVING_TEST_CASES : u32 = 8;
+  const X_ONLY_TEST_ITERATIONS : usize = 8;
+  const COUNTERMEASURES_TEST_CASES : u32 = 2;
+  const BASE_POINT_TABLE_TEST_CASES : u32 = 8;
+  #[cfg(feature = "group")]
+  const PRESET_GROUP_TEST_ITERATIONS : usize = 2;
+  const KOBLITZ_TEST_ITERATIONS : usize = 8;
+  const COFACTOR_TEST_ITERATIONS : usize = 4;
+  const POINT_COUNTING_TEST_ITERATIONS : usize = 4;
+  const CURVE_GENERATION_TEST_ITERATIONS : usize = 4;
+  const TRY_UNPACK_TEST_CASES : u32 = 4;
+  const SEC1_TEST_CASES : u32 = 8;
+  const HASH_TO_CURVE_TEST_ITERATIONS : usize = 2;
+  const BATCH_TEST_CASES : u32 = 2;
+  const BATCH_TEST_SIZE : usize = 16;
+  const ECDLP_TEST_ITERATIONS : usize = 2;
+  const EDWARDS_TEST_ITERATIONS : usize = 4;
+  const ITERATIONS_NUM : usize = 200;
   pub fn generate_affine_point_local<'a, T : GFArithmetic<'a>>(u : T, ec : &BinaryEC<T>) -> Option<AffinePoint<T>>
   {
     let w = {
@@ -34,16 +70,16 @@ mod tests
       .prop_map(|bytes| BigUint::from_bytes_le(&bytes))
       .prop_filter("BigUint should not be zero", |n| *n != BigUint::zero())
   }
-  fn arb_gf163() -> impl Strategy<Value = GF163> { arb_biguint().prop_map(|x| GF163::from(x)) }
-  fn arb_gf167() -> impl Strategy<Value = GF167> { arb_biguint().prop_map(|x| GF167::from(x)) }
-  fn arb_gf173() -> impl Strategy<Value = GF173> { arb_biguint().prop_map(|x| GF173::from(x)) }
-  fn arb_gf179() -> impl Strategy<Value = GF179> { arb_biguint().prop_map(|x| GF179::from(x)) }
-  fn arb_gf191() -> impl Strategy<Value = GF191> { arb_biguint().prop_map(|x| GF191::from(x)) }
-  fn arb_gf233() -> impl Strategy<Value = GF233> { arb_biguint().prop_map(|x| GF233::from(x)) }
-  fn arb_gf257() -> impl Strategy<Value = GF257> { arb_biguint().prop_map(|x| GF257::from(x)) }
-  fn arb_gf307() -> impl Strategy<Value = GF307> { arb_biguint().prop_map(|x| GF307::from(x)) }
-  fn arb_gf367() -> impl Strategy<Value = GF367> { arb_biguint().prop_map(|x| GF367::from(x)) }
-  fn arb_gf431() -> impl Strategy<Value = GF431> { arb_biguint().prop_map(|x| GF431::from(x)) }
+  fn arb_gf163() -> impl Strategy<Value = GF163> { arb_biguint().prop_map(GF163::from) }
+  fn arb_gf167() -> impl Strategy<Value = GF167> { arb_biguint().prop_map(GF167::from) }
+  fn arb_gf173() -> impl Strategy<Value = GF173> { arb_biguint().prop_map(GF173::from) }
+  fn arb_gf179() -> impl Strategy<Value = GF179> { arb_biguint().prop_map(GF179::from) }
+  fn arb_gf191() -> impl Strategy<Value = GF191> { arb_biguint().prop_map(GF191::from) }
+  fn arb_gf233() -> impl Strategy<Value = GF233> { arb_biguint().prop_map(GF233::from) }
+  fn arb_gf257() -> impl Strategy<Value = GF257> { arb_biguint().prop_map(GF257::from) }
+  fn arb_gf307() -> impl Strategy<Value = GF307> { arb_biguint().prop_map(GF307::from) }
+  fn arb_gf367() -> impl Strategy<Value = GF367> { arb_biguint().prop_map(GF367::from) }
+  fn arb_gf431() -> impl Strategy<Value = GF431> { arb_biguint().prop_map(GF431::from) }
 
   fn arb_affine_point_gf163() -> impl Strategy<Value = AffinePoint<GF163>>
   {
@@ -205,6 +241,66 @@ mod tests
     let n = BinaryEC::<GF431>::generate_m431_pb_curve().get_ord();
     arb_biguint().prop_map(move |x| x % &n)
   }
+  // Abscissa satisfies trace condition from the unpacking, but doesn't belong to any point of the EC
+  fn is_free_abscissa<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, x : &T) -> bool
+  {
+    !x.is_zero() && x.trace() == ec.get_ref_a().as_biguint() && generate_affine_point_local(x.clone(), ec).is_none()
+  }
+  fn arb_free_abscissa_gf163() -> impl Strategy<Value = GF163>
+  {
+    let ec = BinaryEC::<GF163>::generate_m163_pb_curve();
+    arb_gf163().prop_filter("Abscissa shouldn't belong to any point", move |x| is_free_abscissa(&ec, x))
+  }
+  fn arb_free_abscissa_gf173() -> impl Strategy<Value = GF173>
+  {
+    let ec = BinaryEC::<GF173>::generate_m173_pb_curve();
+    arb_gf173().prop_filter("Abscissa shouldn't belong to any point", move |x| is_free_abscissa(&ec, x))
+  }
+  fn arb_free_abscissa_gf257() -> impl Strategy<Value = GF257>
+  {
+    let ec = BinaryEC::<GF257>::generate_m257_pb_curve();
+    arb_gf257().prop_filter("Abscissa shouldn't belong to any point", move |x| is_free_abscissa(&ec, x))
+  }
+  fn arb_batch_gf163() -> impl Strategy<Value = (Vec<GF163>, Vec<AffinePoint<GF163>>)>
+  {
+    (
+      vec(arb_gf163().prop_filter("Element should not be zero", |el| !el.is_zero()), BATCH_TEST_SIZE),
+      vec(arb_affine_point_gf163(), BATCH_TEST_SIZE),
+    )
+  }
+  fn arb_batch_gf173() -> impl Strategy<Value = (Vec<GF173>, Vec<AffinePoint<GF173>>)>
+  {
+    (
+      vec(arb_gf173().prop_filter("Element should not be zero", |el| !el.is_zero()), BATCH_TEST_SIZE),
+      vec(arb_affine_point_gf173(), BATCH_TEST_SIZE),
+    )
+  }
+  fn arb_batch_gf257() -> impl Strategy<Value = (Vec<GF257>, Vec<AffinePoint<GF257>>)>
+  {
+    (
+      vec(arb_gf257().prop_filter("Element should not be zero", |el| !el.is_zero()), BATCH_TEST_SIZE),
+      vec(arb_affine_point_gf257(), BATCH_TEST_SIZE),
+    )
+  }
+  fn arb_batch_gf431() -> impl Strategy<Value = (Vec<GF431>, Vec<AffinePoint<GF431>>)>
+  {
+    (
+      vec(arb_gf431().prop_filter("Element should not be zero", |el| !el.is_zero()), BATCH_TEST_SIZE),
+      vec(arb_affine_point_gf431(), BATCH_TEST_SIZE),
+    )
+  }
+  fn arb_point_on_random_ec_gf29() -> impl Strategy<Value = (BinaryEC<GF29>, AffinePoint<GF29>)>
+  {
+    any::<[u8; CURVE_SEED_LEN]>()
+      .prop_filter_map("Unable to generate EC from seed", |seed| BinaryEC::<GF29>::generate_from_seed(&seed).ok())
+      .prop_flat_map(|ec| {
+        let points = arb_biguint().prop_filter_map("Unable to find y coordinate for given x", {
+          let ec = ec.clone();
+          move |x| generate_affine_point_local(GF29::from(x), &ec)
+        });
+        (Just(ec), points)
+      })
+  }
 
   // Add negative
   proptest! {
@@ -936,6 +1032,88 @@ mod tests
       }
   }
 
+  fn check_halving<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, k : BigUint)
+  {
+    let q = ec.mul(ec.get_ref_bp(), k.clone());
+    let p = ec.halve(&q);
+    assert!(ec.check_affine_point(&p));
+    assert_eq!(ec.double(&p), q);
+    assert_eq!(ec.mul(&p, ec.get_ord()), AffinePoint::Infinity);
+    assert_eq!(ec.mul_halve_and_add(ec.get_ref_bp(), k), q);
+    assert_eq!(ec.halve(&AffinePoint::Infinity), AffinePoint::Infinity);
+  }
+
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_163(k in arb_random_ec_num_mod_gf163()) {
+        check_halving(&BinaryEC::<GF163>::generate_m163_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_167(k in arb_random_ec_num_mod_gf167()) {
+        check_halving(&BinaryEC::<GF167>::generate_m167_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_173(k in arb_random_ec_num_mod_gf173()) {
+        check_halving(&BinaryEC::<GF173>::generate_m173_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_179(k in arb_random_ec_num_mod_gf179()) {
+        check_halving(&BinaryEC::<GF179>::generate_m179_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_191(k in arb_random_ec_num_mod_gf191()) {
+        check_halving(&BinaryEC::<GF191>::generate_m191_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_233(k in arb_random_ec_num_mod_gf233()) {
+        check_halving(&BinaryEC::<GF233>::generate_m233_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_257(k in arb_random_ec_num_mod_gf257()) {
+        check_halving(&BinaryEC::<GF257>::generate_m257_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_307(k in arb_random_ec_num_mod_gf307()) {
+        check_halving(&BinaryEC::<GF307>::generate_m307_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_367(k in arb_random_ec_num_mod_gf367()) {
+        check_halving(&BinaryEC::<GF367>::generate_m367_pb_curve(), k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(HALVING_TEST_CASES))]
+      #[test]
+      fn halving_test_431(k in arb_random_ec_num_mod_gf431()) {
+        check_halving(&BinaryEC::<GF431>::generate_m431_pb_curve(), k);
+      }
+  }
+
   #[test]
   fn bp_point_mul_ord_test()
   {
@@ -990,4 +1168,1529 @@ mod tests
       assert_eq!(ec.mul(&p, ec.get_ord()), AffinePoint::Infinity)
     }
   }
+
+  // Koblitz curves
+  fn arb_tau_element() -> impl Strategy<Value = TauElement>
+  {
+    (any::<i64>(), any::<i64>()).prop_map(|(r0, r1)| TauElement::new(r0, r1))
+  }
+
+  proptest! {
+      #[test]
+      fn tnaf_test(r in arb_tau_element()) {
+        for mu in [-1, 1] {
+          let digits = tnaf(&r, mu);
+          assert_eq!(TauElement::from_tnaf(&digits, mu), r);
+          assert!(digits.windows(2).all(|w| w[0] == 0 || w[1] == 0));
+        }
+      }
+  }
+  proptest! {
+      #[test]
+      fn wtnaf_test(r in arb_tau_element()) {
+        for mu in [-1, 1] {
+          for w in 2 ..= 6 {
+            let alphas = wtnaf_representatives(mu, w);
+            let digits = wtnaf(&r, mu, w).unwrap();
+            let restored = digits.iter().rev().fold(TauElement::new(0, 0), |acc, u| {
+              let acc = acc.mul_tau(mu);
+              match u.signum() {
+                1 => acc.add(&alphas[(u.unsigned_abs() as usize - 1) / 2]),
+                -1 => acc.sub(&alphas[(u.unsigned_abs() as usize - 1) / 2]),
+                _ => acc,
+              }
+            });
+            assert_eq!(restored, r);
+            assert!(digits.windows(w as usize).all(|d| d.iter().filter(|u| **u != 0).count() <= 1));
+          }
+          assert_eq!(wtnaf(&r, mu, 1), None);
+          assert_eq!(wtnaf(&r, mu, 9), None);
+        }
+      }
+  }
+
+  #[test]
+  fn partial_reduction_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let ec = BinaryEC::<GF163>::generate_k163_curve();
+    let mu = get_mu(ec.get_ref_a());
+    assert_eq!(get_delta(mu, GF163::get_m()).norm(mu), BigInt::from(ec.get_ord()));
+    for _ in 0 .. ITERATIONS_NUM
+    {
+      let k = generate_num(&mut rng, ec.get_ref_ord().bits() + 32);
+      let rho = partial_reduction(&k, mu, GF163::get_m());
+      // TNAF length of the reduced element is at most $m + a$
+      assert!(tnaf(&rho, mu).len() <= GF163::get_m() as usize + 1);
+    }
+  }
+
+  #[test]
+  fn koblitz_mul_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let ec = BinaryEC::<GF163>::generate_k163_curve();
+    let bp = ec.get_bp();
+    assert!(ec.is_koblitz());
+    assert!(ec.check_affine_point(&bp));
+    assert_eq!(ec.mul(&bp, ec.get_ord()), AffinePoint::Infinity);
+    // $\tau^2 P + 2P = \mu\tau P$
+    let tau_bp = ec.frobenius(&bp);
+    assert_eq!(ec.add(&ec.frobenius(&tau_bp), &ec.double(&bp)), tau_bp);
+    for _ in 0 .. KOBLITZ_TEST_ITERATIONS
+    {
+      let k = generate_num(&mut rng, ec.get_ref_ord().bits());
+      let q = ec.mul(&bp, k.clone());
+      assert_eq!(ec.mul_tnaf(&bp, k.clone()), Some(q.clone()));
+      for w in 3 ..= 6
+      {
+        assert_eq!(ec.mul_wtnaf(&bp, k.clone(), w), Some(q.clone()));
+      }
+    }
+    assert_eq!(ec.mul_tnaf(&bp, ec.get_ord()), Some(AffinePoint::Infinity));
+    for w in [0, 1, 9, u8::MAX]
+    {
+      assert_eq!(ec.mul_wtnaf(&bp, 5_u8, w), None);
+    }
+
+    let ec = BinaryEC::<GF163>::generate_m163_pb_curve();
+    assert!(!ec.is_koblitz());
+    assert_eq!(ec.mul_tnaf(&ec.get_bp(), 5_u8), None);
+    assert_eq!(ec.mul_wtnaf(&ec.get_bp(), 5_u8, 4), None);
+  }
+
+  // Custom curves
+  fn check_curve_reconstruction<'a, T : GFArithmetic<'a>>(ec : BinaryEC<T>, cofactor : u8)
+  {
+    assert_eq!(ec.get_cofactor(), BigUint::from(cofactor));
+    let restored = BinaryEC::new(ec.get_a(), ec.get_b(), ec.get_bp(), ec.get_ord(), BigUint::from(cofactor));
+    assert_eq!(restored, Ok(ec));
+  }
+
+  #[test]
+  fn curve_validation_test()
+  {
+    check_curve_reconstruction(BinaryEC::<GF163>::generate_m163_pb_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF163>::generate_m163_pb_curve_from_examples(), 2);
+    check_curve_reconstruction(BinaryEC::<GF163>::generate_k163_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF167>::generate_m167_pb_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF173>::generate_m173_pb_curve(), 4);
+    check_curve_reconstruction(BinaryEC::<GF179>::generate_m179_pb_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF191>::generate_m191_pb_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF233>::generate_m233_pb_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF257>::generate_m257_pb_curve(), 4);
+    check_curve_reconstruction(BinaryEC::<GF307>::generate_m307_pb_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF367>::generate_m367_pb_curve(), 2);
+    check_curve_reconstruction(BinaryEC::<GF431>::generate_m431_pb_curve(), 2);
+
+    let ec = BinaryEC::<GF163>::generate_m163_pb_curve();
+    let two = BigUint::from(2_u8);
+    assert_eq!(
+      BinaryEC::new(ec.get_a(), GF163::zero(), ec.get_bp(), ec.get_ord(), two.clone()),
+      Err(EcError::ZeroBCoefficient)
+    );
+    assert_eq!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), AffinePoint::Infinity, ec.get_ord(), two.clone()),
+      Err(EcError::BasePointIsInfinity)
+    );
+    let wrong_bp = match ec.get_bp()
+    {
+      AffinePoint::Point { x, y } => AffinePoint::Point { x, y : y + GF163::one() },
+      AffinePoint::Infinity => unreachable!(),
+    };
+    assert_eq!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), wrong_bp, ec.get_ord(), two.clone()),
+      Err(EcError::BasePointNotOnCurve)
+    );
+    assert!(matches!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), ec.get_bp(), ec.get_ord() + 1_u8, two.clone()),
+      Err(EcError::CompositeOrder(_))
+    ));
+    assert!(matches!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), ec.get_bp(), ec.get_ord(), BigUint::from(3_u8)),
+      Err(EcError::HasseBoundViolated(_))
+    ));
+    assert_eq!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), ec.get_bp(), two.clone(), BigUint::one() << 162),
+      Err(EcError::AnomalousCurve)
+    );
+    let next_prime = {
+      let mut p = ec.get_ord() + 2_u8;
+      while !is_probably_prime(&p)
+      {
+        p += 2_u8;
+      }
+      p
+    };
+    assert!(matches!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), ec.get_bp(), next_prime, two),
+      Err(EcError::WrongBasePointOrder(_))
+    ));
+  }
+
+  #[test]
+  fn primality_test()
+  {
+    for p in [2_u64, 3, 5, 89, 97, 65537, 2_147_483_647, 18_446_744_073_709_551_557]
+    {
+      assert!(is_probably_prime(&BigUint::from(p)));
+    }
+    // Carmichael numbers and strong pseudoprimes to the small bases are included
+    for n in [
+      0_u64,
+      1,
+      4,
+      561,
+      1105,
+      2047,
+      3_215_031_751,
+      3_825_123_056_546_413_051,
+      18_446_744_073_709_551_555,
+    ]
+    {
+      assert!(!is_probably_prime(&BigUint::from(n)));
+    }
+    assert!(is_probably_prime(&BinaryEC::<GF431>::generate_m431_pb_curve().get_ord()));
+    assert!(!is_probably_prime(&((BigUint::one() << 127) + 1_u8)));
+  }
+
+  #[test]
+  fn embedding_degree_test()
+  {
+    // $2^2 \equiv 1 \mod 3$
+    assert_eq!(find_embedding_degree(&BigUint::from(3_u8), 2, MOV_DEGREE_BOUND), Some(1));
+    // $ord_{7}(2^1) = 3$
+    assert_eq!(find_embedding_degree(&BigUint::from(7_u8), 1, MOV_DEGREE_BOUND), Some(3));
+    // $ord_{2^{61} - 1}(2^5) = 61$
+    assert_eq!(
+      find_embedding_degree(&((BigUint::one() << 61) - 1_u8), 5, MOV_DEGREE_BOUND),
+      None
+    );
+    assert_eq!(find_embedding_degree(&((BigUint::one() << 61) - 1_u8), 5, 61), Some(61));
+  }
+
+  // Cofactor
+  fn check_cofactor<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, rng : &mut impl CryptoRngCore)
+  {
+    // Point of order 2
+    let t = AffinePoint::Point {
+      x : T::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    assert!(ec.check_affine_point(&t));
+    assert!(!ec.is_in_prime_subgroup(&t));
+    assert_eq!(ec.clear_cofactor(&t), AffinePoint::Infinity);
+    assert_eq!(ec.mul(&t, 1_u8), t);
+    assert_eq!(ec.mul(&t, 2_u8), AffinePoint::Infinity);
+
+    let bp = ec.get_bp();
+    assert!(ec.is_in_prime_subgroup(&bp));
+    assert!(ec.is_in_prime_subgroup(&AffinePoint::Infinity));
+    let p = ec.add(&bp, &t);
+    assert!(ec.check_affine_point(&p));
+    assert!(!ec.is_in_prime_subgroup(&p));
+    assert_eq!(ec.mul(&p, ec.get_ord()), t);
+    assert_eq!(ec.mul(&p, ec.get_curve_ord()), AffinePoint::Infinity);
+
+    for _ in 0 .. COFACTOR_TEST_ITERATIONS
+    {
+      let p = ec.generate_random_affine_point(rng);
+      assert_eq!(ec.mul(&p, ec.get_curve_ord()), AffinePoint::Infinity);
+      let cleared = ec.clear_cofactor(&p);
+      assert!(ec.is_in_prime_subgroup(&cleared));
+      assert_eq!(cleared, ec.mul(&p, ec.get_cofactor()));
+    }
+  }
+
+  #[test]
+  fn cofactor_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_cofactor(&BinaryEC::<GF163>::generate_m163_pb_curve(), &mut rng);
+    check_cofactor(&BinaryEC::<GF173>::generate_m173_pb_curve(), &mut rng);
+    check_cofactor(&BinaryEC::<GF257>::generate_m257_pb_curve(), &mut rng);
+    check_cofactor(&BinaryEC::<GF431>::generate_m431_pb_curve(), &mut rng);
+  }
+
+  // Validated points
+  #[test]
+  fn curve_point_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let ec = BinaryEC::<GF257>::generate_m257_pb_curve();
+    let bp = ec.base_point();
+    let (x, y) = match ec.get_bp()
+    {
+      AffinePoint::Point { x, y } => (x, y),
+      AffinePoint::Infinity => unreachable!(),
+    };
+    assert_eq!(ec.point_from_coordinates(x.clone(), y.clone()), Ok(bp.clone()));
+    assert_eq!(
+      ec.point_from_coordinates(x.clone(), y + GF257::one()),
+      Err(EcError::PointNotOnCurve)
+    );
+    assert_eq!(ec.unpack_point(&bp.pack()), Ok(bp.clone()));
+
+    let k = generate_num(&mut rng, ec.get_ref_ord().bits()) % ec.get_ref_ord();
+    let l = generate_num(&mut rng, ec.get_ref_ord().bits()) % ec.get_ref_ord();
+    let p = &bp * k.clone();
+    let q = &bp * l.clone();
+    assert_eq!(p.get_ref_point(), &ec.mul(&ec.get_bp(), k.clone()));
+    assert_eq!(&p + &q, &bp * ((&k + &l) % ec.get_ref_ord()));
+    assert_eq!(&p - &q, &bp * ((&k + ec.get_ref_ord() - &l) % ec.get_ref_ord()));
+    assert_eq!(p.clone() + (-p.clone()), ec.identity());
+    assert_eq!(p.clone() - p.clone(), ec.identity());
+    assert_eq!(p.double(), &p + &p);
+    assert_eq!(bp.clone() * ec.get_ord(), ec.identity());
+    assert_eq!(AffinePoint::from(p.clone()), p.into_affine());
+  }
+
+  #[test]
+  #[should_panic]
+  fn curve_point_different_ec_test()
+  {
+    let ec1 = BinaryEC::<GF163>::generate_m163_pb_curve();
+    let ec2 = BinaryEC::<GF163>::generate_k163_curve();
+    let _ = ec1.base_point() + ec2.base_point();
+  }
+
+  // Point counting
+  #[test]
+  fn point_counting_test()
+  {
+    macro_rules! check_presets {
+      ($($ec:expr),+) => {
+        $(
+          let ec = $ec;
+          assert_eq!(ec.count_points(), ec.get_curve_ord());
+        )+
+      };
+    }
+    check_presets!(
+      BinaryEC::<GF163>::generate_m163_pb_curve(),
+      BinaryEC::<GF163>::generate_k163_curve(),
+      BinaryEC::<GF167>::generate_m167_pb_curve(),
+      BinaryEC::<GF173>::generate_m173_pb_curve(),
+      BinaryEC::<GF179>::generate_m179_pb_curve(),
+      BinaryEC::<GF191>::generate_m191_pb_curve(),
+      BinaryEC::<GF233>::generate_m233_pb_curve(),
+      BinaryEC::<GF257>::generate_m257_pb_curve(),
+      BinaryEC::<GF307>::generate_m307_pb_curve(),
+      BinaryEC::<GF367>::generate_m367_pb_curve(),
+      BinaryEC::<GF431>::generate_m431_pb_curve()
+    );
+  }
+
+  /// Function counts points on the EC by brute force: $x = 0$ gives one point, every other $x$ gives
+  /// two points if $Tr((x^3 + Ax^2 + B)/x^2) = 0$.
+  fn brute_force_count_points<'a, T : GFArithmetic<'a>>(a : &ACoefficient<T>, b : &T) -> BigUint
+  {
+    let affine_points = (1_u64 .. 1 << T::get_m())
+      .map(|x| T::from_poly(x))
+      .filter(|x| {
+        let x_2 = x.square();
+        let w = x_2.clone() * x.clone() + a.as_field_el() * x_2.clone() + b.clone();
+        (w * x_2.inverse()).trace().is_zero()
+      })
+      .count();
+    BigUint::from(2 * affine_points as u64 + 2)
+  }
+
+  fn check_point_counting<'a, T : GFArithmetic<'a>>(rng : &mut impl CryptoRngCore)
+  {
+    for a in [ACoefficient::<T>::Zero(PhantomData), ACoefficient::<T>::One(PhantomData)]
+    {
+      for _ in 0 .. POINT_COUNTING_TEST_ITERATIONS
+      {
+        let b = T::from_poly(rng.next_u64() % (1 << T::get_m()));
+        if b.is_zero()
+        {
+          continue;
+        }
+        assert_eq!(count_points(&a, &b), brute_force_count_points(&a, &b));
+      }
+    }
+  }
+
+  #[test]
+  fn brute_force_point_counting_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_point_counting::<GF5>(&mut rng);
+    check_point_counting::<GF7>(&mut rng);
+    check_point_counting::<GF11>(&mut rng);
+    check_point_counting::<GF13>(&mut rng);
+  }
+
+  // Curve generation
+  fn check_curve_generation<'a, T : GFArithmetic<'a>>(rng : &mut impl CryptoRngCore)
+  {
+    let (ec, seed) = BinaryEC::<T>::generate_random(rng).unwrap();
+    assert_eq!(BinaryEC::<T>::generate_from_seed(&seed), Ok(ec.clone()));
+    assert_eq!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), ec.get_bp(), ec.get_ord(), ec.get_cofactor()),
+      Ok(ec.clone())
+    );
+    assert_eq!(brute_force_count_points(ec.get_ref_a(), ec.get_ref_b()), ec.get_curve_ord());
+    // For odd $m$ the cofactor is determined by $Tr(A)$: $\#E \equiv 0 \mod 4$ only for $A = 0$
+    let cofactor = match ec.get_ref_a()
+    {
+      ACoefficient::Zero(_) => 4_u8,
+      ACoefficient::One(_) => 2_u8,
+    };
+    assert_eq!(ec.get_cofactor(), BigUint::from(cofactor));
+    assert!(ec.is_in_prime_subgroup(ec.get_ref_bp()));
+  }
+
+  #[test]
+  fn curve_generation_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    for _ in 0 .. CURVE_GENERATION_TEST_ITERATIONS
+    {
+      check_curve_generation::<GF11>(&mut rng);
+      check_curve_generation::<GF13>(&mut rng);
+    }
+    let seed = [0x5a; CURVE_SEED_LEN];
+    assert_eq!(
+      BinaryEC::<GF13>::generate_from_seed(&seed),
+      BinaryEC::<GF13>::generate_from_seed(&seed)
+    );
+    // Every EC over GF(2^5) has $\#E \le 2^5 + 1 + 2\sqrt{2^5} < 45$, and none of them is suitable
+    // because of the MOV condition, so generation has to stop instead of looping forever
+    assert_eq!(
+      BinaryEC::<GF5>::generate_from_seed(&seed),
+      Err(EcError::CurveGenerationFailed(CURVE_GENERATION_ATTEMPTS))
+    );
+  }
+
+  // Base point generation
+  fn check_base_point<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, rng : &mut impl CryptoRngCore)
+  {
+    assert_eq!(ec.verify_base_point(ec.get_ref_bp()), Ok(()));
+    let bp = ec.generate_base_point(rng);
+    assert_eq!(ec.verify_base_point(&bp), Ok(()));
+    assert_eq!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), bp.clone(), ec.get_ord(), ec.get_cofactor()).map(|restored| restored.get_bp()),
+      Ok(bp)
+    );
+    assert_eq!(
+      ec.verify_base_point(&AffinePoint::Infinity),
+      Err(EcError::BasePointIsInfinity)
+    );
+    // Point of the small order isn't suitable as base point
+    let point = loop
+    {
+      let point = ec.generate_random_affine_point(rng);
+      if !ec.is_in_prime_subgroup(&point)
+      {
+        break point;
+      }
+    };
+    assert!(matches!(ec.verify_base_point(&point), Err(EcError::WrongBasePointOrder(_))));
+  }
+
+  #[test]
+  fn base_point_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_base_point(&BinaryEC::<GF163>::generate_m163_pb_curve(), &mut rng);
+    check_base_point(&BinaryEC::<GF173>::generate_m173_pb_curve(), &mut rng);
+    check_base_point(&BinaryEC::<GF257>::generate_m257_pb_curve(), &mut rng);
+    check_base_point(&BinaryEC::<GF431>::generate_m431_pb_curve(), &mut rng);
+    let (ec, _) = BinaryEC::<GF13>::generate_random(&mut rng).unwrap();
+    check_base_point(&ec, &mut rng);
+  }
+
+  // Fallible unpacking
+  fn check_try_unpack<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, k : BigUint, x : T)
+  {
+    let q = ec.mul(ec.get_ref_bp(), k);
+    if !q.is_inf()
+    {
+      assert_eq!(AffinePoint::try_unpack(&q.pack(), ec), Ok(q.clone()));
+      assert_eq!(ec.try_unpack_affine_point(&q.pack()), Ok(q));
+    }
+    assert_eq!(ec.try_unpack_affine_point(&T::zero()), Err(EcError::PointIsInfinity));
+    // $Q + T$, where $T = (0, \sqrt{B})$ has order 2, can be unpacked only if $h = 4$,
+    // because for $h = 2$ packing condition $Tr(x) = A$ holds exactly for points of the prime subgroup
+    if ec.get_cofactor() == BigUint::from(4_u8)
+    {
+      let t = AffinePoint::Point {
+        x : T::zero(),
+        y : ec.get_ref_b().sqrt(),
+      };
+      let point = ec.add(ec.get_ref_bp(), &t);
+      assert_eq!(ec.unpack_affine_point(&point.pack()), point);
+      assert!(matches!(
+        ec.try_unpack_affine_point(&point.pack()),
+        Err(EcError::WrongPointOrder(_))
+      ));
+    }
+    assert_eq!(ec.try_unpack_affine_point(&x), Err(EcError::NoSquareRoot));
+    assert_eq!(ec.unpack_point(&x), Err(EcError::NoSquareRoot));
+  }
+
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(TRY_UNPACK_TEST_CASES))]
+      #[test]
+      fn try_unpack_test_163(k in arb_random_ec_num_mod_gf163(), x in arb_free_abscissa_gf163()) {
+        check_try_unpack(&BinaryEC::<GF163>::generate_m163_pb_curve(), k, x);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(TRY_UNPACK_TEST_CASES))]
+      #[test]
+      fn try_unpack_test_173(k in arb_random_ec_num_mod_gf173(), x in arb_free_abscissa_gf173()) {
+        check_try_unpack(&BinaryEC::<GF173>::generate_m173_pb_curve(), k, x);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(TRY_UNPACK_TEST_CASES))]
+      #[test]
+      fn try_unpack_test_257(k in arb_random_ec_num_mod_gf257(), x in arb_free_abscissa_gf257()) {
+        check_try_unpack(&BinaryEC::<GF257>::generate_m257_pb_curve(), k, x);
+      }
+  }
+
+  // SEC1 encoding
+  fn check_sec1<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, point : AffinePoint<T>)
+  {
+    let len = T::get_m().div_ceil(8) as usize;
+    let compressed = point.to_sec1(true);
+    let uncompressed = point.to_sec1(false);
+    assert_eq!(compressed.len(), 1 + len);
+    assert_eq!(uncompressed.len(), 1 + 2 * len);
+    assert_eq!(compressed[0] & !1, 0x02);
+    assert_eq!(uncompressed[0], 0x04);
+    assert_eq!(AffinePoint::from_sec1(ec, &compressed), Ok(point.clone()));
+    assert_eq!(AffinePoint::from_sec1(ec, &uncompressed), Ok(point.clone()));
+    assert_eq!(
+      AffinePoint::from_sec1(ec, &point.negative().to_sec1(true)),
+      Ok(point.negative())
+    );
+
+    assert_eq!(AffinePoint::<T>::Infinity.to_sec1(true), vec![0x00]);
+    assert_eq!(AffinePoint::from_sec1(ec, &[0x00]), Ok(AffinePoint::Infinity));
+    let t = AffinePoint::Point {
+      x : T::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    let mut t_odd = t.to_sec1(true);
+    assert_eq!(t_odd[0], 0x02);
+    t_odd[0] = 0x03;
+    assert!(matches!(
+      AffinePoint::from_sec1(ec, &t_odd),
+      Err(EcError::InvalidSec1Encoding(_))
+    ));
+    assert_eq!(AffinePoint::from_sec1(ec, &t.to_sec1(true)), Ok(t));
+
+    assert!(matches!(
+      AffinePoint::from_sec1(ec, &[]),
+      Err(EcError::InvalidSec1Encoding(_))
+    ));
+    let mut wrong_tag = compressed.clone();
+    wrong_tag[0] = 0x05;
+    assert!(matches!(
+      AffinePoint::from_sec1(ec, &wrong_tag),
+      Err(EcError::InvalidSec1Encoding(_))
+    ));
+    assert!(matches!(
+      AffinePoint::from_sec1(ec, &compressed[.. len]),
+      Err(EcError::InvalidSec1Encoding(_))
+    ));
+    let mut out_of_field = compressed.clone();
+    out_of_field[1] = 0xff;
+    assert!(matches!(
+      AffinePoint::from_sec1(ec, &out_of_field),
+      Err(EcError::InvalidSec1Encoding(_))
+    ));
+    let mut not_on_curve = uncompressed.clone();
+    not_on_curve[2 * len] ^= 1;
+    assert_eq!(AffinePoint::from_sec1(ec, &not_on_curve), Err(EcError::PointNotOnCurve));
+  }
+
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(SEC1_TEST_CASES))]
+      #[test]
+      fn sec1_test_163(p in arb_affine_point_gf163()) {
+        check_sec1(&BinaryEC::<GF163>::generate_m163_pb_curve(), p);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(SEC1_TEST_CASES))]
+      #[test]
+      fn sec1_test_173(p in arb_affine_point_gf173()) {
+        check_sec1(&BinaryEC::<GF173>::generate_m173_pb_curve(), p);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(SEC1_TEST_CASES))]
+      #[test]
+      fn sec1_test_257(p in arb_affine_point_gf257()) {
+        check_sec1(&BinaryEC::<GF257>::generate_m257_pb_curve(), p);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(SEC1_TEST_CASES))]
+      #[test]
+      fn sec1_test_431(p in arb_affine_point_gf431()) {
+        check_sec1(&BinaryEC::<GF431>::generate_m431_pb_curve(), p);
+      }
+  }
+
+  #[test]
+  fn sec1_test()
+  {
+    // Base point of the sect163k1 from the SEC2
+    let ec = BinaryEC::<GF163>::generate_k163_curve();
+    let x = BigUint::from_str_radix("02FE13C0537BBC11ACAA07D793DE4E6D5E5C94EEE8", 16).unwrap();
+    let y = BigUint::from_str_radix("0289070FB05D38FF58321F2E800536D538CCDAA3D9", 16).unwrap();
+    let mut compressed = vec![0x03];
+    compressed.extend(x.to_bytes_be());
+    let mut uncompressed = vec![0x04];
+    uncompressed.extend(x.to_bytes_be());
+    uncompressed.extend(y.to_bytes_be());
+    assert_eq!(ec.get_bp().to_sec1(true), compressed);
+    assert_eq!(ec.get_bp().to_sec1(false), uncompressed);
+    assert_eq!(AffinePoint::from_sec1(&ec, &compressed), Ok(ec.get_bp()));
+  }
+
+  // Hash to curve
+  #[test]
+  fn expand_message_xmd_test()
+  {
+    // Test vectors from the RFC 9380, appendix `K.1`
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    for (msg, expected) in [
+      ("", "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+      ("abc", "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+    ]
+    {
+      let expected = BigUint::from_str_radix(expected, 16).unwrap().to_bytes_be();
+      assert_eq!(expand_message_xmd::<Sha256>(msg.as_bytes(), dst, 0x20), Ok(expected));
+    }
+    assert_eq!(expand_message_xmd::<Sha256>(b"abc", dst, 100).unwrap().len(), 100);
+    assert!(matches!(
+      expand_message_xmd::<Sha256>(b"abc", &[0; MAX_DST_LEN + 1], 0x20),
+      Err(EcError::InvalidExpandMessageParams(_))
+    ));
+    assert!(matches!(
+      expand_message_xmd::<Sha256>(b"abc", dst, 256 * 32),
+      Err(EcError::InvalidExpandMessageParams(_))
+    ));
+  }
+
+  fn check_hash_to_curve<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, rng : &mut impl CryptoRngCore)
+  {
+    let u = T::rand(rng);
+    assert!(ec.check_affine_point(&map_to_curve(&u, ec).unwrap()));
+    // Candidates aren't defined for $u^2 + u = 0$
+    assert_eq!(map_to_curve(&T::zero(), ec), Err(EcError::PointIsInfinity));
+    assert_eq!(map_to_curve(&T::one(), ec), Err(EcError::PointIsInfinity));
+
+    let dst = b"DSTU4145-RUST-TEST-V01";
+    let msg = generate_num(rng, 256_u32).to_bytes_be();
+    let p = ec.hash_to_curve::<Sha256>(&msg, dst).unwrap();
+    assert!(!p.is_inf());
+    assert!(ec.is_in_prime_subgroup(&p));
+    assert_eq!(hash_to_curve::<T, Sha256>(&msg, dst, ec), Ok(p.clone()));
+    assert_ne!(ec.hash_to_curve::<Sha256>(&msg, b"ANOTHER-DST").unwrap(), p);
+    let q = encode_to_curve::<T, Sha256>(&msg, dst, ec).unwrap();
+    assert!(ec.is_in_prime_subgroup(&q));
+  }
+
+  // Every field element is mapped into the EC, except $u^2 + u \in \{0, A\}$
+  fn check_toy_map_to_curve<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>)
+  {
+    let a = ec.get_ref_a().as_field_el();
+    for num in 0_u64 .. 1 << T::get_m()
+    {
+      let u = T::from_poly(num);
+      let u_2_u = u.square() + u.clone();
+      match map_to_curve(&u, ec)
+      {
+        Ok(p) => assert!(ec.check_affine_point(&p)),
+        Err(err) =>
+        {
+          assert_eq!(err, EcError::PointIsInfinity);
+          assert!(u_2_u.is_zero() || u_2_u == a);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn hash_to_curve_test()
+  {
+    check_toy_map_to_curve(&BinaryEC::<GF5>::generate_m5_toy_curve());
+    check_toy_map_to_curve(&BinaryEC::<GF11>::generate_m11_toy_curve());
+    check_toy_map_to_curve(&BinaryEC::<GF13>::generate_m13_toy_curve());
+    let mut rng = ChaCha20Rng::from_entropy();
+    for _ in 0 .. HASH_TO_CURVE_TEST_ITERATIONS
+    {
+      check_hash_to_curve(&BinaryEC::<GF163>::generate_m163_pb_curve(), &mut rng);
+      check_hash_to_curve(&BinaryEC::<GF173>::generate_m173_pb_curve(), &mut rng);
+      check_hash_to_curve(&BinaryEC::<GF257>::generate_m257_pb_curve(), &mut rng);
+      check_hash_to_curve(&BinaryEC::<GF431>::generate_m431_pb_curve(), &mut rng);
+    }
+  }
+
+  // Registry of the presets
+  #[test]
+  fn registry_test()
+  {
+    let degrees = [163, 167, 173, 179, 191, 233, 257, 307, 367, 431];
+    for (i, (curve, m)) in DstuCurve::ALL.into_iter().zip(degrees).enumerate()
+    {
+      let oid = format!("{DSTU_PB_CURVES_OID_PREFIX}.{i}");
+      assert_eq!(curve.oid(), oid);
+      assert_eq!(curve.index() as usize, i);
+      assert_eq!(DstuCurve::from_oid(&oid), Ok(curve));
+      assert_eq!(curve.get_m(), m);
+      let ec = AnyBinaryEC::from_oid(&oid).unwrap();
+      assert_eq!(ec, curve.generate());
+      assert_eq!(ec.oid(), Some(curve.oid()));
+      assert_eq!(ec.get_m(), m);
+    }
+
+    let ec = BinaryEC::<GF257>::from_oid("1.2.804.2.1.1.1.1.3.1.1.2.6").unwrap();
+    assert_eq!(ec, BinaryEC::<GF257>::generate_m257_pb_curve());
+    assert_eq!(ec.oid(), Some("1.2.804.2.1.1.1.1.3.1.1.2.6"));
+    assert_eq!(AnyBinaryEC::from(ec.clone()).get_ord(), ec.get_ord());
+    assert_eq!(BinaryEC::<GF257>::try_from(AnyBinaryEC::from(ec.clone())), Ok(ec));
+    assert!(matches!(
+      BinaryEC::<GF257>::try_from(DstuCurve::M163.generate()),
+      Err(EcError::OidFieldMismatch(_, 257))
+    ));
+    assert_eq!(
+      BinaryEC::<GF257>::try_from(AnyBinaryEC::from(BinaryEC::<GF163>::generate_k163_curve())),
+      Err(EcError::FieldMismatch(163, 257))
+    );
+    assert_eq!(
+      BinaryEC::<GF163>::from_oid("1.2.804.2.1.1.1.1.3.1.1.2.6"),
+      Err(EcError::OidFieldMismatch("1.2.804.2.1.1.1.1.3.1.1.2.6".to_string(), 163))
+    );
+    assert_eq!(
+      AnyBinaryEC::from_oid("1.2.804.2.1.1.1.1.3.1.1.2.10"),
+      Err(EcError::UnknownOid("1.2.804.2.1.1.1.1.3.1.1.2.10".to_string()))
+    );
+    // Curves that aren't presets don't have OID
+    assert_eq!(BinaryEC::<GF163>::generate_k163_curve().oid(), None);
+    assert_eq!(BinaryEC::<GF163>::generate_m163_pb_curve_from_examples().oid(), None);
+    assert!(std::ptr::eq(GF163::preset(), GF163::preset()));
+    assert_eq!(GF163::preset(), &GF163::generate_preset());
+  }
+
+  // Batch arithmetic
+  fn check_batch<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, elements : Vec<T>, points : Vec<AffinePoint<T>>)
+  {
+    assert_eq!(batch_inverse(&elements), elements.iter().map(|el| el.inverse()).collect::<Vec<_>>());
+    assert!(batch_inverse::<T>(&[]).is_empty());
+
+    let p = &points[0];
+    let t = AffinePoint::Point {
+      x : T::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    let mut pairs = points.windows(2).map(|w| (w[0].clone(), w[1].clone())).collect::<Vec<_>>();
+    pairs.extend([
+      (p.clone(), p.clone()),
+      (p.clone(), p.negative()),
+      (p.clone(), AffinePoint::Infinity),
+      (AffinePoint::Infinity, p.clone()),
+      (AffinePoint::Infinity, AffinePoint::Infinity),
+      (t.clone(), t.clone()),
+      (t.clone(), p.clone()),
+    ]);
+    assert_eq!(
+      ec.batch_add(&pairs),
+      pairs.iter().map(|(p, q)| ec.add(p, q)).collect::<Vec<_>>()
+    );
+    assert_eq!(ec.batch_double(&points), points.iter().map(|p| ec.double(p)).collect::<Vec<_>>());
+    assert!(ec.batch_add(&[]).is_empty());
+
+    // Projective arithmetic agrees with the affine one
+    let mut acc = ProjectivePoint::neutral();
+    let mut expected = AffinePoint::Infinity;
+    let mut projective = vec![];
+    for q in points.iter().chain([p, &p.negative(), &t, &AffinePoint::Infinity])
+    {
+      acc = acc.add_affine(ec, q).double(ec);
+      expected = ec.double(&ec.add(&expected, q));
+      assert_eq!(acc.to_affine(), expected);
+      projective.push(acc.clone());
+    }
+    assert_eq!(ProjectivePoint::from(p.clone()).add_affine(ec, p), ProjectivePoint::from(ec.double(p)));
+    assert!(ProjectivePoint::from(p.clone()).add_affine(ec, &p.negative()).is_inf());
+    assert_eq!(ProjectivePoint::from(p.clone()).negative().to_affine(), p.negative());
+    projective.push(ProjectivePoint::neutral());
+    for (r, s) in projective.iter().zip(projective.iter().rev())
+    {
+      assert_eq!(r.add(ec, s).to_affine(), ec.add(&r.to_affine(), &s.to_affine()));
+      assert_eq!(r.add(ec, r), r.double(ec));
+      assert!(r.add(ec, &r.negative()).is_inf());
+    }
+    assert_eq!(
+      ec.batch_normalize(&projective),
+      projective.iter().map(|p| p.to_affine()).collect::<Vec<_>>()
+    );
+  }
+
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BATCH_TEST_CASES))]
+      #[test]
+      fn batch_test_163((elements, points) in arb_batch_gf163()) {
+        check_batch(&BinaryEC::<GF163>::generate_m163_pb_curve(), elements, points);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BATCH_TEST_CASES))]
+      #[test]
+      fn batch_test_173((elements, points) in arb_batch_gf173()) {
+        check_batch(&BinaryEC::<GF173>::generate_m173_pb_curve(), elements, points);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BATCH_TEST_CASES))]
+      #[test]
+      fn batch_test_257((elements, points) in arb_batch_gf257()) {
+        check_batch(&BinaryEC::<GF257>::generate_m257_pb_curve(), elements, points);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BATCH_TEST_CASES))]
+      #[test]
+      fn batch_test_431((elements, points) in arb_batch_gf431()) {
+        check_batch(&BinaryEC::<GF431>::generate_m431_pb_curve(), elements, points);
+      }
+  }
+
+  // Fixed-base multiplication
+  // Cache is cloned into every case, so the table is built once per EC
+  fn check_base_point_table<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, cache : BasePointTableCache<T>, k : BigUint)
+  {
+    let table = cache.get_or_init(ec);
+    assert_eq!(table.get_ref_bp(), ec.get_ref_bp());
+    assert_eq!(table.mul(ec, k.clone()), ec.mul(ec.get_ref_bp(), k % ec.get_ref_ord()));
+    let n = ec.get_ord();
+    assert_eq!(table.mul(ec, BigUint::zero()), AffinePoint::Infinity);
+    assert_eq!(table.mul(ec, n.clone()), AffinePoint::Infinity);
+    assert_eq!(table.mul(ec, BigUint::one()), ec.get_bp());
+    assert_eq!(table.mul(ec, &n - BigUint::one()), ec.get_bp().negative());
+    assert_eq!(table.mul(ec, &n + BigUint::from(2_u8)), ec.double(ec.get_ref_bp()));
+  }
+
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BASE_POINT_TABLE_TEST_CASES))]
+      #[test]
+      fn base_point_table_test_163(cache in Just(BasePointTableCache::new()), k in arb_biguint()) {
+        check_base_point_table(&BinaryEC::<GF163>::generate_m163_pb_curve(), cache, k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BASE_POINT_TABLE_TEST_CASES))]
+      #[test]
+      fn base_point_table_test_257(cache in Just(BasePointTableCache::new()), k in arb_biguint()) {
+        check_base_point_table(&BinaryEC::<GF257>::generate_m257_pb_curve(), cache, k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BASE_POINT_TABLE_TEST_CASES))]
+      #[test]
+      fn base_point_table_test_431(cache in Just(BasePointTableCache::new()), k in arb_biguint()) {
+        check_base_point_table(&BinaryEC::<GF431>::generate_m431_pb_curve(), cache, k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(BASE_POINT_TABLE_TEST_CASES))]
+      #[test]
+      fn base_point_table_test_k283(cache in Just(BasePointTableCache::new()), k in arb_biguint()) {
+        check_base_point_table(&BinaryEC::<GF283>::generate_k283_curve(), cache, k);
+      }
+  }
+
+  #[test]
+  fn base_point_table_cache_test()
+  {
+    let ec = BinaryEC::<GF163>::generate_m163_pb_curve();
+    let cache = BasePointTableCache::new();
+    let shared = cache.clone();
+    assert!(!shared.is_initialized());
+    assert!(cache.get_or_init(&ec) == &BasePointTable::new(&ec));
+    assert!(shared.is_initialized());
+    assert!(std::ptr::eq(cache.get_or_init(&ec), shared.get_or_init(&ec)));
+  }
+
+  // Discrete logarithm
+  #[test]
+  fn factorize_test()
+  {
+    let to_factors = |factors : &[(u64, u32)]| factors.iter().map(|(r, e)| (BigUint::from(*r), *e)).collect::<Vec<_>>();
+    assert_eq!(factorize(&BigUint::one()), vec![]);
+    assert_eq!(factorize(&BigUint::from(2_u8)), to_factors(&[(2, 1)]));
+    assert_eq!(factorize(&BigUint::from(720_u32)), to_factors(&[(2, 4), (3, 2), (5, 1)]));
+    // $2^2 \cdot 4099 \cdot 1000003 \cdot 1000033$, where factors are above the trial division bound
+    let n = BigUint::from(4_u64 * 4099 * 1000003 * 1000033);
+    assert_eq!(factorize(&n), to_factors(&[(2, 2), (4099, 1), (1000003, 1), (1000033, 1)]));
+    let n = BigUint::from(1000003_u64 * 1000003);
+    assert_eq!(factorize(&n), to_factors(&[(1000003, 2)]));
+  }
+
+  fn check_discrete_log<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, rng : &mut impl CryptoRngCore)
+  {
+    let p = ec.get_bp();
+    assert_eq!(point_order(ec, &p), ec.get_ord());
+    assert_eq!(point_order(ec, &AffinePoint::Infinity), BigUint::one());
+    let k = generate_num(rng, ec.get_ref_ord().bits()) % ec.get_ref_ord();
+    let q = ec.mul(&p, k.clone());
+    assert_eq!(pollard_rho(ec, &p, &q, ec.get_ref_ord(), rng), Some(k.clone()));
+    assert_eq!(ec.discrete_log(&p, &q, rng), Some(k));
+    assert_eq!(ec.discrete_log(&p, &AffinePoint::Infinity, rng), Some(BigUint::zero()));
+
+    // Point of the composite order $h \cdot n$ or its divisor
+    let r = ec.generate_random_affine_point(rng);
+    let ord = point_order(ec, &r);
+    assert!(ec.mul(&r, ord.clone()).is_inf());
+    assert!((ec.get_curve_ord() % &ord).is_zero());
+    let k = generate_num(rng, ord.bits()) % &ord;
+    let q = ec.mul(&r, k.clone());
+    assert_eq!(pohlig_hellman(ec, &r, &q, &ord, rng), Some(k));
+
+    // Point of order 2 doesn't belong to the subgroup of prime order
+    let t = AffinePoint::Point {
+      x : T::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    assert_eq!(point_order(ec, &t), BigUint::from(2_u8));
+    assert_eq!(ec.discrete_log(&p, &t, rng), None);
+    assert_eq!(ec.discrete_log(&t, &t, rng), Some(BigUint::one()));
+  }
+
+  #[test]
+  fn discrete_log_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    for _ in 0 .. ECDLP_TEST_ITERATIONS
+    {
+      let (ec, _) = BinaryEC::<GF13>::generate_random(&mut rng).unwrap();
+      check_discrete_log(&ec, &mut rng);
+      let (ec, _) = BinaryEC::<GF23>::generate_random(&mut rng).unwrap();
+      check_discrete_log(&ec, &mut rng);
+      let (ec, _) = BinaryEC::<GF29>::generate_random(&mut rng).unwrap();
+      check_discrete_log(&ec, &mut rng);
+    }
+  }
+
+  // Toy curves
+  fn check_toy_curve<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>)
+  {
+    let points = ec.enumerate_points();
+    assert_eq!(BigUint::from(points.len()), ec.get_curve_ord());
+    assert_eq!(BigUint::from(points.len()), ec.count_points());
+    assert_eq!(points.iter().cloned().collect::<HashSet<_>>().len(), points.len());
+    assert!(points.iter().skip(1).all(|point| ec.check_affine_point(point)));
+    assert_eq!(ec.verify_base_point(ec.get_ref_bp()), Ok(()));
+    assert_eq!(ec.group_structure(), (ec.get_curve_ord(), BigUint::one()));
+  }
+
+  // Unpacking of every field element is compared with the packed points of the subgroup of order $n$
+  fn check_toy_packing<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>)
+  {
+    let points = ec.enumerate_points();
+    let subgroup = points.iter().filter(|point| ec.is_in_prime_subgroup(point)).cloned().collect::<Vec<_>>();
+    assert_eq!(BigUint::from(subgroup.len()), ec.get_ord());
+    let packed = subgroup
+      .iter()
+      .filter(|point| !point.is_inf())
+      .map(|point| (ec.pack_affine_point(point), point.clone()))
+      .collect::<HashMap<_, _>>();
+    assert_eq!(packed.len(), subgroup.len() - 1);
+    // Point $(1, y)$ with $Tr(y) = 0$ is packed into zero, the same as $O_e$, so it can't be unpacked
+    for num in 1_u64 .. 1 << T::get_m()
+    {
+      let num = T::from_poly(num);
+      assert_eq!(ec.try_unpack_affine_point(&num).ok(), packed.get(&num).cloned());
+      if let Some(point) = packed.get(&num)
+      {
+        assert_eq!(ec.unpack_affine_point(&num), *point);
+      }
+    }
+  }
+
+  fn check_toy_group_law<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>)
+  {
+    let points = ec.enumerate_points();
+    let points_set = points.iter().cloned().collect::<HashSet<_>>();
+    let bp = ec.get_bp();
+    for p in points.iter()
+    {
+      assert!(ec.add(p, &p.negative()).is_inf());
+      assert_eq!(ec.double(p), ec.add(p, p));
+      assert_eq!(ProjectivePoint::from(p.clone()).double(ec).to_affine(), ec.double(p));
+      for q in points.iter()
+      {
+        let sum = ec.add(p, q);
+        assert!(points_set.contains(&sum));
+        assert_eq!(sum, ec.add(q, p));
+        assert_eq!(ProjectivePoint::from(p.clone()).add_affine(ec, q).to_affine(), sum);
+        assert_eq!(ec.add(&sum, &bp), ec.add(p, &ec.add(q, &bp)));
+      }
+      // Multiplication is compared with the sequential addition
+      let mut r = AffinePoint::Infinity;
+      for k in 0 ..= points.len()
+      {
+        assert_eq!(ec.mul(p, k), r);
+        r = ec.add(&r, p);
+      }
+    }
+  }
+
+  #[test]
+  fn toy_curves_test()
+  {
+    check_toy_curve(&BinaryEC::<GF5>::generate_m5_toy_curve());
+    check_toy_curve(&BinaryEC::<GF7>::generate_m7_toy_curve());
+    check_toy_curve(&BinaryEC::<GF11>::generate_m11_toy_curve());
+    check_toy_curve(&BinaryEC::<GF13>::generate_m13_toy_curve());
+    check_toy_curve(&BinaryEC::<GF17>::generate_m17_toy_curve());
+    check_toy_packing(&BinaryEC::<GF5>::generate_m5_toy_curve());
+    check_toy_packing(&BinaryEC::<GF7>::generate_m7_toy_curve());
+    check_toy_packing(&BinaryEC::<GF11>::generate_m11_toy_curve());
+    check_toy_packing(&BinaryEC::<GF13>::generate_m13_toy_curve());
+    check_toy_group_law(&BinaryEC::<GF5>::generate_m5_toy_curve());
+    check_toy_group_law(&BinaryEC::<GF7>::generate_m7_toy_curve());
+  }
+
+  #[test]
+  fn new_unchecked_test()
+  {
+    // $\#E = 2^2 \cdot 23^2$ is smooth, so validation rejects the EC, but logarithm is still solvable
+    let mut rng = ChaCha20Rng::from_entropy();
+    let a = ACoefficient::<GF11>::Zero(PhantomData);
+    let b = GF11::one();
+    let points = enumerate_points(&a, &b);
+    let points_num = BigUint::from(points.len());
+    for p in points.into_iter().filter(|p| !p.is_inf()).take(POINT_COUNTING_TEST_ITERATIONS)
+    {
+      let ec = BinaryEC::new_unchecked(a.clone(), b.clone(), p.clone(), points_num.clone(), BigUint::one());
+      let ord = point_order(&ec, &p);
+      let k = generate_num(&mut rng, 32_u32) % &ord;
+      assert_eq!(ec.discrete_log(&p, &ec.mul(&p, k.clone()), &mut rng), Some(k));
+      assert_eq!(
+        BinaryEC::new(a.clone(), b.clone(), p, points_num.clone(), BigUint::one()),
+        Err(EcError::CompositeOrder(points_num.to_str_radix(16)))
+      );
+    }
+  }
+
+  #[test]
+  fn group_structure_test()
+  {
+    // $2^{11} - 1 = 23 \cdot 89$, so both EC's with $\#E = 2^2 \cdot 23^2$ may have non-cyclic group
+    let a = ACoefficient::<GF11>::Zero(PhantomData);
+    let b = GF11::one();
+    assert_eq!(enumerate_points(&a, &b).len(), 2116);
+    assert_eq!(group_structure(&a, &b), (BigUint::from(92_u8), BigUint::from(23_u8)));
+    let b = GF11::from_poly(0x23_u8);
+    assert_eq!(enumerate_points(&a, &b).len(), 2116);
+    assert_eq!(group_structure(&a, &b), (BigUint::from(2116_u16), BigUint::one()));
+
+    let mut rng = ChaCha20Rng::from_entropy();
+    for _ in 0 .. POINT_COUNTING_TEST_ITERATIONS
+    {
+      let b = GF11::from_poly(generate_num(&mut rng, GF11::get_m()));
+      if b.is_zero()
+      {
+        continue;
+      }
+      let a = ACoefficient::<GF11>::One(PhantomData);
+      let points_num = BigUint::from(enumerate_points(&a, &b).len());
+      assert_eq!(points_num, count_points(&a, &b));
+      let (n_1, n_2) = group_structure(&a, &b);
+      assert_eq!(&n_1 * &n_2, points_num);
+      assert!((&n_1 % &n_2).is_zero());
+      assert!((((BigUint::one() << GF11::get_m()) - BigUint::one()) % &n_2).is_zero());
+    }
+  }
+
+  // Binary Edwards curves
+  fn check_edwards<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, rng : &mut impl CryptoRngCore)
+  {
+    let edwards = ec.to_edwards().unwrap();
+    assert!(!edwards.get_ref_d_1().is_zero());
+    assert!(edwards.get_ref_d_2().trace().is_one());
+    assert_eq!(edwards.get_ref_ec(), ec);
+    let bp = edwards.get_bp();
+    assert!(edwards.check_point(&bp));
+    assert_eq!(edwards.to_affine_point(&bp), ec.get_bp());
+    assert!(edwards.mul(&bp, ec.get_ord()).is_neutral());
+    assert_eq!(edwards.from_affine_point(&AffinePoint::Infinity), EdwardsPoint::neutral());
+    assert!(edwards.to_affine_point(&EdwardsPoint::neutral()).is_inf());
+    for _ in 0 .. EDWARDS_TEST_ITERATIONS
+    {
+      let (p, q) = (ec.generate_random_affine_point(rng), ec.generate_random_affine_point(rng));
+      let (e_p, e_q) = (edwards.from_affine_point(&p), edwards.from_affine_point(&q));
+      assert!(edwards.check_point(&e_p));
+      assert_eq!(edwards.to_affine_point(&e_p), p);
+      assert_eq!(edwards.to_affine_point(&e_p.negative()), p.negative());
+      assert_eq!(edwards.to_affine_point(&edwards.add(&e_p, &e_q)), ec.add(&p, &q));
+      assert_eq!(edwards.to_affine_point(&edwards.double(&e_p)), ec.double(&p));
+      assert!(edwards.add(&e_p, &e_p.negative()).is_neutral());
+      assert_eq!(edwards.add(&e_p, &EdwardsPoint::neutral()), e_p);
+      let k = generate_num(rng, ec.get_curve_ord().bits());
+      assert_eq!(edwards.to_affine_point(&edwards.mul(&e_p, k.clone())), ec.mul(&p, k));
+    }
+  }
+
+  // Complete addition law is compared with the Weierstrass one for every pair of points
+  fn check_edwards_exhaustive<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>)
+  {
+    let edwards = ec.to_edwards().unwrap();
+    let points = ec.enumerate_points();
+    let edwards_points = points.iter().map(|point| edwards.from_affine_point(point)).collect::<Vec<_>>();
+    assert_eq!(edwards_points.iter().cloned().collect::<HashSet<_>>().len(), points.len());
+    for (p, e_p) in points.iter().zip(edwards_points.iter())
+    {
+      assert!(edwards.check_point(e_p));
+      assert_eq!(edwards.to_affine_point(e_p), *p);
+      for (q, e_q) in points.iter().zip(edwards_points.iter())
+      {
+        assert_eq!(edwards.to_affine_point(&edwards.add(e_p, e_q)), ec.add(p, q));
+      }
+      for k in 0 ..= points.len()
+      {
+        assert_eq!(edwards.to_affine_point(&edwards.mul(e_p, k)), ec.mul(p, k));
+      }
+    }
+  }
+
+  #[test]
+  fn edwards_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_edwards(&BinaryEC::<GF163>::generate_m163_pb_curve(), &mut rng);
+    check_edwards(&BinaryEC::<GF163>::generate_k163_curve(), &mut rng);
+    check_edwards(&BinaryEC::<GF257>::generate_m257_pb_curve(), &mut rng);
+    let (ec, _) = BinaryEC::<GF13>::generate_random(&mut rng).unwrap();
+    check_edwards(&ec, &mut rng);
+    check_edwards_exhaustive(&BinaryEC::<GF5>::generate_m5_toy_curve());
+    check_edwards_exhaustive(&BinaryEC::<GF7>::generate_m7_toy_curve());
+  }
+
+  // Quadratic twist
+  fn check_twist_order<'a, T : GFArithmetic<'a>>(rng : &mut impl CryptoRngCore)
+  {
+    let (ec, _) = BinaryEC::<T>::generate_random(rng).unwrap();
+    let twisted_a = match ec.get_ref_a()
+    {
+      ACoefficient::Zero(_) => ACoefficient::One(PhantomData),
+      ACoefficient::One(_) => ACoefficient::Zero(PhantomData),
+    };
+    assert_eq!(ec.twist_order(), count_points(&twisted_a, ec.get_ref_b()));
+    assert_eq!(ec.twist_order() + ec.get_curve_ord(), (BigUint::one() << (T::get_m() + 1)) + BigUint::from(2_u8));
+  }
+
+  fn check_twist<'a, T : GFArithmetic<'a>>(rng : &mut impl CryptoRngCore)
+  {
+    let (ec, twist) = loop
+    {
+      let (ec, _) = BinaryEC::<T>::generate_random(rng).unwrap();
+      if let Ok(twist) = ec.twist()
+      {
+        break (ec, twist);
+      }
+    };
+    assert_ne!(twist.get_ref_a(), ec.get_ref_a());
+    assert_eq!(twist.get_ref_b(), ec.get_ref_b());
+    assert_eq!(twist.get_curve_ord(), ec.twist_order());
+    assert_eq!(twist.count_points(), twist.get_curve_ord());
+    assert_eq!(twist.twist_order(), ec.get_curve_ord());
+    assert_eq!(ec.twist(), ec.twist());
+    if let Ok(twist_of_twist) = twist.twist()
+    {
+      assert_eq!(twist_of_twist.get_ref_a(), ec.get_ref_a());
+      assert_eq!(twist_of_twist.get_ord(), ec.get_ord());
+      assert_eq!(twist_of_twist.get_cofactor(), ec.get_cofactor());
+    }
+  }
+
+  fn check_x_only<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, rng : &mut impl CryptoRngCore)
+  {
+    assert_eq!(ec.check_x_coordinate(&T::zero()), Err(EcError::WrongPointOrder(ec.get_ref_ord().to_str_radix(16))));
+    let AffinePoint::Point { x: bp_x, .. } = ec.get_bp()
+    else
+    {
+      panic!("base point is the point at infinity")
+    };
+    assert_eq!(ec.mul_x_coordinate(&bp_x, ec.get_ord()), Ok(None));
+    assert_eq!(ec.mul_x_coordinate(&bp_x, 1_u8), Ok(Some(bp_x.clone())));
+    for _ in 0 .. X_ONLY_TEST_ITERATIONS
+    {
+      let p = ec.generate_random_affine_point(rng);
+      let AffinePoint::Point { x, .. } = &p
+      else
+      {
+        continue;
+      };
+      assert!(!is_on_twist(ec, x));
+      assert_eq!(ec.check_x_coordinate(x), Ok(()));
+      let k = generate_num(rng, ec.get_curve_ord().bits());
+      let expected = match ec.mul(&p, k.clone())
+      {
+        AffinePoint::Point { x, .. } => Some(x),
+        AffinePoint::Infinity => None,
+      };
+      assert_eq!(ec.mul_x_coordinate(x, k), Ok(expected));
+    }
+    let mut twist_points = 0;
+    while twist_points < X_ONLY_TEST_ITERATIONS
+    {
+      let x = T::from(generate_num(rng, T::get_m()));
+      if is_on_twist(ec, &x)
+      {
+        assert_eq!(ec.check_x_coordinate(&x), Err(EcError::PointOnTwist));
+        assert_eq!(ec.mul_x_coordinate(&x, 2_u8), Err(EcError::PointOnTwist));
+        twist_points += 1;
+      }
+    }
+  }
+
+  // Every $x \ne 0$ belongs either to the EC or to its twist
+  fn check_x_only_exhaustive<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>)
+  {
+    let points = ec.enumerate_points();
+    let xs = points
+      .iter()
+      .filter_map(|point| match point
+      {
+        AffinePoint::Point { x, .. } => Some(x.clone()),
+        AffinePoint::Infinity => None,
+      })
+      .collect::<HashSet<_>>();
+    for x in (1_u64 .. 1 << T::get_m()).map(T::from_poly)
+    {
+      assert_eq!(ec.check_x_coordinate(&x).is_ok(), xs.contains(&x));
+      for point in points.iter().filter(|point| matches!(point, AffinePoint::Point { x: p_x, .. } if *p_x == x))
+      {
+        for k in 0 ..= points.len()
+        {
+          let expected = match ec.mul(point, k)
+          {
+            AffinePoint::Point { x, .. } => Some(x),
+            AffinePoint::Infinity => None,
+          };
+          assert_eq!(ec.mul_x_coordinate(&x, k), Ok(expected));
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn twist_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_twist_order::<GF11>(&mut rng);
+    check_twist_order::<GF13>(&mut rng);
+    check_twist::<GF29>(&mut rng);
+    check_x_only(&BinaryEC::<GF163>::generate_m163_pb_curve(), &mut rng);
+    check_x_only(&BinaryEC::<GF163>::generate_k163_curve(), &mut rng);
+    check_x_only(&BinaryEC::<GF257>::generate_m257_pb_curve(), &mut rng);
+    check_x_only_exhaustive(&BinaryEC::<GF5>::generate_m5_toy_curve());
+    check_x_only_exhaustive(&BinaryEC::<GF7>::generate_m7_toy_curve());
+  }
+
+  #[test]
+  fn twist_security_test()
+  {
+    // Twist orders of these presets are fully factorized, the largest factors have 127, 182 and 280 bits
+    for (curve, largest_factor_bits) in [(DstuCurve::M173, 127), (DstuCurve::M257, 182), (DstuCurve::M307, 280)]
+    {
+      let ec = curve.generate();
+      let security = ec.twist_security();
+      assert!(security.get_ref_cofactor().is_one());
+      let product = security.get_ref_factors().iter().fold(BigUint::one(), |acc, (r, e)| acc * r.pow(*e));
+      assert_eq!(&product, security.get_ref_twist_order());
+      assert!(security.get_ref_factors().iter().all(|(r, _)| is_probably_prime(r)));
+      assert_eq!(security.largest_prime_factor().map(|r| r.bits()), Some(largest_factor_bits));
+      assert_eq!(security.security_bits(), Some(largest_factor_bits / 2));
+    }
+    // $2^3 \cdot 5 \cdot c$, where 158-bit cofactor $c$ is composite without small factors
+    let security = AnyBinaryEC::from(BinaryEC::<GF163>::generate_m163_pb_curve()).twist_security();
+    let to_factors = |factors : &[(u64, u32)]| factors.iter().map(|(r, e)| (BigUint::from(*r), *e)).collect::<Vec<_>>();
+    assert_eq!(security.get_ref_factors(), to_factors(&[(2, 3), (5, 1)]));
+    assert_eq!(security.get_ref_cofactor().bits(), 158);
+    assert!(!is_probably_prime(security.get_ref_cofactor()));
+    assert_eq!(security.largest_prime_factor(), None);
+    assert_eq!(security.security_bits(), None);
+  }
+
+  // Side-channel countermeasures
+  fn all_countermeasures() -> Vec<Countermeasures>
+  {
+    (0 .. 8_u8)
+      .map(|mask| {
+        Countermeasures::NONE
+          .with_scalar_blinding(mask & 1 != 0)
+          .with_randomized_coordinates(mask & 2 != 0)
+          .with_base_point_split(mask & 4 != 0)
+      })
+      .collect()
+  }
+
+  fn check_mul_secret<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, p : AffinePoint<T>, k : BigUint)
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    for countermeasures in all_countermeasures()
+    {
+      assert!(ec.mul_secret(&ec.get_bp(), ec.get_ord(), countermeasures, &mut rng).is_inf());
+      assert!(ec.mul_secret(&AffinePoint::Infinity, 3_u8, countermeasures, &mut rng).is_inf());
+      assert_eq!(ec.mul_secret(&ec.get_bp(), 1_u8, countermeasures, &mut rng), ec.get_bp());
+      assert_eq!(ec.mul_secret(&p, k.clone(), countermeasures, &mut rng), ec.mul(&p, k.clone()));
+    }
+    let p = ProjectivePoint::from(p);
+    let randomized = p.randomize(&mut rng);
+    assert_eq!(randomized, p);
+    assert_eq!(randomized.to_affine(), p.to_affine());
+  }
+
+  // Points of every order and every residue of the scalar modulo the order of the group
+  fn check_mul_secret_exhaustive<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, rng : &mut impl CryptoRngCore)
+  {
+    let points = ec.enumerate_points();
+    for p in points.iter()
+    {
+      for (k, countermeasures) in (0 ..= points.len()).flat_map(|k| all_countermeasures().into_iter().map(move |c| (k, c)))
+      {
+        assert_eq!(ec.mul_secret(p, k, countermeasures, rng), ec.mul(p, k));
+      }
+    }
+  }
+
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(COUNTERMEASURES_TEST_CASES))]
+      #[test]
+      fn mul_secret_test_163(p in arb_affine_point_gf163(), k in arb_biguint()) {
+        check_mul_secret(&BinaryEC::<GF163>::generate_m163_pb_curve(), p, k);
+      }
+  }
+  proptest! {
+      #![proptest_config(ProptestConfig::with_cases(COUNTERMEASURES_TEST_CASES))]
+      #[test]
+      fn mul_secret_test_random_ec_29((ec, p) in arb_point_on_random_ec_gf29(), k in arb_biguint()) {
+        check_mul_secret(&ec, p, k);
+      }
+  }
+
+  #[test]
+  fn countermeasures_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    assert_eq!(Countermeasures::default(), Countermeasures::ALL.with_base_point_split(false));
+    check_mul_secret_exhaustive(&BinaryEC::<GF5>::generate_m5_toy_curve(), &mut rng);
+  }
+
+  #[cfg(feature = "group")]
+  fn multi_scalar_mul<G : group::Group>(points : &[G], scalars : &[G::Scalar]) -> G
+  {
+    points.iter().zip(scalars.iter()).map(|(point, scalar)| *point * scalar).sum()
+  }
+
+  #[cfg(feature = "group")]
+  fn check_preset_scalar<T : for<'a> GFArithmetic<'a> + rust_ec::preset_group::PresetScalarParams>(rng : &mut impl CryptoRngCore)
+  {
+    use ff::{Field, PrimeField};
+    use rust_ec::preset_group::PresetScalar;
+
+    let n = T::generate_preset().get_ord();
+    assert_eq!(PresetScalar::<T>::modulus(), n);
+    assert_eq!(BigUint::from_str_radix(&PresetScalar::<T>::MODULUS[2 ..], 16).unwrap(), n);
+    assert_eq!(PresetScalar::<T>::NUM_BITS as u64, n.bits());
+    assert_eq!(PresetScalar::<T>::TWO_INV.double(), PresetScalar::ONE);
+    let root_of_unity = PresetScalar::<T>::ROOT_OF_UNITY;
+    assert_eq!(root_of_unity * PresetScalar::<T>::ROOT_OF_UNITY_INV, PresetScalar::ONE);
+    assert_eq!(root_of_unity.pow_vartime([1 << PresetScalar::<T>::S]), PresetScalar::ONE);
+    assert_ne!(root_of_unity.pow_vartime([1 << (PresetScalar::<T>::S - 1)]), PresetScalar::ONE);
+    let t = (&n - BigUint::one()) >> PresetScalar::<T>::S;
+    let generator = PresetScalar::<T>::MULTIPLICATIVE_GENERATOR;
+    assert_eq!(generator.pow_vartime(t.to_u64_digits()), root_of_unity);
+    assert_eq!(generator.pow_vartime([1 << PresetScalar::<T>::S]), PresetScalar::<T>::DELTA);
+    assert!(bool::from(generator.sqrt().is_none()));
+    assert!(bool::from(PresetScalar::<T>::from_repr(PresetScalar::<T>::ZERO.to_repr()).is_some()));
+    assert!(bool::from(PresetScalar::<T>::invert(&PresetScalar::ZERO).is_none()));
+
+    let mut n_repr = <PresetScalar<T> as PrimeField>::Repr::default();
+    n_repr.as_mut()[.. n.to_bytes_le().len()].copy_from_slice(&n.to_bytes_le());
+    assert!(bool::from(PresetScalar::<T>::from_repr(n_repr).is_none()));
+    assert_eq!(PresetScalar::<T>::from_biguint(&(&n + BigUint::from(5_u8))), PresetScalar::from(5));
+
+    for _ in 0 .. PRESET_GROUP_TEST_ITERATIONS
+    {
+      let (a, b) = (PresetScalar::<T>::random(&mut *rng), PresetScalar::<T>::random(&mut *rng));
+      assert!(a.to_biguint() < n);
+      assert_eq!(PresetScalar::<T>::from_repr(a.to_repr()).unwrap(), a);
+      assert_eq!(bool::from(a.is_odd()), a.to_biguint().bit(0));
+      assert_eq!((a + b).to_biguint(), (a.to_biguint() + b.to_biguint()) % &n);
+      assert_eq!((a * b).to_biguint(), (a.to_biguint() * b.to_biguint()) % &n);
+      assert_eq!(a - b + b, a);
+      assert_eq!(-a + a, PresetScalar::ZERO);
+      assert_eq!(a * a.invert().unwrap(), PresetScalar::ONE);
+      let root = a.square().sqrt().unwrap();
+      assert!(root == a || root == -a);
+      let (is_square, root) = PresetScalar::sqrt_ratio(&(a.square() * b), &b);
+      assert!(bool::from(is_square));
+      assert_eq!(root.square(), a.square());
+      assert_eq!([a, b].iter().product::<PresetScalar<T>>(), a * b);
+    }
+  }
+
+  #[cfg(feature = "group")]
+  fn check_preset_point<T : for<'a> GFArithmetic<'a> + rust_ec::preset_group::PresetScalarParams>(rng : &mut impl CryptoRngCore)
+  {
+    use ff::Field;
+    use group::{Curve, Group};
+    use rust_ec::preset_group::{PresetPoint, PresetScalar};
+
+    let ec = T::generate_preset();
+    let generator = PresetPoint::<T>::generator();
+    assert_eq!(generator.to_affine(), ec.get_bp());
+    assert_eq!(PresetPoint::from_affine(&ec.get_bp()), Ok(generator));
+    assert_eq!(PresetPoint::from_affine(&AffinePoint::Infinity), Ok(PresetPoint::<T>::identity()));
+    let order_two_point = AffinePoint::Point {
+      x : T::zero(),
+      y : ec.get_ref_b().sqrt(),
+    };
+    assert_eq!(
+      PresetPoint::from_affine(&order_two_point),
+      Err(EcError::WrongPointOrder(ec.get_ord().to_str_radix(16)))
+    );
+    assert!(bool::from(PresetPoint::<T>::identity().is_identity()));
+    assert!(bool::from((generator * -PresetScalar::ONE + generator).is_identity()));
+    assert_eq!(generator * PresetScalar::ZERO, PresetPoint::identity());
+
+    for _ in 0 .. PRESET_GROUP_TEST_ITERATIONS
+    {
+      let (p, q) = (PresetPoint::<T>::random(&mut *rng), PresetPoint::<T>::random(&mut *rng));
+      let (a, b) = (PresetScalar::<T>::random(&mut *rng), PresetScalar::<T>::random(&mut *rng));
+      assert!(ec.is_in_prime_subgroup(&p.to_affine()));
+      assert_eq!(PresetPoint::from_affine(&p.to_affine()), Ok(p));
+      assert_eq!(p + q - q, p);
+      assert_eq!(p + q.to_affine(), p + q);
+      assert_eq!(p + (-p), PresetPoint::identity());
+      assert_eq!(p.double(), p + p);
+      let (pa, qb) = (p * a, q * b);
+      assert_eq!(pa.to_affine(), ec.mul(&p.to_affine(), a.to_biguint()));
+      assert_eq!(pa * b, p * (a * b));
+      assert_eq!(multi_scalar_mul(&[p, p], &[a, b]), p * (a + b));
+      assert_eq!(multi_scalar_mul(&[p, q], &[a, b]), pa + qb);
+    }
+  }
+
+  #[cfg(feature = "group")]
+  #[test]
+  fn preset_group_test()
+  {
+    let mut rng = ChaCha20Rng::from_entropy();
+    check_preset_scalar::<GF163>(&mut rng);
+    check_preset_scalar::<GF167>(&mut rng);
+    check_preset_scalar::<GF173>(&mut rng);
+    check_preset_scalar::<GF179>(&mut rng);
+    check_preset_scalar::<GF191>(&mut rng);
+    check_preset_scalar::<GF233>(&mut rng);
+    check_preset_scalar::<GF257>(&mut rng);
+    check_preset_scalar::<GF367>(&mut rng);
+    check_preset_point::<GF163>(&mut rng);
+  }
+
+  /// Function checks preset against the published base point $G = (x, y)$, order $n$ and cofactor $h$.
+  fn check_nist_preset<'a, T : GFArithmetic<'a>>(ec : &BinaryEC<T>, x : &str, y : &str, n : &str, h : u8)
+  {
+    let uncompressed = BigUint::from_str_radix(&format!("04{x}{y}"), 16).unwrap().to_bytes_be();
+    assert_eq!(uncompressed.len(), 1 + 2 * T::get_m().div_ceil(8) as usize);
+    assert_eq!(AffinePoint::from_sec1(ec, &uncompressed), Ok(ec.get_bp()));
+    assert_eq!(ec.get_bp().to_sec1(false), uncompressed);
+    assert_eq!(*ec.get_ref_ord(), BigUint::from_str_radix(n, 16).unwrap());
+    assert_eq!(*ec.get_ref_cofactor(), BigUint::from(h));
+    assert!(ec.mul(ec.get_ref_bp(), ec.get_ord()).is_inf());
+    assert_eq!(
+      BinaryEC::new(ec.get_a(), ec.get_b(), ec.get_bp(), ec.get_ord(), ec.get_cofactor()).as_ref(),
+      Ok(ec)
+    );
+  }
+
+  #[test]
+  fn nist_presets_test()
+  {
+    // Base points and orders of the sect163k1 .. sect571r1 from the SEC2, which are the same as in FIPS 186
+    check_nist_preset(
+      &BinaryEC::<GF163>::generate_k163_curve(),
+      "02FE13C0537BBC11ACAA07D793DE4E6D5E5C94EEE8",
+      "0289070FB05D38FF58321F2E800536D538CCDAA3D9",
+      "4000000000000000000020108A2E0CC0D99F8A5EF",
+      2,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF163>::generate_b163_curve(),
+      "03F0EBA16286A2D57EA0991168D4994637E8343E36",
+      "00D51FBC6C71A0094FA2CDD545B11C5C0C797324F1",
+      "40000000000000000000292FE77E70C12A4234C33",
+      2,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF233Nist>::generate_k233_curve(),
+      "017232BA853A7E731AF129F22FF4149563A419C26BF50A4C9D6EEFAD6126",
+      "01DB537DECE819B7F70F555A67C427A8CD9BF18AEB9B56E0C11056FAE6A3",
+      "8000000000000000000000000000069D5BB915BCD46EFB1AD5F173ABDF",
+      4,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF233Nist>::generate_b233_curve(),
+      "00FAC9DFCBAC8313BB2139F1BB755FEF65BC391F8B36F8F8EB7371FD558B",
+      "01006A08A41903350678E58528BEBF8A0BEFF867A7CA36716F7E01F81052",
+      "1000000000000000000000000000013E974E72F8A6922031D2603CFE0D7",
+      2,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF283>::generate_k283_curve(),
+      "0503213F78CA44883F1A3B8162F188E553CD265F23C1567A16876913B0C2AC2458492836",
+      "01CCDA380F1C9E318D90F95D07E5426FE87E45C0E8184698E45962364E34116177DD2259",
+      "1FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE9AE2ED07577265DFF7F94451E061E163C61",
+      4,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF283>::generate_b283_curve(),
+      "05F939258DB7DD90E1934F8C70B0DFEC2EED25B8557EAC9C80E2E198F8CDBECD86B12053",
+      "03676854FE24141CB98FE6D4B20D02B4516FF702350EDDB0826779C813F0DF45BE8112F4",
+      "3FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEF90399660FC938A90165B042A7CEFADB307",
+      2,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF409>::generate_k409_curve(),
+      "0060F05F658F49C1AD3AB1890F7184210EFD0987E307C84C27ACCFB8F9F67CC2C460189EB5AAAA62EE222EB1B35540CFE9023746",
+      "01E369050B7C4E42ACBA1DACBF04299C3460782F918EA427E6325165E9EA10E3DA5F6C42E9C55215AA9CA27A5863EC48D8E0286B",
+      "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE5F83B2D4EA20400EC4557D5ED3E3E7CA5B4B5C83B8E01E5FCF",
+      4,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF409>::generate_b409_curve(),
+      "015D4860D088DDB3496B0C6064756260441CDE4AF1771D4DB01FFE5B34E59703DC255A868A1180515603AEAB60794E54BB7996A7",
+      "0061B1CFAB6BE5F32BBFA78324ED106A7636B9C5A7BD198D0158AA4F5488D08F38514F1FDF4B4F40D2181B3681C364BA0273C706",
+      "10000000000000000000000000000000000000000000000000001E2AAD6A612F33307BE5FA47C3C9E052F838164CD37D9A21173",
+      2,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF571>::generate_k571_curve(),
+      "026EB7A859923FBC82189631F8103FE4AC9CA2970012D5D46024804801841CA44370958493B205E647DA304DB4CEB08CBBD1BA39494776FB988B47174DCA88C7E2945283A01C8972",
+      "0349DC807F4FBF374F4AEADE3BCA95314DD58CEC9F307A54FFC61EFC006D8A2C9D4979C0AC44AEA74FBEBBB9F772AEDCB620B01A7BA7AF1B320430C8591984F601CD4C143EF1C7A3",
+      "20000000000000000000000000000000000000000000000000000000000000000000000131850E1F19A63E4B391A8DB917F4138B630D84BE5D639381E91DEB45CFE778F637C1001",
+      4,
+    );
+    check_nist_preset(
+      &BinaryEC::<GF571>::generate_b571_curve(),
+      "0303001D34B856296C16C0D40D3CD7750A93D1D2955FA80AA5F40FC8DB7B2ABDBDE53950F4C0D293CDD711A35B67FB1499AE60038614F1394ABFA3B4C850D927E1E7769C8EEC2D19",
+      "037BF27342DA639B6DCCFFFEB73D69D78C6C27A6009CBBCA1980F8533921E8A684423E43BAB08A576291AF8F461BB2A8B3531D2F0485C19B16E2F1516E23DD3C1A4827AF1B8AC15B",
+      "3FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE661CE18FF55987308059B186823851EC7DD9CA1161DE93D5174D66E8382E9BB2FE84E47",
+      2,
+    );
+  }
 }